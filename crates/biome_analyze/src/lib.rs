@@ -11,6 +11,8 @@ pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+mod plugin;
+pub mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -29,6 +31,11 @@ pub use crate::diagnostics::AnalyzerDiagnostic;
 pub use crate::diagnostics::SuppressionDiagnostic;
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
 pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+use crate::plugin::PluginVisitor;
+pub use crate::plugin::{
+    AnalyzerPlugin, PluginHost, PluginReport, PluginSignal, SandboxedPlugin, SandboxedPluginRule,
+    PLUGIN_GROUP,
+};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
@@ -65,6 +72,8 @@ pub struct Analyzer<'analyzer, L: Language, Matcher, Break, Diag> {
     phases: BTreeMap<Phases, Vec<Box<dyn Visitor<Language = L> + 'analyzer>>>,
     /// Holds the metadata for all the rules statically known to the analyzer
     metadata: &'analyzer MetadataRegistry,
+    /// Names of the rules defined by the plugins registered in the analyzer
+    plugins: Vec<&'static str>,
     /// Executor for the query matches emitted by the visitors
     query_matcher: Matcher,
     /// Language-specific suppression comment parsing function
@@ -100,6 +109,7 @@ where
         Self {
             phases: BTreeMap::new(),
             metadata,
+            plugins: Vec::new(),
             query_matcher,
             parse_suppression_comment,
             apply_suppression_comment,
@@ -116,10 +126,17 @@ where
         self.phases.entry(phase).or_default().push(visitor);
     }
 
+    /// Registers an [AnalyzerPlugin], its rule runs alongside the rules of the registry
+    pub fn add_plugin(&mut self, plugin: Box<dyn AnalyzerPlugin<L> + 'analyzer>) {
+        self.plugins.push(plugin.name());
+        self.add_visitor(Phases::Syntax, Box::new(PluginVisitor::new(plugin)));
+    }
+
     pub fn run(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
         let Self {
             phases,
             metadata,
+            plugins,
             mut query_matcher,
             parse_suppression_comment,
             mut emit_signal,
@@ -134,6 +151,7 @@ where
                 phase,
                 visitors: &mut visitors,
                 metadata,
+                plugins: &plugins,
                 query_matcher: &mut query_matcher,
                 signal_queue: BinaryHeap::new(),
                 parse_suppression_comment,
@@ -201,6 +219,8 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     visitors: &'phase mut [Box<dyn Visitor<Language = L> + 'analyzer>],
     /// Holds the metadata for all the rules statically known to the analyzer
     metadata: &'analyzer MetadataRegistry,
+    /// Names of the rules defined by the plugins registered in the analyzer
+    plugins: &'phase [&'static str],
     /// Executor for the query matches emitted by the visitors
     query_matcher: &'phase mut Matcher,
     /// Queue for pending analyzer signals
@@ -475,17 +495,28 @@ where
                 SuppressionKind::Everything => None,
                 SuppressionKind::Rule(rule) => Some(rule),
                 SuppressionKind::MaybeLegacy(rule) => Some(rule),
+                SuppressionKind::Plugin(rule) => Some(rule),
                 SuppressionKind::Deprecated => None,
             };
 
             if let Some(rule) = rule {
-                let group_rule = rule.find('/').map(|index| {
-                    let (start, end) = rule.split_at(index);
-                    (start, &end[1..])
-                });
+                let group_rule = if matches!(kind, SuppressionKind::Plugin(_)) {
+                    Some((PLUGIN_GROUP, rule))
+                } else {
+                    rule.find('/').map(|index| {
+                        let (start, end) = rule.split_at(index);
+                        (start, &end[1..])
+                    })
+                };
 
                 let key = match group_rule {
+                    None if rule == PLUGIN_GROUP => Some(RuleFilter::Group(PLUGIN_GROUP)),
                     None => self.metadata.find_group(rule).map(RuleFilter::from),
+                    Some((PLUGIN_GROUP, rule)) => self
+                        .plugins
+                        .iter()
+                        .find(|plugin| **plugin == rule)
+                        .map(|plugin| RuleFilter::Rule(PLUGIN_GROUP, plugin)),
                     Some((group, rule)) => {
                         self.metadata.find_rule(group, rule).map(RuleFilter::from)
                     }
@@ -684,6 +715,8 @@ pub enum SuppressionKind<'a> {
     Rule(&'a str),
     /// A suppression using the legacy syntax to disable a specific rule eg. `// rome-ignore lint(style/useWhile)`
     MaybeLegacy(&'a str),
+    /// A suppression disabling a rule defined by a plugin eg. `// biome-ignore lint/plugin(noDirectFetch)`
+    Plugin(&'a str),
     /// `rome-ignore` is legacy
     Deprecated,
 }
@@ -836,9 +869,10 @@ impl<'analysis> AnalysisFilter<'analysis> {
                 enabled_rules.iter().any(|filter| filter.match_group::<G>())
             })
             && self.disabled_rules.map_or(true, |disabled_rules| {
-                !disabled_rules
-                    .iter()
-                    .any(|filter| filter.match_group::<G>())
+                // Disabling a single rule doesn't disable the rest of its group
+                !disabled_rules.iter().any(|filter| {
+                    matches!(filter, RuleFilter::Group(_)) && filter.match_group::<G>()
+                })
             })
     }
 
@@ -856,6 +890,22 @@ impl<'analysis> AnalysisFilter<'analysis> {
             })
     }
 
+    /// Return `true` if the rule of the plugin called `name` matches this filter
+    pub fn match_plugin(&self, name: &str) -> bool {
+        let match_plugin = |filter: &RuleFilter| match *filter {
+            RuleFilter::Group(group) => group == PLUGIN_GROUP,
+            RuleFilter::Rule(group, rule) => group == PLUGIN_GROUP && rule == name,
+        };
+
+        self.categories.contains(RuleCategories::LINT)
+            && self
+                .enabled_rules
+                .map_or(true, |enabled_rules| enabled_rules.iter().any(match_plugin))
+            && self.disabled_rules.map_or(true, |disabled_rules| {
+                !disabled_rules.iter().any(match_plugin)
+            })
+    }
+
     /// It creates a new filter with the set of [enabled rules](RuleFilter) passed as argument
     pub fn from_enabled_rules(enabled_rules: Option<&'analysis [RuleFilter<'analysis>]>) -> Self {
         Self {
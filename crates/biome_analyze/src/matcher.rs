@@ -83,6 +83,11 @@ impl GroupKey {
     pub fn group<G: RuleGroup>() -> Self {
         Self::new(G::NAME)
     }
+
+    /// Returns the name of the group
+    pub fn name(&self) -> &'static str {
+        self.group
+    }
 }
 
 impl From<GroupKey> for RuleFilter<'static> {
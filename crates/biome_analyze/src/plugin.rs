@@ -0,0 +1,248 @@
+use crate::{
+    ActionCategory, AnalyzerAction, AnalyzerSignal, DiagnosticSignal, RuleDiagnostic, RuleKey,
+    SignalEntry, Visitor, VisitorContext,
+};
+use biome_console::markup;
+use biome_diagnostics::{category, Applicability, DiagnosticExt, Error, Severity};
+use biome_rowan::{BatchMutation, Language, SyntaxNode, TextRange, WalkEvent};
+
+/// Name of the group of the rules defined by plugins. A plugin rule is suppressed with
+/// `// biome-ignore lint/plugin(<name>)`, and all of them with `// biome-ignore lint/plugin`.
+pub const PLUGIN_GROUP: &str = "plugin";
+
+/// A lint rule that isn't known statically by the analyzer, for example a rule
+/// defined in a file referenced by the configuration
+pub trait AnalyzerPlugin<L: Language> {
+    /// Name of the rule, unique among the plugins
+    fn name(&self) -> &'static str;
+
+    /// Runs the rule on the root of a file, and returns the signals it emits
+    fn evaluate(&self, root: &SyntaxNode<L>) -> Vec<PluginSignal<L>>;
+}
+
+/// Signal emitted by an [AnalyzerPlugin]
+pub struct PluginSignal<L: Language> {
+    /// Text range in the document this signal covers
+    pub text_range: TextRange,
+    /// Boxed analyzer signal to be emitted
+    pub signal: Box<dyn AnalyzerSignal<L>>,
+}
+
+/// A plugin that runs isolated from the analyzer, such as a module compiled to WebAssembly.
+/// It reads the file and reports its diagnostics through the [PluginHost], and it runs in the
+/// analyzer wrapped in a [SandboxedPluginRule].
+pub trait SandboxedPlugin<L: Language> {
+    /// Name of the rule, unique among the plugins
+    fn name(&self) -> &'static str;
+
+    /// Runs the rule on the file exposed by the `host`
+    fn run(&self, host: &mut dyn PluginHost<L>);
+}
+
+/// The API that the analyzer exposes to a [SandboxedPlugin]
+pub trait PluginHost<L: Language> {
+    /// The root of the file that the plugin analyzes
+    fn root(&self) -> &SyntaxNode<L>;
+
+    /// Reports a diagnostic of the plugin
+    fn report(&mut self, report: PluginReport<L>);
+}
+
+/// A diagnostic reported by a [SandboxedPlugin] through the [PluginHost]
+pub struct PluginReport<L: Language> {
+    /// Text range of the reported code
+    pub text_range: TextRange,
+    /// Message of the diagnostic
+    pub message: String,
+    /// Severity of the diagnostic
+    pub severity: Severity,
+    /// A node of the file and the node that replaces it when the fix of the diagnostic is applied
+    pub replacement: Option<(SyntaxNode<L>, SyntaxNode<L>)>,
+}
+
+/// Runs a [SandboxedPlugin] as an [AnalyzerPlugin]: the reports of the plugin become the
+/// signals of the rule, and their replacements become unsafe fixes
+pub struct SandboxedPluginRule<P>(pub P);
+
+impl<L, P> AnalyzerPlugin<L> for SandboxedPluginRule<P>
+where
+    L: Language + 'static,
+    P: SandboxedPlugin<L>,
+{
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    fn evaluate(&self, root: &SyntaxNode<L>) -> Vec<PluginSignal<L>> {
+        let mut host = SignalHost {
+            name: self.0.name(),
+            root,
+            signals: Vec::new(),
+        };
+        self.0.run(&mut host);
+        host.signals
+    }
+}
+
+/// The [PluginHost] of a [SandboxedPluginRule], it turns the reports into signals
+struct SignalHost<'a, L: Language> {
+    name: &'static str,
+    root: &'a SyntaxNode<L>,
+    signals: Vec<PluginSignal<L>>,
+}
+
+impl<'a, L: Language + 'static> PluginHost<L> for SignalHost<'a, L> {
+    fn root(&self) -> &SyntaxNode<L> {
+        self.root
+    }
+
+    fn report(&mut self, report: PluginReport<L>) {
+        let PluginReport {
+            text_range,
+            message,
+            severity,
+            replacement,
+        } = report;
+        let name = self.name;
+        let root = self.root.clone();
+
+        let signal = DiagnosticSignal::new(move || -> Error {
+            RuleDiagnostic::new(category!("lint/plugin"), text_range, message.as_str())
+                .with_severity(severity)
+        })
+        .with_action(move || {
+            let (node, replacement) = replacement.clone()?;
+            let mut mutation = BatchMutation::new(root.clone());
+            mutation.replace_element(node.into(), replacement.into());
+            Some(AnalyzerAction {
+                rule_name: Some((PLUGIN_GROUP, name)),
+                category: ActionCategory::QuickFix,
+                applicability: Applicability::MaybeIncorrect,
+                message: markup! { "Apply the fix of the plugin." }.to_owned(),
+                mutation,
+            })
+        });
+
+        self.signals.push(PluginSignal {
+            text_range,
+            signal: Box::new(signal),
+        });
+    }
+}
+
+/// Runs a plugin on the root of the file, and queues its signals so that they go
+/// through the suppression comments like the signals of the other rules
+pub(crate) struct PluginVisitor<'a, L: Language> {
+    plugin: Box<dyn AnalyzerPlugin<L> + 'a>,
+}
+
+impl<'a, L: Language> PluginVisitor<'a, L> {
+    pub(crate) fn new(plugin: Box<dyn AnalyzerPlugin<L> + 'a>) -> Self {
+        Self { plugin }
+    }
+}
+
+impl<'a, L: Language> Visitor for PluginVisitor<'a, L> {
+    type Language = L;
+
+    fn visit(&mut self, event: &WalkEvent<SyntaxNode<L>>, ctx: VisitorContext<L>) {
+        let WalkEvent::Enter(node) = event else {
+            return;
+        };
+        if node.parent().is_some() {
+            return;
+        }
+
+        let rule = RuleKey::new(PLUGIN_GROUP, self.plugin.name());
+        for PluginSignal { text_range, signal } in self.plugin.evaluate(node) {
+            ctx.signal_queue.push(SignalEntry {
+                signal,
+                rule,
+                text_range,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnalyzerPlugin, PluginHost, PluginReport, SandboxedPlugin, SandboxedPluginRule};
+    use biome_diagnostics::{Diagnostic, Severity};
+    use biome_rowan::raw_language::{RawLanguage, RawLanguageKind, RawSyntaxTreeBuilder};
+    use biome_rowan::{SyntaxNode, TextRange, TextSize};
+
+    fn literal(text: &str) -> SyntaxNode<RawLanguage> {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token(RawLanguageKind::NUMBER_TOKEN, text);
+        builder.finish_node();
+        builder.finish()
+    }
+
+    /// Reports the literals `1`, and replaces them with `2`
+    struct NoOne;
+
+    impl SandboxedPlugin<RawLanguage> for NoOne {
+        fn name(&self) -> &'static str {
+            "noOne"
+        }
+
+        fn run(&self, host: &mut dyn PluginHost<RawLanguage>) {
+            let literals = host
+                .root()
+                .descendants()
+                .filter(|node| {
+                    node.kind() == RawLanguageKind::LITERAL_EXPRESSION && node.text_trimmed() == "1"
+                })
+                .collect::<Vec<_>>();
+            for node in literals {
+                host.report(PluginReport {
+                    text_range: node.text_trimmed_range(),
+                    message: "Don't use 1.".to_string(),
+                    severity: Severity::Warning,
+                    replacement: Some((node, literal("2"))),
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn sandboxed_plugin_reports_through_the_host() {
+        let root = {
+            let mut builder = RawSyntaxTreeBuilder::new();
+            builder.start_node(RawLanguageKind::ROOT);
+            builder.start_node(RawLanguageKind::EXPRESSION_LIST);
+            for text in ["1", "3", "1"] {
+                builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+                builder.token(RawLanguageKind::NUMBER_TOKEN, text);
+                builder.finish_node();
+            }
+            builder.finish_node();
+            builder.finish_node();
+            builder.finish()
+        };
+
+        let plugin = SandboxedPluginRule(NoOne);
+        assert_eq!(plugin.name(), "noOne");
+
+        let signals = plugin.evaluate(&root);
+        let ranges = signals
+            .iter()
+            .map(|signal| signal.text_range)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            [
+                TextRange::new(TextSize::from(0), TextSize::from(1)),
+                TextRange::new(TextSize::from(2), TextSize::from(3)),
+            ]
+        );
+
+        let signal = &signals[0].signal;
+        let diagnostic = signal.diagnostic().unwrap();
+        assert_eq!(diagnostic.severity(), Severity::Warning);
+        let action = signal.actions().next().unwrap();
+        assert_eq!(action.rule_name, Some(("plugin", "noOne")));
+        assert_eq!(action.mutation.commit().to_string(), "231");
+    }
+}
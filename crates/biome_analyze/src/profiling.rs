@@ -0,0 +1,178 @@
+//! Opt-in collection of the time spent by the analyzer and by the phases around it.
+//!
+//! Profiling is disabled by default, and recording a timing is a no-op until [enable] is called.
+//! Each thread accumulates its timings separately, so the threads that process files in
+//! parallel don't contend on a shared lock. The timings of all the threads are merged by [drain].
+
+use crate::{RuleKey, Visitor, VisitorContext, VisitorFinishContext};
+use biome_rowan::{SyntaxNode, WalkEvent};
+use rustc_hash::FxHashMap;
+use std::cell::OnceCell;
+use std::ops::AddAssign;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The phase that contains the time spent running the lint rules
+pub const RULES_PHASE: &str = "rules";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The profiles of all the threads that recorded at least a timing
+static PROFILES: Mutex<Vec<Arc<Mutex<Profile>>>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static THREAD_PROFILE: OnceCell<Arc<Mutex<Profile>>> = const { OnceCell::new() };
+}
+
+/// Starts recording the timings
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// The time spent by a phase or by a rule, and the number of times it was run
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub total: Duration,
+    pub count: u64,
+}
+
+impl AddAssign<Duration> for Timing {
+    fn add_assign(&mut self, duration: Duration) {
+        self.total += duration;
+        self.count += 1;
+    }
+}
+
+impl AddAssign for Timing {
+    fn add_assign(&mut self, other: Timing) {
+        self.total += other.total;
+        self.count += other.count;
+    }
+}
+
+/// The timings recorded while profiling was enabled
+#[derive(Debug, Default, Clone)]
+pub struct Profile {
+    /// The timings of the phases, e.g. parse, semantic model, control flow graph, rules and format
+    pub phases: FxHashMap<&'static str, Timing>,
+    /// The timings of the lint rules
+    pub rules: FxHashMap<RuleKey, Timing>,
+}
+
+impl Profile {
+    fn merge(&mut self, other: Profile) {
+        for (phase, timing) in other.phases {
+            *self.phases.entry(phase).or_default() += timing;
+        }
+        for (rule, timing) in other.rules {
+            *self.rules.entry(rule).or_default() += timing;
+        }
+    }
+
+    fn rules_total(&self) -> Duration {
+        self.phases
+            .get(RULES_PHASE)
+            .map_or(Duration::ZERO, |timing| timing.total)
+    }
+}
+
+fn with_thread_profile<T>(func: impl FnOnce(&mut Profile) -> T) -> T {
+    THREAD_PROFILE.with(|cell| {
+        let profile = cell.get_or_init(|| {
+            let profile = Arc::default();
+            PROFILES.lock().unwrap().push(Arc::clone(&profile));
+            profile
+        });
+        let mut profile = profile.lock().unwrap();
+        func(&mut profile)
+    })
+}
+
+/// Records the time spent by a phase, if profiling is enabled
+pub fn record_phase(phase: &'static str, duration: Duration) {
+    if is_enabled() {
+        with_thread_profile(|profile| *profile.phases.entry(phase).or_default() += duration);
+    }
+}
+
+/// Records the time spent by a lint rule, which also counts towards the [RULES_PHASE]
+pub(crate) fn record_rule(rule: RuleKey, duration: Duration) {
+    if is_enabled() {
+        with_thread_profile(|profile| {
+            *profile.rules.entry(rule).or_default() += duration;
+            *profile.phases.entry(RULES_PHASE).or_default() += duration;
+        });
+    }
+}
+
+/// Runs `func` and records its duration under `phase`, if profiling is enabled
+pub fn time_phase<T>(phase: &'static str, func: impl FnOnce() -> T) -> T {
+    if !is_enabled() {
+        return func();
+    }
+    let start = Instant::now();
+    let result = func();
+    record_phase(phase, start.elapsed());
+    result
+}
+
+/// Returns the timings recorded by all the threads so far, and resets them
+pub fn drain() -> Profile {
+    let mut result = Profile::default();
+    for profile in PROFILES.lock().unwrap().iter() {
+        result.merge(std::mem::take(&mut *profile.lock().unwrap()));
+    }
+    result
+}
+
+/// A [Visitor] that records the time spent by the visitor it wraps under `phase`.
+///
+/// Visitors can run lint rules while they visit the tree: the time spent by these rules
+/// is recorded separately, and it's excluded from the time of the phase.
+pub struct ProfiledVisitor<V> {
+    phase: &'static str,
+    inner: V,
+}
+
+impl<V> ProfiledVisitor<V> {
+    pub fn new(phase: &'static str, inner: V) -> Self {
+        Self { phase, inner }
+    }
+
+    fn time<T>(phase: &'static str, func: impl FnOnce() -> T) -> T {
+        if !is_enabled() {
+            return func();
+        }
+        let rules_before = with_thread_profile(|profile| profile.rules_total());
+        let start = Instant::now();
+        let result = func();
+        let elapsed = start.elapsed();
+        let rules_elapsed =
+            with_thread_profile(|profile| profile.rules_total()).saturating_sub(rules_before);
+        record_phase(phase, elapsed.saturating_sub(rules_elapsed));
+        result
+    }
+}
+
+impl<V: Visitor> Visitor for ProfiledVisitor<V> {
+    type Language = V::Language;
+
+    fn visit(
+        &mut self,
+        event: &WalkEvent<SyntaxNode<Self::Language>>,
+        ctx: VisitorContext<Self::Language>,
+    ) {
+        let inner = &mut self.inner;
+        Self::time(self.phase, || inner.visit(event, ctx));
+    }
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<Self::Language>) {
+        let Self { phase, inner } = *self;
+        Self::time(phase, || Box::new(inner).finish(ctx));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     context::RuleContext,
     matcher::{GroupKey, MatchQueryParams},
+    profiling,
     query::{QueryKey, Queryable},
     signals::RuleSignal,
     AddVisitor, AnalysisFilter, GroupCategory, QueryMatcher, Rule, RuleGroup, RuleKey,
@@ -13,6 +14,7 @@ use std::{
     any::TypeId,
     borrow,
     collections::{BTreeMap, BTreeSet},
+    time::Instant,
 };
 
 /// Defines all the phases that the [RuleRegistry] supports.
@@ -406,6 +408,8 @@ impl<L: Language + Default> RegistryRule<L> {
                 }
             }
 
+            let start = profiling::is_enabled().then(Instant::now);
+
             // SAFETY: The rule should never get executed in the first place
             // if the query doesn't match
             let query_result = params.query.downcast_ref().unwrap();
@@ -446,6 +450,10 @@ impl<L: Language + Default> RegistryRule<L> {
                 });
             }
 
+            if let Some(start) = start {
+                profiling::record_rule(RuleKey::rule::<R>(), start.elapsed());
+            }
+
             Ok(())
         }
 
@@ -1,24 +1,19 @@
 use crate::CliDiagnostic;
+use biome_diagnostics::LineIndexBuf;
 use biome_fs::FileSystem;
 use biome_service::{DynRef, PartialConfiguration};
+use biome_text_size::{TextRange, TextSize};
+use rustc_hash::FxHashMap;
 use std::ffi::OsString;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn get_changed_files(
     fs: &DynRef<'_, dyn FileSystem>,
     configuration: &PartialConfiguration,
     since: Option<String>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
-    let default_branch = configuration
-        .vcs
-        .as_ref()
-        .and_then(|v| v.default_branch.as_ref());
-
-    let base = match (since.as_ref(), default_branch) {
-        (Some(since), Some(_)) => since,
-        (Some(since), None) => since,
-        (None, Some(branch)) => branch,
-        (None, None) => return Err(CliDiagnostic::incompatible_end_configuration("The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.")),
-    };
+    let base = get_base(configuration, since.as_deref(), "--changed")?;
 
     let changed_files = fs.get_changed_files(base)?;
 
@@ -30,3 +25,111 @@ pub(crate) fn get_changed_files(
 
     Ok(filtered_changed_files)
 }
+
+pub(crate) fn get_changed_lines(
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration: &PartialConfiguration,
+    since: Option<String>,
+) -> Result<ChangedLinesByFile, CliDiagnostic> {
+    let base = get_base(configuration, since.as_deref(), "--changed-lines")?;
+
+    let changed_lines = fs.get_changed_lines(base)?;
+
+    let changed_lines = ChangedLinesByFile(
+        changed_lines
+            .into_iter()
+            .filter(|file| !file.lines.is_empty())
+            .map(|file| (PathBuf::from(file.path), file.lines))
+            .collect(),
+    );
+
+    if changed_lines.0.is_empty() {
+        return Err(CliDiagnostic::no_files_processed());
+    }
+
+    Ok(changed_lines)
+}
+
+/// Returns the base to compare against, from the `--since` argument or from the `vcs.defaultBranch` configuration
+fn get_base<'a>(
+    configuration: &'a PartialConfiguration,
+    since: Option<&'a str>,
+    flag: &str,
+) -> Result<&'a str, CliDiagnostic> {
+    let default_branch = configuration
+        .vcs
+        .as_ref()
+        .and_then(|v| v.default_branch.as_deref());
+
+    match (since, default_branch) {
+        (Some(since), Some(_)) => Ok(since),
+        (Some(since), None) => Ok(since),
+        (None, Some(branch)) => Ok(branch),
+        (None, None) => Err(CliDiagnostic::incompatible_end_configuration(format!("The `{flag}` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument."))),
+    }
+}
+
+pub(crate) fn get_staged_files(
+    fs: &DynRef<'_, dyn FileSystem>,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    let staged_files = fs.get_staged_files()?;
+
+    let staged_files = staged_files.iter().map(OsString::from).collect::<Vec<_>>();
+
+    if staged_files.is_empty() {
+        return Err(CliDiagnostic::no_files_processed());
+    }
+
+    Ok(staged_files)
+}
+
+/// The lines that changed in each file, used to restrict diagnostics and formatting to them
+#[derive(Debug, Default)]
+pub(crate) struct ChangedLinesByFile(FxHashMap<PathBuf, Vec<Range<u32>>>);
+
+impl ChangedLinesByFile {
+    /// Returns the paths of the files that have changed lines
+    pub(crate) fn paths(&self) -> Vec<OsString> {
+        let mut paths: Vec<_> = self.0.keys().map(OsString::from).collect();
+        paths.sort();
+        paths
+    }
+
+    /// Returns the zero-based ranges of the lines that changed in the file at `path`
+    fn lines(&self, path: &Path) -> &[Range<u32>] {
+        self.0.get(path).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether `span` touches at least one of the lines that changed in the file at `path`
+    pub(crate) fn intersects(
+        &self,
+        path: &Path,
+        line_index: &LineIndexBuf,
+        span: TextRange,
+    ) -> bool {
+        let line_of =
+            |offset: TextSize| line_index.partition_point(|start| *start <= offset) as u32 - 1;
+        let start_line = line_of(span.start());
+        let end_line = line_of(span.end());
+        self.lines(path)
+            .iter()
+            .any(|lines| lines.start <= end_line && start_line < lines.end)
+    }
+
+    /// Returns the ranges of `content` covered by the lines that changed in the file at `path`
+    pub(crate) fn text_ranges(&self, path: &Path, content: &str) -> Vec<TextRange> {
+        let line_index = LineIndexBuf::from_source_text(content);
+        let content_len = TextSize::of(content);
+        let line_start = |line: u32| {
+            line_index
+                .get(line as usize)
+                .copied()
+                .unwrap_or(content_len)
+        };
+        self.lines(path)
+            .iter()
+            .map(|lines| TextRange::new(line_start(lines.start), line_start(lines.end)))
+            .filter(|range| !range.is_empty())
+            .collect()
+    }
+}
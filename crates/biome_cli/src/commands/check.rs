@@ -1,6 +1,7 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_changed_lines, get_staged_files};
 use crate::cli_options::CliOptions;
-use crate::commands::{get_stdin, validate_configuration_diagnostics};
+use crate::commands::{get_patch_output, get_stdin, validate_configuration_diagnostics};
+use crate::execute::{settings_hash, watch};
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -13,10 +14,14 @@ use biome_service::configuration::{
 use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
 use biome_service::PartialConfiguration;
 use std::ffi::OsString;
+use std::path::PathBuf;
 
+#[derive(Clone)]
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) diff: bool,
+    pub(crate) output_patch: Option<PathBuf>,
     pub(crate) cli_options: CliOptions,
     pub(crate) configuration: Option<PartialConfiguration>,
     pub(crate) paths: Vec<OsString>,
@@ -25,7 +30,12 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) linter_enabled: Option<bool>,
     pub(crate) organize_imports_enabled: Option<bool>,
     pub(crate) changed: bool,
+    pub(crate) changed_lines: bool,
     pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) profile: bool,
 }
 
 /// Handler for the "check" command of the Biome CLI
@@ -33,9 +43,56 @@ pub(crate) fn check(
     session: CliSession,
     payload: CheckCommandPayload,
 ) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(
+        payload.cli_options.log_level.clone(),
+        payload.cli_options.log_kind.clone(),
+    );
+
+    if !payload.watch {
+        return check_paths(session, payload);
+    }
+    if payload.stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+    if payload.changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--changed",
+        ));
+    }
+    if payload.staged {
+        return Err(CliDiagnostic::incompatible_arguments("--watch", "--staged"));
+    }
+    if payload.changed_lines {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--changed-lines",
+        ));
+    }
+
+    let cli_options = payload.cli_options.clone();
+    let paths = payload.paths.clone();
+    watch(session, &cli_options, paths, |session, paths| {
+        check_paths(
+            session,
+            CheckCommandPayload {
+                paths,
+                ..payload.clone()
+            },
+        )
+    })
+}
+
+/// Runs the check once on the paths of the payload
+fn check_paths(session: CliSession, payload: CheckCommandPayload) -> Result<(), CliDiagnostic> {
     let CheckCommandPayload {
         apply,
         apply_unsafe,
+        diff,
+        output_patch,
         cli_options,
         configuration,
         mut paths,
@@ -45,8 +102,12 @@ pub(crate) fn check(
         formatter_enabled,
         since,
         changed,
+        changed_lines,
+        staged,
+        watch: _,
+        cache,
+        profile,
     } = payload;
-    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
     let fix_file_mode = if apply && apply_unsafe {
         return Err(CliDiagnostic::incompatible_arguments(
@@ -61,6 +122,21 @@ pub(crate) fn check(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    let patch_output = get_patch_output(
+        diff,
+        output_patch,
+        fix_file_mode.map(|_| if apply { "--apply" } else { "--apply-unsafe" }),
+    )?;
+
+    if let (Some(patch_output), Some(_)) = (&patch_output, &stdin_file_path) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            patch_output.as_argument(),
+            "--stdin-file-path",
+        ));
+    }
+    // The patch contains the safe fixes
+    let fix_file_mode = fix_file_mode.or(patch_output.as_ref().map(|_| FixFileMode::SafeFixes));
+
     let loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
     validate_configuration_diagnostics(
@@ -117,13 +193,57 @@ pub(crate) fn check(
 
     let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "check")?;
 
-    if since.is_some() && !changed {
+    if since.is_some() && !changed && !changed_lines {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if changed && staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed",
+            "--staged",
+        ));
+    }
+
+    if changed_lines && (changed || staged) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed-lines",
+            if changed { "--changed" } else { "--staged" },
+        ));
+    }
+
+    // Fixes can change any line of a file
+    if changed_lines && fix_file_mode.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed-lines",
+            match &patch_output {
+                Some(patch_output) => patch_output.as_argument(),
+                None if apply => "--apply",
+                None => "--apply-unsafe",
+            },
+        ));
+    }
+
+    let changed_lines = if changed_lines {
+        let changed_lines = get_changed_lines(&session.app.fs, &fs_configuration, since.clone())?;
+        paths = changed_lines.paths();
+        Some(changed_lines)
+    } else {
+        None
+    };
+
     if changed {
         paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
+    } else if staged {
+        if stdin.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--staged",
+                "--stdin-file-path",
+            ));
+        }
+        paths = get_staged_files(&session.app.fs)?;
     }
+    let settings_hash = cache.then(|| settings_hash(&fs_configuration));
+
     session
         .app
         .workspace
@@ -138,7 +258,12 @@ pub(crate) fn check(
         Execution::new(TraversalMode::Check {
             fix_file_mode,
             stdin,
-        }),
+        })
+        .with_cache(settings_hash)
+        .with_profile(profile)
+        .with_staged(staged)
+        .with_changed_lines(changed_lines)
+        .with_patch_output(patch_output),
         session,
         &cli_options,
         paths,
@@ -1,6 +1,7 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_changed_lines};
 use crate::cli_options::CliOptions;
 use crate::commands::validate_configuration_diagnostics;
+use crate::execute::settings_hash;
 use crate::{execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution};
 use biome_deserialize::Merge;
 use biome_service::configuration::organize_imports::PartialOrganizeImports;
@@ -20,7 +21,10 @@ pub(crate) struct CiCommandPayload {
     pub(crate) configuration: PartialConfiguration,
     pub(crate) cli_options: CliOptions,
     pub(crate) changed: bool,
+    pub(crate) changed_lines: bool,
     pub(crate) since: Option<String>,
+    pub(crate) cache: bool,
+    pub(crate) profile: bool,
 }
 
 /// Handler for the "ci" command of the Biome CLI
@@ -85,14 +89,30 @@ pub(crate) fn ci(session: CliSession, mut payload: CiCommandPayload) -> Result<(
     let (vcs_base_path, gitignore_matches) =
         configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
-    if payload.since.is_some() && !payload.changed {
+    if payload.since.is_some() && !payload.changed && !payload.changed_lines {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
-    if payload.changed {
-        payload.paths = get_changed_files(&session.app.fs, &configuration, payload.since)?;
+    if payload.changed && payload.changed_lines {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed-lines",
+            "--changed",
+        ));
     }
 
+    let changed_lines = if payload.changed_lines {
+        let changed_lines = get_changed_lines(&session.app.fs, &configuration, payload.since)?;
+        payload.paths = changed_lines.paths();
+        Some(changed_lines)
+    } else if payload.changed {
+        payload.paths = get_changed_files(&session.app.fs, &configuration, payload.since)?;
+        None
+    } else {
+        None
+    };
+
+    let settings_hash = payload.cache.then(|| settings_hash(&configuration));
+
     session
         .app
         .workspace
@@ -104,7 +124,10 @@ pub(crate) fn ci(session: CliSession, mut payload: CiCommandPayload) -> Result<(
         })?;
 
     execute_mode(
-        Execution::new_ci(),
+        Execution::new_ci()
+            .with_cache(settings_hash)
+            .with_profile(payload.profile)
+            .with_changed_lines(changed_lines),
         session,
         &payload.cli_options,
         payload.paths,
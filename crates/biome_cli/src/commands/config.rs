@@ -0,0 +1,457 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{validate_configuration_diagnostics, ConfigSubCommand};
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_analyze::RuleFilter;
+use biome_console::{markup, ConsoleExt};
+use biome_css_syntax::CssLanguage;
+use biome_deserialize::Merge;
+use biome_diagnostics::{Category, Severity};
+use biome_formatter::{FormatOptions, IndentStyle};
+use biome_fs::RomePath;
+use biome_js_syntax::JsLanguage;
+use biome_json_syntax::JsonLanguage;
+use biome_service::configuration::{
+    load_configuration, LoadedConfiguration, PartialCssConfiguration, PartialCssFormatter,
+    PartialFormatterConfiguration, PartialJavascriptConfiguration, PartialJavascriptFormatter,
+    PartialJsonConfiguration, PartialJsonFormatter, PlainIndentStyle,
+};
+use biome_service::file_handlers::Language;
+use biome_service::settings::WorkspaceSettings;
+use biome_service::PartialConfiguration;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Handler for the "config" command of the Biome CLI
+pub(crate) fn config(
+    session: CliSession,
+    sub_command: ConfigSubCommand,
+) -> Result<(), CliDiagnostic> {
+    match sub_command {
+        ConfigSubCommand::Print {
+            configuration,
+            cli_options,
+            path,
+        } => print(session, cli_options, configuration, path),
+    }
+}
+
+/// Prints the configuration that applies to `path`, and where each value comes from
+fn print(
+    session: CliSession,
+    cli_options: CliOptions,
+    cli_configuration: Option<PartialConfiguration>,
+    path: PathBuf,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let loaded_configuration =
+        load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+
+    let LoadedConfiguration {
+        configuration: mut fs_configuration,
+        sources,
+        ..
+    } = loaded_configuration;
+
+    let mut layers: Vec<Layer> = sources
+        .iter()
+        .map(|(file_path, configuration)| {
+            Layer::new(file_path.display().to_string(), configuration)
+        })
+        .collect();
+    if let Some(cli_configuration) = cli_configuration {
+        layers.push(Layer::new(
+            "the command line".to_string(),
+            &cli_configuration,
+        ));
+        fs_configuration.merge_with(cli_configuration);
+    }
+
+    let mut settings = WorkspaceSettings::default();
+    settings.merge_with_configuration(
+        fs_configuration.clone(),
+        session.app.fs.working_directory(),
+        None,
+        &[],
+    )?;
+
+    // The overrides are applied in order, after the configuration files and the command line
+    let overrides: Vec<Layer> = fs_configuration
+        .overrides
+        .iter()
+        .flat_map(|overrides| overrides.0.iter())
+        .zip(settings.override_settings.patterns.iter())
+        .enumerate()
+        .filter(|(_, (_, pattern))| {
+            !pattern.include.is_empty()
+                && pattern.include.matches_path(&path)
+                && !pattern.exclude.matches_path(&path)
+        })
+        .map(|(index, (override_pattern, _))| {
+            let mut layer = Layer::new(format!("overrides[{index}]"), override_pattern);
+            layer.values.remove("include");
+            layer.values.remove("ignore");
+            layer
+        })
+        .collect();
+
+    let resolver = Resolver { layers, overrides };
+
+    let mut header = String::new();
+    if sources.is_empty() {
+        writeln!(header, "Configuration files: none").ok();
+    } else {
+        let files = sources
+            .iter()
+            .map(|(file_path, _)| file_path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(header, "Configuration files: {files}").ok();
+    }
+    if resolver.overrides.is_empty() {
+        write!(header, "Overrides: none").ok();
+    } else {
+        let overrides = resolver
+            .overrides
+            .iter()
+            .map(|layer| layer.source.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(header, "Overrides: {overrides}").ok();
+    }
+    let path_name = path.display().to_string();
+    session.app.console.log(markup! {
+        "Effective configuration of "<Emphasis>{path_name}</Emphasis>"\n\n"{header}
+    });
+
+    let formatter = print_formatter(&resolver, &settings, &path);
+    session.app.console.log(markup! {
+        <Emphasis>"Formatter"</Emphasis>"\n"{formatter}
+    });
+
+    let linter = print_linter(&resolver, &settings, &path);
+    session.app.console.log(markup! {
+        <Emphasis>"Linter"</Emphasis>"\n"{linter}
+    });
+
+    Ok(())
+}
+
+/// The values of a configuration, keyed by their path in the configuration file,
+/// e.g. `javascript.formatter.quoteStyle`
+type FlatConfiguration = BTreeMap<String, Value>;
+
+/// A configuration that takes part in the effective configuration
+struct Layer {
+    /// The name printed as the source of the values of this configuration
+    source: String,
+    values: FlatConfiguration,
+}
+
+impl Layer {
+    fn new(source: String, configuration: &impl Serialize) -> Self {
+        Self {
+            source,
+            values: flatten_to_map(configuration),
+        }
+    }
+}
+
+/// A value set by one of the configurations
+struct Resolved<'a> {
+    /// The key that set the value
+    key: &'a str,
+    value: &'a Value,
+    /// The configuration that set the value
+    source: &'a str,
+}
+
+impl Resolved<'_> {
+    fn origin(&self) -> String {
+        format!("{} in {}", self.key, self.source)
+    }
+}
+
+/// Finds the configuration that sets each value of the [WorkspaceSettings], the way the
+/// workspace merges the configurations
+struct Resolver {
+    /// The configuration files and the command line, in the order they are merged
+    layers: Vec<Layer>,
+    /// The overrides that match the file, in order
+    overrides: Vec<Layer>,
+}
+
+impl Resolver {
+    /// Returns the value of the first of the `keys` that is set. The overrides take precedence
+    /// over the configuration files.
+    fn resolve<'a>(&'a self, keys: &'a [String]) -> Option<Resolved<'a>> {
+        let get = |layer: &'a Layer, key: &'a String| {
+            layer.values.get(key).map(|value| Resolved {
+                key,
+                value,
+                source: layer.source.as_str(),
+            })
+        };
+
+        let mut resolved = keys
+            .iter()
+            .find_map(|key| self.layers.iter().rev().find_map(|layer| get(layer, key)));
+        for layer in &self.overrides {
+            if let Some(value) = keys.iter().find_map(|key| get(layer, key)) {
+                resolved = Some(value);
+            }
+        }
+        resolved
+    }
+
+    /// Returns where the value of the first of the `keys` that is set comes from
+    fn origin(&self, keys: &[String]) -> String {
+        self.resolve(keys)
+            .map_or_else(|| "default".to_string(), |resolved| resolved.origin())
+    }
+}
+
+/// Returns the key used for the options of `language` in the configuration
+fn language_key(language: Language) -> Option<&'static str> {
+    match language {
+        Language::JavaScript
+        | Language::JavaScriptReact
+        | Language::TypeScript
+        | Language::TypeScriptReact => Some("javascript"),
+        Language::Json | Language::Jsonc => Some("json"),
+        Language::Css => Some("css"),
+        Language::Unknown => None,
+    }
+}
+
+/// The options of the languages that fall back on the options of the `formatter` section
+const GLOBAL_FORMATTER_OPTIONS: &[&str] =
+    &["indentStyle", "indentWidth", "lineEnding", "lineWidth"];
+
+fn print_formatter(resolver: &Resolver, settings: &WorkspaceSettings, path: &Path) -> String {
+    let language = Language::from_path_and_known_filename(path);
+    let language_prefix = language_key(language).map(|language| format!("{language}.formatter."));
+
+    let mut output = String::new();
+    for (key, value) in formatter_values(settings, language, path) {
+        let option = key
+            .strip_prefix("formatter.")
+            .or_else(|| {
+                language_prefix
+                    .as_ref()
+                    .and_then(|prefix| key.strip_prefix(prefix.as_str()))
+            })
+            .unwrap_or_default();
+        // `indentSize` is deprecated, and sets `indentWidth` when it isn't set
+        let mut keys = vec![key.clone()];
+        if option == "indentWidth" {
+            keys.push(key.replace("indentWidth", "indentSize"));
+        }
+        if language_prefix.is_some() && GLOBAL_FORMATTER_OPTIONS.contains(&option) {
+            keys.push(format!("formatter.{option}"));
+            if option == "indentWidth" {
+                keys.push("formatter.indentSize".to_string());
+            }
+        }
+        let origin = resolver.origin(&keys);
+        writeln!(output, "  {key}: {value}  ({origin})").ok();
+    }
+    output
+}
+
+/// Returns the formatter values that the workspace applies to `path`, keyed by their path in the
+/// configuration file
+fn formatter_values(
+    settings: &WorkspaceSettings,
+    language: Language,
+    path: &Path,
+) -> FlatConfiguration {
+    let formatter = settings.formatter();
+    let rome_path = RomePath::new(path);
+    let enabled = settings
+        .override_settings
+        .formatter_disabled(path)
+        .map_or(formatter.enabled, |disabled| !disabled);
+    let mut configuration = PartialConfiguration {
+        formatter: Some(PartialFormatterConfiguration {
+            enabled: Some(enabled),
+            format_with_errors: Some(formatter.format_with_errors),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    match language_key(language) {
+        Some("javascript") => {
+            let options = settings.format_options::<JsLanguage>(&rome_path);
+            configuration.javascript = Some(PartialJavascriptConfiguration {
+                formatter: Some(PartialJavascriptFormatter {
+                    enabled: Some(!settings.javascript_formatter_disabled()),
+                    indent_style: Some(plain_indent_style(options.indent_style())),
+                    indent_width: Some(options.indent_width().value()),
+                    line_ending: Some(options.line_ending()),
+                    line_width: Some(options.line_width()),
+                    quote_style: Some(options.quote_style()),
+                    jsx_quote_style: Some(options.jsx_quote_style()),
+                    quote_properties: Some(options.quote_properties()),
+                    trailing_comma: Some(options.trailing_comma()),
+                    semicolons: Some(options.semicolons()),
+                    arrow_parentheses: Some(options.arrow_parentheses()),
+                    bracket_spacing: Some(options.bracket_spacing().value()),
+                    bracket_same_line: Some(options.bracket_same_line().value()),
+                    indent_size: None,
+                }),
+                ..Default::default()
+            });
+        }
+        Some("json") => {
+            let options = settings.format_options::<JsonLanguage>(&rome_path);
+            configuration.json = Some(PartialJsonConfiguration {
+                formatter: Some(PartialJsonFormatter {
+                    enabled: Some(!settings.json_formatter_disabled()),
+                    indent_style: Some(plain_indent_style(options.indent_style())),
+                    indent_width: Some(options.indent_width().value()),
+                    line_ending: Some(options.line_ending()),
+                    line_width: Some(options.line_width()),
+                    indent_size: None,
+                }),
+                ..Default::default()
+            });
+        }
+        Some("css") => {
+            let options = settings.format_options::<CssLanguage>(&rome_path);
+            configuration.css = Some(PartialCssConfiguration {
+                formatter: Some(PartialCssFormatter {
+                    enabled: Some(!settings.css_formatter_disabled()),
+                    indent_style: Some(plain_indent_style(options.indent_style())),
+                    indent_width: Some(options.indent_width().value()),
+                    line_ending: Some(options.line_ending()),
+                    line_width: Some(options.line_width()),
+                    quote_style: Some(options.quote_style()),
+                    indent_size: None,
+                }),
+                ..Default::default()
+            });
+        }
+        _ => {
+            // The global options only apply through the options of the languages
+            let global = configuration.formatter.get_or_insert_with(Default::default);
+            global.indent_style = formatter.indent_style.map(plain_indent_style);
+            global.indent_width = formatter.indent_width.map(|width| width.value());
+            global.line_ending = formatter.line_ending;
+            global.line_width = formatter.line_width;
+        }
+    }
+
+    flatten_to_map(&configuration)
+}
+
+fn plain_indent_style(indent_style: IndentStyle) -> PlainIndentStyle {
+    match indent_style {
+        IndentStyle::Tab => PlainIndentStyle::Tab,
+        IndentStyle::Space => PlainIndentStyle::Space,
+    }
+}
+
+fn print_linter(resolver: &Resolver, settings: &WorkspaceSettings, path: &Path) -> String {
+    let mut output = String::new();
+    let enabled = settings
+        .override_settings
+        .linter_disabled(path)
+        .map_or(settings.linter().enabled, |disabled| !disabled);
+    let origin = resolver.origin(&["linter.enabled".to_string()]);
+    writeln!(output, "  linter.enabled: {enabled}  ({origin})").ok();
+    if !enabled {
+        return output;
+    }
+
+    writeln!(output, "\n  Enabled rules:").ok();
+    let Some(rules) = settings.as_rules(path) else {
+        return output;
+    };
+    for rule_filter in rules.as_enabled_rules() {
+        let RuleFilter::Rule(group, rule) = rule_filter else {
+            continue;
+        };
+        let severity = format!("lint/{group}/{rule}")
+            .parse::<&'static Category>()
+            .ok()
+            .and_then(|category| rules.get_severity_from_code(category));
+        let severity = match severity {
+            Some(Severity::Error) => "error",
+            _ => "warn",
+        };
+
+        let rule_key = [format!("linter.rules.{group}.{rule}")];
+        let group_keys = [
+            format!("linter.rules.{group}.all"),
+            format!("linter.rules.{group}.recommended"),
+            "linter.rules.all".to_string(),
+            "linter.rules.recommended".to_string(),
+        ];
+        match resolver.resolve(&rule_key) {
+            Some(resolved) => {
+                writeln!(
+                    output,
+                    "  {group}/{rule}: {severity}  ({})",
+                    resolved.origin()
+                )
+                .ok();
+                if let Some(options) = resolved
+                    .value
+                    .get("options")
+                    .filter(|options| !options.is_null())
+                {
+                    writeln!(output, "    options: {options}").ok();
+                }
+            }
+            None => {
+                let origin = resolver
+                    .resolve(&group_keys)
+                    .map_or_else(|| "recommended".to_string(), |resolved| resolved.origin());
+                writeln!(output, "  {group}/{rule}: {severity}  ({origin})").ok();
+            }
+        }
+    }
+    output
+}
+
+/// Serializes a configuration, and returns its values keyed by their path.
+///
+/// The options of a rule are kept in a single value, so that `linter.rules.style.useNamingConvention`
+/// is a key. The `null` values are skipped.
+fn flatten_to_map(configuration: &impl Serialize) -> FlatConfiguration {
+    fn flatten(prefix: String, value: Value, into: &mut FlatConfiguration) {
+        let is_rule = prefix.starts_with("linter.rules.") && prefix.split('.').count() == 4;
+        match value {
+            Value::Object(object) if !is_rule => {
+                for (key, value) in object {
+                    let key = if prefix.is_empty() {
+                        key
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    flatten(key, value, into);
+                }
+            }
+            Value::Null => {}
+            value => {
+                into.insert(prefix, value);
+            }
+        }
+    }
+
+    let mut values = FlatConfiguration::new();
+    if let Ok(value) = serde_json::to_value(configuration) {
+        flatten(String::new(), value, &mut values);
+    }
+    values
+}
@@ -1,8 +1,8 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_changed_lines, get_staged_files};
 use crate::cli_options::CliOptions;
-use crate::commands::{get_stdin, validate_configuration_diagnostics};
+use crate::commands::{get_patch_output, get_stdin, validate_configuration_diagnostics};
 use crate::diagnostics::DeprecatedArgument;
-use crate::execute::ReportMode;
+use crate::execute::{settings_hash, watch, ReportMode};
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -16,7 +16,9 @@ use biome_service::configuration::{
 };
 use biome_service::workspace::UpdateSettingsParams;
 use std::ffi::OsString;
+use std::path::PathBuf;
 
+#[derive(Clone)]
 pub(crate) struct FormatCommandPayload {
     pub(crate) javascript_formatter: Option<PartialJavascriptFormatter>,
     pub(crate) json_formatter: Option<PartialJsonFormatter>,
@@ -26,14 +28,69 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) files_configuration: Option<PartialFilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) write: bool,
+    pub(crate) diff: bool,
+    pub(crate) output_patch: Option<PathBuf>,
     pub(crate) cli_options: CliOptions,
     pub(crate) paths: Vec<OsString>,
     pub(crate) changed: bool,
+    pub(crate) changed_lines: bool,
     pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) profile: bool,
 }
 
 /// Handler for the "format" command of the Biome CLI
 pub(crate) fn format(
+    session: CliSession,
+    payload: FormatCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(
+        payload.cli_options.log_level.clone(),
+        payload.cli_options.log_kind.clone(),
+    );
+
+    if !payload.watch {
+        return format_paths(session, payload);
+    }
+    if payload.stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+    if payload.changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--changed",
+        ));
+    }
+    if payload.staged {
+        return Err(CliDiagnostic::incompatible_arguments("--watch", "--staged"));
+    }
+    if payload.changed_lines {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--changed-lines",
+        ));
+    }
+
+    let cli_options = payload.cli_options.clone();
+    let paths = payload.paths.clone();
+    watch(session, &cli_options, paths, |session, paths| {
+        format_paths(
+            session,
+            FormatCommandPayload {
+                paths,
+                ..payload.clone()
+            },
+        )
+    })
+}
+
+/// Runs the formatter once on the paths of the payload
+fn format_paths(
     mut session: CliSession,
     payload: FormatCommandPayload,
 ) -> Result<(), CliDiagnostic> {
@@ -46,12 +103,27 @@ pub(crate) fn format(
         stdin_file_path,
         files_configuration,
         write,
+        diff,
+        output_patch,
         mut json_formatter,
         mut css_formatter,
         since,
         changed,
+        changed_lines,
+        staged,
+        watch: _,
+        cache,
+        profile,
     } = payload;
-    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let patch_output = get_patch_output(diff, output_patch, write.then_some("--write"))?;
+
+    if let (Some(patch_output), Some(_)) = (&patch_output, &stdin_file_path) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            patch_output.as_argument(),
+            "--stdin-file-path",
+        ));
+    }
 
     let loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
@@ -156,14 +228,46 @@ pub(crate) fn format(
     let (vcs_base_path, gitignore_matches) =
         configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
-    if since.is_some() && !changed {
+    if since.is_some() && !changed && !changed_lines {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    if changed && staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed",
+            "--staged",
+        ));
+    }
+
+    if changed_lines && (changed || staged) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--changed-lines",
+            if changed { "--changed" } else { "--staged" },
+        ));
+    }
+
+    let changed_lines = if changed_lines {
+        let changed_lines = get_changed_lines(&session.app.fs, &configuration, since.clone())?;
+        paths = changed_lines.paths();
+        Some(changed_lines)
+    } else {
+        None
+    };
+
     if changed {
         paths = get_changed_files(&session.app.fs, &configuration, since)?;
+    } else if staged {
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--staged",
+                "--stdin-file-path",
+            ));
+        }
+        paths = get_staged_files(&session.app.fs)?;
     }
 
+    let settings_hash = cache.then(|| settings_hash(&configuration));
+
     session
         .app
         .workspace
@@ -193,5 +297,15 @@ pub(crate) fn format(
         })
     };
 
-    execute_mode(execution, session, &cli_options, paths)
+    execute_mode(
+        execution
+            .with_cache(settings_hash)
+            .with_profile(profile)
+            .with_staged(staged)
+            .with_changed_lines(changed_lines)
+            .with_patch_output(patch_output),
+        session,
+        &cli_options,
+        paths,
+    )
 }
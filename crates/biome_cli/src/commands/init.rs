@@ -1,17 +1,310 @@
+use crate::execute::migrate::eslint::{find_eslint_configuration_file, read_eslint_files};
+use crate::execute::migrate::merge_migrated_configuration;
+use crate::execute::migrate::prettier::{find_prettier_configuration_file, read_prettier_files};
 use crate::{CliDiagnostic, CliSession};
-use biome_console::{markup, ConsoleExt, HorizontalLine};
-use biome_service::{create_config, PartialConfiguration};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{markup, Console, ConsoleExt, HorizontalLine};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{Merge, StringSet};
+use biome_formatter::{LineEnding, LineWidth};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_project::PackageJson;
+use biome_service::configuration::linter::{
+    Correctness, RuleConfiguration, RulePlainConfiguration,
+};
+use biome_service::configuration::vcs::{PartialVcsConfiguration, VcsClientKind};
+use biome_service::configuration::{
+    PartialFilesConfiguration, PartialFormatterConfiguration, PlainIndentStyle,
+};
+use biome_service::WorkspaceError;
+use biome_service::{create_config, ConfigurationDiagnostic, DynRef, PartialConfiguration};
+use std::io;
+use std::path::Path;
 
-pub(crate) fn init(mut session: CliSession) -> Result<(), CliDiagnostic> {
+/// The folders where the tools of the ecosystem usually write their output
+const BUILD_OUTPUT_FOLDERS: [&str; 9] = [
+    "dist",
+    "build",
+    "out",
+    "coverage",
+    ".next",
+    ".nuxt",
+    ".output",
+    ".svelte-kit",
+    ".turbo",
+];
+
+const EDITORCONFIG_FILE: &str = ".editorconfig";
+
+pub(crate) fn init(mut session: CliSession, yes: bool) -> Result<(), CliDiagnostic> {
     let fs = &mut session.app.fs;
-    create_config(fs, PartialConfiguration::init())?;
+    let console = &mut *session.app.console;
+
+    // The questions aren't asked if the configuration can't be created
+    if fs.path_exists(Path::new(fs.config_name())) {
+        return Err(
+            WorkspaceError::Configuration(ConfigurationDiagnostic::new_already_exists()).into(),
+        );
+    }
+
+    let mut configuration = PartialConfiguration::init();
+    let mut project_settings = Vec::new();
+
+    if let Some(package_json) = read_package_json(fs) {
+        let typescript = package_json.dependency("typescript").is_some()
+            || fs.path_exists(Path::new("tsconfig.json"));
+        let react = package_json.dependency("react").is_some();
+        let detected: Vec<_> = [("TypeScript", typescript), ("React", react)]
+            .into_iter()
+            .filter_map(|(name, is_used)| is_used.then_some(name))
+            .collect();
+        if !detected.is_empty() {
+            project_settings.push(format!("Detected {}.", detected.join(" and ")));
+        }
+
+        if react
+            && confirm(
+                console,
+                yes,
+                "React is used. Do you want to enable the rule useHookAtTopLevel, which checks the rules of hooks?",
+            )
+        {
+            let rules = configuration
+                .linter
+                .get_or_insert_with(Default::default)
+                .rules
+                .get_or_insert_with(Default::default);
+            rules.correctness = Some(Correctness {
+                use_hook_at_top_level: Some(RuleConfiguration::Plain(
+                    RulePlainConfiguration::Error,
+                )),
+                ..Default::default()
+            });
+            project_settings.push("Enabled the rule useHookAtTopLevel.".to_string());
+        }
+    }
+
+    if let Some(path) = find_prettier_configuration_file(fs) {
+        if confirm(
+            console,
+            yes,
+            &format!("Found the Prettier configuration {path}. Do you want to migrate it?"),
+        ) {
+            let prettier_configuration = read_prettier_files(fs, console)?;
+            if prettier_configuration.has_configuration() {
+                merge_migrated_configuration(
+                    &mut configuration,
+                    prettier_configuration.as_biome_configuration(),
+                );
+                project_settings.push(format!("Migrated the Prettier configuration {path}."));
+            }
+        }
+    }
 
-    session.app.console.log(markup! {
+    if fs.path_exists(Path::new(EDITORCONFIG_FILE))
+        && confirm(
+            console,
+            yes,
+            "Found .editorconfig. Do you want the formatter to use its settings?",
+        )
+    {
+        let mut content = String::new();
+        fs.open_with_options(
+            Path::new(EDITORCONFIG_FILE),
+            OpenOptions::default().read(true),
+        )
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .map_err(CliDiagnostic::io_error)?;
+        if let Some(formatter) = editorconfig_to_formatter_configuration(&content) {
+            // The migration of Prettier sets all the options, so `.editorconfig` is applied
+            // afterwards to keep its settings
+            configuration
+                .formatter
+                .get_or_insert_with(PartialFormatterConfiguration::default)
+                .merge_with(formatter);
+            project_settings.push("Migrated the settings of .editorconfig.".to_string());
+        }
+    }
+
+    if let Some(path) = find_eslint_configuration_file(fs) {
+        if confirm(
+            console,
+            yes,
+            &format!("Found the ESLint configuration {path}. Do you want to migrate it?"),
+        ) {
+            let eslint_configuration = read_eslint_files(fs, console)?;
+            eslint_configuration.print_report(console);
+            merge_migrated_configuration(
+                &mut configuration,
+                eslint_configuration.as_biome_configuration(),
+            );
+            project_settings.push(format!("Migrated the ESLint configuration {path}."));
+        }
+    }
+
+    if fs.path_exists(Path::new(".git"))
+        && confirm(
+            console,
+            yes,
+            "The project uses git. Do you want to enable the VCS integration, so Biome ignores the files of .gitignore?",
+        )
+    {
+        configuration.vcs = Some(PartialVcsConfiguration {
+            enabled: Some(true),
+            client_kind: Some(VcsClientKind::Git),
+            use_ignore_file: Some(true),
+            ..Default::default()
+        });
+        project_settings.push("Enabled the VCS integration.".to_string());
+    }
+
+    let build_output_folders: Vec<_> = BUILD_OUTPUT_FOLDERS
+        .into_iter()
+        .filter(|folder| fs.path_exists(Path::new(folder)))
+        .collect();
+    if !build_output_folders.is_empty()
+        && confirm(
+            console,
+            yes,
+            &format!(
+                "Found the build output folders {}. Do you want Biome to ignore them?",
+                build_output_folders.join(", ")
+            ),
+        )
+    {
+        let files = configuration
+            .files
+            .get_or_insert_with(PartialFilesConfiguration::default);
+        let ignore = files.ignore.get_or_insert_with(StringSet::default);
+        ignore.extend(
+            build_output_folders
+                .iter()
+                .map(|&folder| folder.to_string()),
+        );
+        project_settings.push(format!(
+            "Ignored the build output folders {}.",
+            build_output_folders.join(", ")
+        ));
+    }
+
+    create_config(fs, configuration)?;
+
+    print_welcome(console, &project_settings);
+
+    Ok(())
+}
+
+/// Asks a question whose answer is yes or no. The default answer is yes, and it's the
+/// answer of all the questions when the option `--yes` is passed. The questions aren't asked
+/// when the console isn't interactive, and the project settings aren't applied.
+fn confirm(console: &mut dyn Console, yes: bool, question: &str) -> bool {
+    if yes {
+        return true;
+    }
+    if !console.is_interactive() {
+        return false;
+    }
+    console.append(markup! {
+        <Emphasis>"? "</Emphasis>{question}<Dim>" (Y/n) "</Dim>
+    });
+    match console.read_line() {
+        Some(answer) => {
+            let answer = answer.trim();
+            answer.is_empty()
+                || answer.eq_ignore_ascii_case("y")
+                || answer.eq_ignore_ascii_case("yes")
+        }
+        None => {
+            // The input ended, the default answer is used
+            console.log(markup! {""});
+            true
+        }
+    }
+}
+
+/// Reads the `package.json` of the project. It returns [None] if it doesn't exist or if it
+/// can't be parsed.
+fn read_package_json(fs: &DynRef<'_, dyn FileSystem>) -> Option<PackageJson> {
+    let mut content = String::new();
+    fs.open_with_options(Path::new("package.json"), OpenOptions::default().read(true))
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .ok()?;
+    // The dependencies whose version can't be parsed, e.g. `workspace:*`, are skipped
+    deserialize_from_json_str::<PackageJson>(&content, JsonParserOptions::default(), "")
+        .into_deserialized()
+}
+
+/// Converts the properties of the section `[*]` of an `.editorconfig` file, which apply to all
+/// the files, into the configuration of the formatter. It returns [None] if the section
+/// doesn't have any property supported by Biome.
+fn editorconfig_to_formatter_configuration(content: &str) -> Option<PartialFormatterConfiguration> {
+    let mut formatter = PartialFormatterConfiguration::default();
+    let mut in_global_section = false;
+    let mut tab_width = None;
+    let mut indent_size_is_tab = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            in_global_section = section.trim() == "*";
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if !in_global_section {
+            continue;
+        }
+        let value = value.trim().to_ascii_lowercase();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "indent_style" => {
+                formatter.indent_style = match value.as_str() {
+                    "tab" => Some(PlainIndentStyle::Tab),
+                    "space" => Some(PlainIndentStyle::Space),
+                    _ => None,
+                }
+            }
+            "indent_size" if value == "tab" => indent_size_is_tab = true,
+            "indent_size" => formatter.indent_width = value.parse().ok(),
+            "tab_width" => tab_width = value.parse().ok(),
+            "end_of_line" => {
+                formatter.line_ending = match value.as_str() {
+                    "lf" => Some(LineEnding::Lf),
+                    "crlf" => Some(LineEnding::Crlf),
+                    "cr" => Some(LineEnding::Cr),
+                    _ => None,
+                }
+            }
+            "max_line_length" => {
+                formatter.line_width = value
+                    .parse::<u16>()
+                    .ok()
+                    .and_then(|line_width| LineWidth::try_from(line_width).ok())
+            }
+            _ => {}
+        }
+    }
+    // `indent_size = tab` means that the indentation has the width of a tab
+    if indent_size_is_tab || formatter.indent_width.is_none() {
+        formatter.indent_width = formatter.indent_width.or(tab_width);
+    }
+
+    (formatter != PartialFormatterConfiguration::default()).then_some(formatter)
+}
+
+fn print_welcome(console: &mut dyn Console, project_settings: &[String]) {
+    console.log(markup! {
 "\n"<Inverse>"Welcome to Biome! Let's get you started..."</Inverse>"
 
 "<Info><Emphasis>"Files created "</Emphasis></Info>{HorizontalLine::new(106)}"
 
-  "<Dim>"- "</Dim><Emphasis>"biome.json: "</Emphasis>"Your project configuration. Documentation: "<Hyperlink href="https://biomejs.dev/reference/configuration">"https://biomejs.dev/reference/configuration"</Hyperlink>"
+  "<Dim>"- "</Dim><Emphasis>"biome.json: "</Emphasis>"Your project configuration. Documentation: "<Hyperlink href="https://biomejs.dev/reference/configuration">"https://biomejs.dev/reference/configuration"</Hyperlink>{ProjectSettings(project_settings)}"
 
 "<Info><Emphasis>"Next Steps "</Emphasis></Info>{HorizontalLine::new(109)}"
 
@@ -28,6 +321,25 @@ pub(crate) fn init(mut session: CliSession) -> Result<(), CliDiagnostic> {
      Ask questions, get support, or contribute by participating on GitHub ("<Hyperlink href="https://github.com/biomejs/biome">"https://github.com/biomejs/biome"</Hyperlink>"),
      or join our community Discord ("<Hyperlink href="https://discord.gg/BypW39g6Yc">"https://discord.gg/BypW39g6Yc"</Hyperlink>")"
     });
+}
 
-    Ok(())
+/// The section of the welcome message that lists the settings applied after inspecting the
+/// project. Nothing is printed if no setting was applied.
+struct ProjectSettings<'a>(&'a [String]);
+
+impl Display for ProjectSettings<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        fmt.write_markup(markup! {
+            "\n\n"<Info><Emphasis>"Project settings "</Emphasis></Info>{HorizontalLine::new(103)}"\n"
+        })?;
+        for setting in self.0 {
+            fmt.write_markup(markup! {
+                "\n  "<Dim>"- "</Dim>{setting}
+            })?;
+        }
+        Ok(())
+    }
 }
@@ -1,9 +1,12 @@
 use crate::cli_options::CliOptions;
+use crate::commands::{validate_configuration_diagnostics, MigrateSubCommand};
 use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::{execute_mode, Execution, TraversalMode};
 use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
 use biome_service::configuration::{load_configuration, LoadedConfiguration};
+use biome_service::workspace::UpdateSettingsParams;
 use biome_service::ConfigurationBasePath;
+use std::ffi::OsString;
 use std::path::PathBuf;
 
 /// Handler for the "check" command of the Biome CLI
@@ -12,7 +15,12 @@ pub(crate) fn migrate(
     cli_options: CliOptions,
     write: bool,
     prettier: bool,
+    sub_command: Option<MigrateSubCommand>,
 ) -> Result<(), CliDiagnostic> {
+    if let Some(MigrateSubCommand::EslintComments { paths }) = sub_command {
+        return migrate_eslint_comments(session, cli_options, write, paths);
+    }
+
     let base_path = match cli_options.config_path.as_ref() {
         None => ConfigurationBasePath::default(),
         Some(path) => ConfigurationBasePath::FromUser(PathBuf::from(path)),
@@ -20,6 +28,7 @@ pub(crate) fn migrate(
     let LoadedConfiguration {
         configuration: _,
         diagnostics: _,
+        sources: _,
         directory_path,
         file_path,
     } = load_configuration(&session.app.fs, base_path)?;
@@ -32,6 +41,7 @@ pub(crate) fn migrate(
                 configuration_file_path: path,
                 configuration_directory_path: directory_path,
                 prettier,
+                eslint: matches!(sub_command, Some(MigrateSubCommand::Eslint)),
             }),
             session,
             &cli_options,
@@ -43,3 +53,51 @@ pub(crate) fn migrate(
         }))
     }
 }
+
+/// Rewrites the ESLint suppression comments of the files in `paths`.
+///
+/// The configuration is loaded like the `lint` command does, because the lint diagnostics
+/// tell which lines are suppressed by the `eslint-disable` and `eslint-enable` comments.
+fn migrate_eslint_comments(
+    session: CliSession,
+    cli_options: CliOptions,
+    write: bool,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
+
+    let loaded_configuration =
+        load_configuration(&session.app.fs, cli_options.as_configuration_base_path())?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+
+    let LoadedConfiguration {
+        configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+
+    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            working_directory: session.app.fs.working_directory(),
+            configuration,
+            vcs_base_path,
+            gitignore_matches,
+        })?;
+
+    execute_mode(
+        Execution::new(TraversalMode::MigrateEslintComments { write }),
+        session,
+        &cli_options,
+        paths,
+    )
+}
@@ -1,6 +1,6 @@
 use crate::cli_options::{cli_options, CliOptions, ColorsArg};
 use crate::diagnostics::DeprecatedConfigurationFile;
-use crate::execute::Stdin;
+use crate::execute::{PatchOutput, Stdin};
 use crate::logging::LoggingKind;
 use crate::{CliDiagnostic, LoggingLevel, VERSION};
 use biome_console::{markup, Console, ConsoleExt};
@@ -17,6 +17,7 @@ use biome_service::configuration::{
     PartialLinterConfiguration,
 };
 use biome_service::documentation::Doc;
+use biome_service::workspace::RuleSelector;
 use biome_service::{ConfigurationDiagnostic, PartialConfiguration, WorkspaceError};
 use bpaf::Bpaf;
 use std::ffi::OsString;
@@ -24,6 +25,7 @@ use std::path::PathBuf;
 
 pub(crate) mod check;
 pub(crate) mod ci;
+pub(crate) mod config;
 pub(crate) mod daemon;
 pub(crate) mod explain;
 pub(crate) mod format;
@@ -31,11 +33,14 @@ pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod search;
 pub(crate) mod version;
 
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options, version(VERSION))]
 /// Biome official CLI. Use it to check the health of your project or run it to check single files.
+// The command is parsed once, the size of the configurations it holds doesn't matter
+#[allow(clippy::large_enum_variant)]
 pub enum BiomeCommand {
     /// Shows the Biome version information and quit
     #[bpaf(command)]
@@ -70,6 +75,13 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Print the changes that `--apply` would make as a unified diff, without writing them.
+        /// The diff can be applied with `git apply`.
+        #[bpaf(long("diff"), switch)]
+        diff: bool,
+        /// Write the changes that `--apply` would make as a unified diff to the given file, without writing them.
+        #[bpaf(long("output-patch"), argument("PATH"))]
+        output_patch: Option<PathBuf>,
         /// Allow to enable or disable the formatter check.
         #[bpaf(
             long("formatter-enabled"),
@@ -105,12 +117,34 @@ pub enum BiomeCommand {
         /// configuration will be linted.
         #[bpaf(long("changed"), switch)]
         changed: bool,
+        /// When set to true, only the diagnostics and the formatting of the lines that have been changed
+        /// compared to your `defaultBranch` configuration will be reported.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
 
         /// Use this to specify the base branch to compare against when you're using the --changed
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the files that are staged in the git index will be processed.
+        /// Fixes and formatting are applied to the staged content, and the results are staged again.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+
+        /// Keep running after the first run, and process the files again every time they change.
+        /// When the configuration file changes, all the files are processed again.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Store the results of the processed files in `node_modules/.cache/biome`, and skip the files that didn't change since the last run.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// Print the slowest files, and the time spent by each lint rule and by each phase of the processing: parsing, building the semantic model and the control flow graph, running the rules and formatting.
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -124,9 +158,29 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Print the changes that `--apply` would make as a unified diff, without writing them.
+        /// The diff can be applied with `git apply`.
+        #[bpaf(long("diff"), switch)]
+        diff: bool,
+        /// Write the changes that `--apply` would make as a unified diff to the given file, without writing them.
+        #[bpaf(long("output-patch"), argument("PATH"))]
+        output_patch: Option<PathBuf>,
         #[bpaf(external(partial_linter_configuration), hide_usage, optional)]
         linter_configuration: Option<PartialLinterConfiguration>,
 
+        /// Run only the given rule or group of rules, even if it's turned off in the configuration.
+        /// The option can be passed multiple times.
+        ///
+        /// Example: `biome lint --only=suspicious/noDebugger --only=style`
+        #[bpaf(long("only"), argument("GROUP|RULE"), many)]
+        only: Vec<RuleSelector>,
+
+        /// Skip the given rule or group of rules. The option can be passed multiple times.
+        ///
+        /// Example: `biome lint --skip=style/useNamingConvention`
+        #[bpaf(long("skip"), argument("GROUP|RULE"), many)]
+        skip: Vec<RuleSelector>,
+
         #[bpaf(external(partial_vcs_configuration), optional, hide_usage)]
         vcs_configuration: Option<PartialVcsConfiguration>,
 
@@ -146,10 +200,28 @@ pub enum BiomeCommand {
         /// configuration will be linted.
         #[bpaf(long("changed"), switch)]
         changed: bool,
+        /// When set to true, only the diagnostics and the formatting of the lines that have been changed
+        /// compared to your `defaultBranch` configuration will be reported.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
         /// Use this to specify the base branch to compare against when you're using the --changed
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// When set to true, only the files that are staged in the git index will be processed.
+        /// Fixes and formatting are applied to the staged content, and the results are staged again.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+        /// Keep running after the first run, and process the files again every time they change.
+        /// When the configuration file changes, all the files are processed again.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+        /// Store the results of the processed files in `node_modules/.cache/biome`, and skip the files that didn't change since the last run.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+        /// Print the slowest files, and the time spent by each lint rule and by each phase of the processing: parsing, building the semantic model and the control flow graph, running the rules and formatting.
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -189,16 +261,47 @@ pub enum BiomeCommand {
         #[bpaf(switch)]
         write: bool,
 
+        /// Print the changes that `--write` would make as a unified diff, without writing them.
+        /// The diff can be applied with `git apply`.
+        #[bpaf(long("diff"), switch)]
+        diff: bool,
+
+        /// Write the changes that `--write` would make as a unified diff to the given file, without writing them.
+        #[bpaf(long("output-patch"), argument("PATH"))]
+        output_patch: Option<PathBuf>,
+
         /// When set to true, only the files that have been changed compared to your `defaultBranch`
         /// configuration will be linted.
         #[bpaf(long("changed"), switch)]
         changed: bool,
+        /// When set to true, only the diagnostics and the formatting of the lines that have been changed
+        /// compared to your `defaultBranch` configuration will be reported.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
 
         /// Use this to specify the base branch to compare against when you're using the --changed
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// When set to true, only the files that are staged in the git index will be processed.
+        /// Fixes and formatting are applied to the staged content, and the results are staged again.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+
+        /// Keep running after the first run, and process the files again every time they change.
+        /// When the configuration file changes, all the files are processed again.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Store the results of the processed files in `node_modules/.cache/biome`, and skip the files that didn't change since the last run.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// Print the slowest files, and the time spent by each lint rule and by each phase of the processing: parsing, building the semantic model and the control flow graph, running the rules and formatting.
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -227,20 +330,66 @@ pub enum BiomeCommand {
         /// configuration will be linted.
         #[bpaf(long("changed"), switch)]
         changed: bool,
+        /// When set to true, only the diagnostics and the formatting of the lines that have been changed
+        /// compared to your `defaultBranch` configuration will be reported.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
 
         /// Use this to specify the base branch to compare against when you're using the --changed
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Store the results of the processed files in `node_modules/.cache/biome`, and skip the files that didn't change since the last run.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// Print the slowest files, and the time spent by each lint rule and by each phase of the processing: parsing, building the semantic model and the control flow graph, running the rules and formatting.
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
+
+        /// Single file, single path or list of paths
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+
+    /// Searches the code that has the same syntax shape as a pattern, ignoring the formatting and the comments.
+    ///
+    /// The pattern is parsed with the parser of the language of each file. In the pattern,
+    /// `$name` matches any node, and `$$$name` matches any number of elements of a list,
+    /// such as the arguments of a call. In JSON files, these placeholders are written inside strings.
+    ///
+    /// Example: `biome search 'console.$method($$$args)' ./src`
+    #[bpaf(command)]
+    Search {
+        #[bpaf(external(partial_vcs_configuration), optional, hide_usage)]
+        vcs_configuration: Option<PartialVcsConfiguration>,
+
+        #[bpaf(external(partial_files_configuration), optional, hide_usage)]
+        files_configuration: Option<PartialFilesConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The code to search
+        #[bpaf(positional("PATTERN"))]
+        pattern: String,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
     },
 
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
+    ///
+    /// The project is inspected first: Biome offers to migrate the Prettier and ESLint configurations,
+    /// to use the settings of `.editorconfig`, to enable the VCS integration, and to ignore the build output folders.
     #[bpaf(command)]
-    Init,
+    Init {
+        /// Accept all the suggestions without asking
+        #[bpaf(long("yes"), short('y'), switch)]
+        yes: bool,
+    },
     /// Acts as a server for the Language Server Protocol over stdin/stdout
     #[bpaf(command("lsp-proxy"))]
     LspProxy(
@@ -251,9 +400,10 @@ pub enum BiomeCommand {
     /// It updates the configuration when there are breaking changes
     #[bpaf(command)]
     Migrate {
-        /// It attempts to find the files `.prettierrc`/`prettier.json` and `.prettierignore`, and map
-        /// Prettier's configuration into `biome.json`
-        #[bpaf(long("prettier"), switch, hide, hide_usage)]
+        /// It attempts to find the Prettier configuration, from the files `.prettierrc`, `.prettierrc.json`,
+        /// `.prettierrc.yaml`, `.prettierrc.yml`, `.prettierrc.toml` or from the `prettier` key of `package.json`,
+        /// and the file `.prettierignore`, and map Prettier's configuration into `biome.json`
+        #[bpaf(long("prettier"), switch)]
         prettier: bool,
 
         #[bpaf(external, hide_usage)]
@@ -262,8 +412,15 @@ pub enum BiomeCommand {
         /// Writes the new configuration file to disk
         #[bpaf(long("write"), switch)]
         write: bool,
+
+        #[bpaf(external(migrate_sub_command), optional, hide_usage)]
+        sub_command: Option<MigrateSubCommand>,
     },
 
+    /// Commands to inspect the configuration
+    #[bpaf(command)]
+    Config(#[bpaf(external(config_sub_command), hide_usage)] ConfigSubCommand),
+
     /// A command to retrieve the documentation of various aspects of the CLI.
     ///
     /// ## Examples
@@ -294,6 +451,41 @@ pub enum BiomeCommand {
     PrintSocket,
 }
 
+#[derive(Debug, Clone, Bpaf)]
+pub enum MigrateSubCommand {
+    /// It attempts to find the ESLint configuration file in the working directory, and maps its rules, `overrides` and `ignorePatterns` into `biome.json`
+    #[bpaf(command("eslint"))]
+    Eslint,
+
+    /// It rewrites the `eslint-disable` comments of the files into `biome-ignore` comments, using the Biome rules that correspond to the ESLint rules
+    #[bpaf(command("eslint-comments"))]
+    EslintComments {
+        /// Single file, single path or list of paths
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+}
+
+#[derive(Debug, Clone, Bpaf)]
+pub enum ConfigSubCommand {
+    /// Prints the configuration that applies to a file: the formatter options, the enabled rules with their severity and options,
+    /// and the configuration file, `overrides` entry or command line argument that sets each value
+    ///
+    /// Example: `biome config print src/index.ts`
+    #[bpaf(command("print"))]
+    Print {
+        #[bpaf(external(partial_configuration), hide_usage, optional)]
+        configuration: Option<PartialConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The file to print the configuration of. The file doesn't need to exist on disk.
+        #[bpaf(positional("PATH"))]
+        path: PathBuf,
+    },
+}
+
 impl BiomeCommand {
     pub const fn get_color(&self) -> Option<&ColorsArg> {
         match self {
@@ -303,11 +495,15 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
-            | BiomeCommand::Migrate { cli_options, .. } => cli_options.colors.as_ref(),
+            | BiomeCommand::Migrate { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Config(ConfigSubCommand::Print { cli_options, .. }) => {
+                cli_options.colors.as_ref()
+            }
             BiomeCommand::LspProxy(_)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Init { .. }
             | BiomeCommand::Explain { .. }
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => None,
@@ -322,8 +518,12 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
-            | BiomeCommand::Migrate { cli_options, .. } => cli_options.use_server,
-            BiomeCommand::Init
+            | BiomeCommand::Migrate { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Config(ConfigSubCommand::Print { cli_options, .. }) => {
+                cli_options.use_server
+            }
+            BiomeCommand::Init { .. }
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
             | BiomeCommand::Explain { .. }
@@ -343,12 +543,16 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate { cli_options, .. } => cli_options.verbose,
+            | BiomeCommand::Migrate { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Config(ConfigSubCommand::Print { cli_options, .. }) => {
+                cli_options.verbose
+            }
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Init { .. }
             | BiomeCommand::Explain { .. }
             | BiomeCommand::LspProxy(_)
             | BiomeCommand::RunServer { .. }
@@ -362,13 +566,17 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate { cli_options, .. } => cli_options.log_level.clone(),
+            | BiomeCommand::Migrate { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Config(ConfigSubCommand::Print { cli_options, .. }) => {
+                cli_options.log_level.clone()
+            }
             BiomeCommand::Version(_)
             | BiomeCommand::LspProxy(_)
             | BiomeCommand::Rage(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Init { .. }
             | BiomeCommand::Explain { .. }
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => LoggingLevel::default(),
@@ -380,13 +588,17 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate { cli_options, .. } => cli_options.log_kind.clone(),
+            | BiomeCommand::Migrate { cli_options, .. }
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Config(ConfigSubCommand::Print { cli_options, .. }) => {
+                cli_options.log_kind.clone()
+            }
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
             | BiomeCommand::LspProxy(_)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Init { .. }
             | BiomeCommand::Explain { .. }
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => LoggingKind::default(),
@@ -461,3 +673,34 @@ pub(crate) fn get_stdin(
 
     Ok(stdin)
 }
+
+/// Computes where the changes are printed as a unified diff, if the options `--diff` or `--output-patch` are passed.
+///
+/// ## Errors
+/// - If both options are passed, or if `write_argument` is [Some]: the changes can't be written and printed as a patch at the same time.
+pub(crate) fn get_patch_output(
+    diff: bool,
+    output_patch: Option<PathBuf>,
+    write_argument: Option<&str>,
+) -> Result<Option<PatchOutput>, CliDiagnostic> {
+    let patch_output = match (diff, output_patch) {
+        (true, Some(_)) => {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--diff",
+                "--output-patch",
+            ))
+        }
+        (true, None) => Some(PatchOutput::Stdout),
+        (false, Some(path)) => Some(PatchOutput::File(path)),
+        (false, None) => None,
+    };
+
+    if let (Some(patch_output), Some(write_argument)) = (&patch_output, write_argument) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            patch_output.as_argument(),
+            write_argument,
+        ));
+    }
+
+    Ok(patch_output)
+}
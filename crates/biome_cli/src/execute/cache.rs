@@ -0,0 +1,206 @@
+use crate::execute::Execution;
+use crate::VERSION;
+use biome_diagnostics::serde::Diagnostic as SerdeDiagnostic;
+use biome_fs::{FileSystem, FileSystemExt};
+use biome_service::PartialConfiguration;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// The directory, relative to the working directory, where the cache files are stored
+const CACHE_DIRECTORY: &str = "node_modules/.cache/biome";
+
+/// Computes the hash of the resolved configuration.
+///
+/// The hash is stored inside the cache, and a change of the configuration invalidates all its entries.
+pub(crate) fn settings_hash(configuration: &PartialConfiguration) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(configuration)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+pub(crate) fn content_hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The content of a cache file, as it's saved on disk
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheContent {
+    /// The version of Biome that created the cache
+    version: String,
+    /// The hash of the configuration used when the cache was created
+    settings_hash: u64,
+    /// The cached results, mapped by file path
+    files: BTreeMap<String, CacheEntry>,
+}
+
+/// The results of the processing of a single file
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CacheEntry {
+    /// The hash of the content of the file the results belong to
+    content_hash: u64,
+    /// The diagnostics emitted by the linter, if the file was linted
+    lint: Option<CachedLintResult>,
+    /// Whether the file is correctly formatted
+    formatted: bool,
+    /// Whether the imports of the file are already sorted
+    imports_sorted: bool,
+}
+
+impl CacheEntry {
+    pub(crate) fn lint(&self) -> Option<&CachedLintResult> {
+        self.lint.as_ref()
+    }
+
+    pub(crate) const fn is_formatted(&self) -> bool {
+        self.formatted
+    }
+
+    pub(crate) const fn has_imports_sorted(&self) -> bool {
+        self.imports_sorted
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct CachedLintResult {
+    pub(crate) diagnostics: Vec<SerdeDiagnostic>,
+    pub(crate) errors: usize,
+}
+
+/// A cache of the results of the traversal, stored on disk and shared across runs of the CLI.
+///
+/// Each command has its own cache file, whose entries are invalidated as a whole when the version of
+/// Biome or the configuration change. Single entries are invalidated when the content of their file changes.
+pub(crate) struct FileCache {
+    /// The path of the cache file
+    path: PathBuf,
+    /// The hash of the configuration used by the current run
+    settings_hash: u64,
+    /// The entries of the cache, mapped by file path. Entries are only updated once the result of
+    /// a feature is available, so a panic can't leave them in an inconsistent state
+    entries: AssertUnwindSafe<DashMap<String, CacheEntry>>,
+}
+
+impl FileCache {
+    /// It reads the cache file of the current command. Missing, unreadable or stale caches result
+    /// in an empty cache.
+    pub(crate) fn load(fs: &dyn FileSystem, execution: &Execution, settings_hash: u64) -> Self {
+        let path = fs
+            .working_directory()
+            .unwrap_or_default()
+            .join(CACHE_DIRECTORY)
+            .join(format!("{}.json", execution.traversal_mode()));
+
+        let entries = match read_cache_content(fs, &path) {
+            Ok(content) if content.version == VERSION && content.settings_hash == settings_hash => {
+                content.files.into_iter().collect()
+            }
+            Ok(_) => {
+                debug!("The cache at {} is stale, discarding it", path.display());
+                DashMap::default()
+            }
+            Err(err) => {
+                debug!("Could not read the cache at {}: {err}", path.display());
+                DashMap::default()
+            }
+        };
+
+        Self {
+            path,
+            settings_hash,
+            entries: AssertUnwindSafe(entries),
+        }
+    }
+
+    /// Returns the entry of the file at `path`, only if it was created for the given `content`
+    pub(crate) fn get(&self, path: &Path, content: &str) -> Option<CacheEntry> {
+        let entry = self.entries.get(&path.display().to_string())?;
+        (entry.content_hash == content_hash(content)).then(|| entry.clone())
+    }
+
+    pub(crate) fn record_lint(
+        &self,
+        path: &Path,
+        content: &str,
+        diagnostics: Vec<SerdeDiagnostic>,
+        errors: usize,
+    ) {
+        self.update(path, content, |entry| {
+            entry.lint = Some(CachedLintResult {
+                diagnostics,
+                errors,
+            })
+        });
+    }
+
+    pub(crate) fn record_formatted(&self, path: &Path, content: &str) {
+        self.update(path, content, |entry| entry.formatted = true);
+    }
+
+    pub(crate) fn record_imports_sorted(&self, path: &Path, content: &str) {
+        self.update(path, content, |entry| entry.imports_sorted = true);
+    }
+
+    /// Updates the entry of the file at `path`, resetting it first if it was created for a different content
+    fn update(&self, path: &Path, content: &str, func: impl FnOnce(&mut CacheEntry)) {
+        let content_hash = content_hash(content);
+        let mut entry = self.entries.entry(path.display().to_string()).or_default();
+        if entry.content_hash != content_hash {
+            *entry = CacheEntry {
+                content_hash,
+                ..CacheEntry::default()
+            };
+        }
+        func(&mut entry);
+    }
+
+    /// Writes the cache to disk. Failures are logged and otherwise ignored, the cache isn't
+    /// essential to the execution of the command.
+    pub(crate) fn persist(self, fs: &dyn FileSystem) {
+        let content = CacheContent {
+            version: VERSION.to_string(),
+            settings_hash: self.settings_hash,
+            files: self.entries.0.into_iter().collect(),
+        };
+        if let Err(err) = write_cache_content(fs, &self.path, &content) {
+            warn!(
+                "Could not write the cache at {}: {err}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+fn read_cache_content(fs: &dyn FileSystem, path: &Path) -> io::Result<CacheContent> {
+    let mut buffer = String::new();
+    fs.open(path)?.read_to_string(&mut buffer)?;
+    serde_json::from_str(&buffer).map_err(io::Error::from)
+}
+
+fn write_cache_content(fs: &dyn FileSystem, path: &Path, content: &CacheContent) -> io::Result<()> {
+    let content = serde_json::to_string(content).map_err(io::Error::from)?;
+    let mut file = match fs.create(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            // The cache directory doesn't exist yet
+            if let Some(parent) = path.parent() {
+                fs.create_dir_all(parent)?;
+            }
+            fs.create(path)?
+        }
+        Err(err) => return Err(err),
+    };
+    file.set_content(content.as_bytes())
+}
@@ -3,6 +3,7 @@ use biome_diagnostics::{
     Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Severity, Visit,
 };
 use biome_text_edit::TextEdit;
+use biome_text_size::TextRange;
 use std::io;
 
 #[derive(Debug, Diagnostic)]
@@ -68,6 +69,52 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "migrate",
+	severity = Information,
+	message = "Suppression comments can be migrated:"
+)]
+pub(crate) struct EslintCommentsDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "migrate", severity = Warning)]
+pub(crate) struct UnsupportedEslintRuleDiagnostic {
+    #[location(span)]
+    pub(crate) span: TextRange,
+    #[message]
+    #[description]
+    pub(crate) message: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The code matches the pattern."
+)]
+pub(crate) struct SearchMatchDiagnostic {
+    #[location(span)]
+    pub(crate) span: TextRange,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern can't be parsed in the language of this file, the file was skipped.",
+    tags(VERBOSE)
+)]
+pub(crate) struct InvalidSearchPatternDiagnostic {
+    #[location(resource)]
+    pub(crate) file_path: String,
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
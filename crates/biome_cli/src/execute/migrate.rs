@@ -1,7 +1,10 @@
-mod prettier;
+pub(crate) mod eslint;
+pub(crate) mod eslint_comments;
+pub(crate) mod prettier;
 
 use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::diagnostics::{ContentDiffAdvice, MigrateDiffDiagnostic};
+use crate::execute::migrate::eslint::read_eslint_files;
 use crate::execute::migrate::prettier::read_prettier_files;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
@@ -30,6 +33,7 @@ pub(crate) struct MigratePayload<'a> {
     pub(crate) verbose: bool,
     #[allow(unused)]
     pub(crate) prettier: bool,
+    pub(crate) eslint: bool,
 }
 
 pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic> {
@@ -40,6 +44,7 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
         configuration_directory_path,
         verbose,
         prettier,
+        eslint,
     } = migrate_payload;
     let mut cache = NodeCache::default();
     let fs = &session.app.fs;
@@ -119,10 +124,21 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
 
     let new_configuration_content = tree.to_string();
 
-    if prettier {
+    let migrated_configuration = if prettier {
         let prettier_configuration = read_prettier_files(fs, console)?;
+        prettier_configuration
+            .has_configuration()
+            .then(|| prettier_configuration.as_biome_configuration())
+    } else if eslint {
+        let eslint_configuration = read_eslint_files(fs, console)?;
+        eslint_configuration.print_report(console);
+        Some(eslint_configuration.as_biome_configuration())
+    } else {
+        None
+    };
 
-        if prettier_configuration.has_configuration() {
+    if prettier || eslint {
+        if let Some(migrated_configuration) = migrated_configuration {
             let configuration = deserialize_from_json_str::<PartialConfiguration>(
                 configuration_content.as_str(),
                 JsonParserOptions::default(),
@@ -130,7 +146,7 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
             )
             .into_deserialized();
             if let Some(mut configuration) = configuration {
-                configuration.merge_with(prettier_configuration.as_biome_configuration());
+                merge_migrated_configuration(&mut configuration, migrated_configuration);
 
                 let new_content = serde_json::to_string(&configuration).map_err(|err| {
                     CliDiagnostic::MigrateError(MigrationDiagnostic {
@@ -222,3 +238,17 @@ pub(crate) fn run(migrate_payload: MigratePayload) -> Result<(), CliDiagnostic>
 
     Ok(())
 }
+
+/// Merges the configuration migrated from another tool into the current configuration.
+/// The migrated `overrides` are appended to the current ones, so they take precedence.
+pub(crate) fn merge_migrated_configuration(
+    configuration: &mut PartialConfiguration,
+    mut migrated_configuration: PartialConfiguration,
+) {
+    let mut overrides = configuration.overrides.take().unwrap_or_default();
+    if let Some(migrated_overrides) = migrated_configuration.overrides.take() {
+        overrides.0.extend(migrated_overrides.0);
+    }
+    configuration.merge_with(migrated_configuration);
+    configuration.overrides = (!overrides.0.is_empty()).then_some(overrides);
+}
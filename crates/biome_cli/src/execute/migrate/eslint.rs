@@ -0,0 +1,630 @@
+use crate::diagnostics::MigrationDiagnostic;
+use crate::CliDiagnostic;
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleSource,
+};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    TextNumber, VisitableType,
+};
+use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_js_syntax::JsLanguage;
+use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
+use biome_service::{DynRef, PartialConfiguration};
+use rustc_hash::FxHashMap;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// The ESLint configuration files that Biome can read, in the order ESLint looks them up
+const ESLINT_CONFIG_FILES: [&str; 2] = [".eslintrc.json", ".eslintrc"];
+const ESLINT_IGNORE_FILE: &str = ".eslintignore";
+
+/// A value of the ESLint configuration file. The file is deserialized as a whole, because ESLint
+/// rules accept options of any shape.
+#[derive(Debug)]
+struct EslintValue(Value);
+
+impl Deserializable for EslintValue {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(EslintValueVisitor, name, diagnostics)
+    }
+}
+
+struct EslintValueVisitor;
+impl DeserializationVisitor for EslintValueVisitor {
+    type Output = EslintValue;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::all();
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(EslintValue(Value::Null))
+    }
+
+    fn visit_bool(
+        self,
+        value: bool,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(EslintValue(Value::Bool(value)))
+    }
+
+    fn visit_number(
+        self,
+        value: TextNumber,
+        range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        match value.text().parse() {
+            Ok(number) => Some(EslintValue(Value::Number(number))),
+            Err(_) => {
+                diagnostics.push(DeserializationDiagnostic::new_out_of_bound_integer(
+                    f64::MIN,
+                    f64::MAX,
+                    range,
+                ));
+                None
+            }
+        }
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(EslintValue(Value::String(value.text().to_string())))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let items = items
+            .flatten()
+            .filter_map(|item| EslintValue::deserialize(&item, name, diagnostics))
+            .map(|item| item.0)
+            .collect();
+        Some(EslintValue(Value::Array(items)))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut map = Map::new();
+        for (key, value) in members.flatten() {
+            let Some(key) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(value) = EslintValue::deserialize(&value, name, diagnostics) {
+                map.insert(key.text().to_string(), value.0);
+            }
+        }
+        Some(EslintValue(Value::Object(map)))
+    }
+}
+
+/// Maps the names of the ESLint rules to the Biome rules that declare them as their source
+#[derive(Debug, Default)]
+pub(crate) struct EslintRulesVisitor {
+    rules: FxHashMap<String, Vec<(&'static str, &'static str)>>,
+}
+
+impl EslintRulesVisitor {
+    pub(crate) fn new() -> Self {
+        let mut visitor = Self::default();
+        biome_js_analyze::visit_registry(&mut visitor);
+        visitor
+    }
+
+    /// Returns the groups and the names of the Biome rules that correspond to the ESLint rule `name`.
+    ///
+    /// The rules of the TypeScript plugin that extend a core ESLint rule map to the same Biome rules.
+    pub(crate) fn get(&self, name: &str) -> &[(&'static str, &'static str)] {
+        self.rules
+            .get(name)
+            .or_else(|| {
+                name.strip_prefix("@typescript-eslint/")
+                    .and_then(|name| self.rules.get(name))
+            })
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for EslintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        if R::METADATA.deprecated.is_some() {
+            return;
+        }
+        let Some(source) = R::METADATA.source.as_ref() else {
+            return;
+        };
+        let plugin = match source {
+            RuleSource::Clippy(_) => return,
+            RuleSource::Eslint(_) => "",
+            RuleSource::EslintImport(_) => "import/",
+            RuleSource::EslintImportAccess(_) => "import-access/",
+            RuleSource::EslintJest(_) => "jest/",
+            RuleSource::EslintJsxA11y(_) => "jsx-a11y/",
+            RuleSource::EslintReact(_) => "react/",
+            RuleSource::EslintReactHooks(_) => "react-hooks/",
+            RuleSource::EslintSonarJs(_) => "sonarjs/",
+            RuleSource::EslintStylistic(_) => "@stylistic/",
+            RuleSource::EslintTypeScript(_) => "@typescript-eslint/",
+            RuleSource::EslintUnicorn(_) => "unicorn/",
+            RuleSource::EslintMysticatea(_) => "@mysticatea/",
+        };
+        self.rules
+            .entry(format!("{plugin}{}", source.as_rule_name()))
+            .or_default()
+            .push((<R::Group as RuleGroup>::NAME, R::METADATA.name));
+    }
+}
+
+/// The result of the migration of an ESLint configuration
+#[derive(Debug, Default)]
+pub(crate) struct FromEslintConfiguration {
+    /// Path of the ESLint configuration file
+    configuration_path: PathBuf,
+    /// Path of the `.eslintignore` file
+    ignore_path: Option<PathBuf>,
+    /// The translated Biome configuration, from the ESLint configuration
+    configuration: PartialConfiguration,
+    /// The ESLint rules that don't have an equivalent in Biome
+    unsupported_rules: BTreeSet<String>,
+    /// The ESLint rules whose options couldn't be translated to the options of the Biome rule
+    unsupported_options: BTreeSet<String>,
+    /// The configurations extended by the ESLint configuration, which can't be migrated
+    extends: Vec<String>,
+}
+
+impl FromEslintConfiguration {
+    pub(crate) fn as_biome_configuration(&self) -> PartialConfiguration {
+        self.configuration.clone()
+    }
+
+    /// Prints the parts of the ESLint configuration that couldn't be migrated
+    pub(crate) fn print_report(&self, console: &mut dyn Console) {
+        let configuration_path = self.configuration_path.display().to_string();
+        console.log(markup! {
+            <Info>"Migrating the ESLint configuration "<Emphasis>{configuration_path}</Emphasis></Info>
+        });
+        if let Some(ignore_path) = self.ignore_path.as_deref() {
+            let ignore_path = ignore_path.display().to_string();
+            console.log(markup! {
+                <Info>"Migrating the ignored files of "<Emphasis>{ignore_path}</Emphasis></Info>
+            });
+        }
+        if !self.extends.is_empty() {
+            let extends = self.extends.join(", ");
+            console.log(markup! {
+                <Warn>"The extended configurations can't be migrated: "</Warn>{extends}
+            });
+        }
+        if !self.unsupported_rules.is_empty() {
+            let count = self.unsupported_rules.len();
+            console.log(markup! {
+                <Warn>"The following "{count}" rule(s) don't have an equivalent in Biome:"</Warn>
+            });
+            for rule in &self.unsupported_rules {
+                console.log(markup! {
+                    "  - "{rule}
+                });
+            }
+        }
+        if !self.unsupported_options.is_empty() {
+            let count = self.unsupported_options.len();
+            console.log(markup! {
+                <Warn>"The options of the following "{count}" rule(s) couldn't be migrated, the Biome rules use their default options:"</Warn>
+            });
+            for rule in &self.unsupported_options {
+                console.log(markup! {
+                    "  - "{rule}
+                });
+            }
+        }
+    }
+}
+
+/// Translates the ESLint configuration, and the content of the `.eslintignore` file, into a Biome configuration
+struct EslintMigrator<'a> {
+    rules: &'a EslintRulesVisitor,
+    result: &'a mut FromEslintConfiguration,
+}
+
+impl EslintMigrator<'_> {
+    fn migrate(&mut self, eslint: &Map<String, Value>, ignored_globs: Vec<String>) -> Value {
+        let mut configuration = Map::new();
+
+        if let Some(extends) = eslint.get("extends") {
+            self.result.extends = string_list(extends);
+        }
+
+        let mut linter = Map::new();
+        linter.insert("rules".to_string(), self.migrate_rules(eslint.get("rules")));
+
+        let mut ignore: Vec<_> = eslint
+            .get("ignorePatterns")
+            .map(string_list)
+            .unwrap_or_default();
+        ignore.extend(ignored_globs);
+        if !ignore.is_empty() {
+            linter.insert("ignore".to_string(), json!(ignore));
+        }
+        configuration.insert("linter".to_string(), Value::Object(linter));
+
+        if let Some(Value::Array(overrides)) = eslint.get("overrides") {
+            let overrides: Vec<_> = overrides
+                .iter()
+                .filter_map(Value::as_object)
+                .map(|eslint_override| self.migrate_override(eslint_override))
+                .collect();
+            if !overrides.is_empty() {
+                configuration.insert("overrides".to_string(), Value::Array(overrides));
+            }
+        }
+
+        Value::Object(configuration)
+    }
+
+    fn migrate_override(&mut self, eslint_override: &Map<String, Value>) -> Value {
+        let mut pattern = Map::new();
+        if let Some(files) = eslint_override.get("files") {
+            pattern.insert("include".to_string(), json!(string_list(files)));
+        }
+        if let Some(excluded_files) = eslint_override.get("excludedFiles") {
+            pattern.insert("ignore".to_string(), json!(string_list(excluded_files)));
+        }
+        pattern.insert(
+            "linter".to_string(),
+            json!({ "rules": self.migrate_rules(eslint_override.get("rules")) }),
+        );
+        Value::Object(pattern)
+    }
+
+    /// Maps the ESLint rules to the Biome rules, grouped like in the `linter.rules` configuration
+    fn migrate_rules(&mut self, eslint_rules: Option<&Value>) -> Value {
+        let mut groups = Map::new();
+        let Some(Value::Object(eslint_rules)) = eslint_rules else {
+            return Value::Object(groups);
+        };
+        for (eslint_name, eslint_configuration) in eslint_rules {
+            let biome_rules = self.rules.get(eslint_name);
+            if biome_rules.is_empty() {
+                self.result.unsupported_rules.insert(eslint_name.clone());
+                continue;
+            }
+            let (level, options) = match eslint_configuration {
+                Value::Array(items) => match items.split_first() {
+                    Some((severity, options)) => (severity, options),
+                    None => continue,
+                },
+                severity => (severity, [].as_slice()),
+            };
+            let Some(level) = migrate_severity(level) else {
+                self.result.unsupported_rules.insert(eslint_name.clone());
+                continue;
+            };
+
+            let migrated_rules: Vec<_> = if options.is_empty() || level == "off" {
+                biome_rules
+                    .iter()
+                    .map(|(group, rule)| (group, rule, json!(level)))
+                    .collect()
+            } else {
+                // When the ESLint rule maps to several Biome rules, only the ones that
+                // support its options are enabled
+                let with_options: Vec<_> = biome_rules
+                    .iter()
+                    .filter_map(|(group, rule)| {
+                        let options = migrate_rule_options(rule, options)?;
+                        Some((group, rule, json!({ "level": level, "options": options })))
+                    })
+                    .collect();
+                if with_options.is_empty() {
+                    self.result.unsupported_options.insert(eslint_name.clone());
+                    biome_rules
+                        .iter()
+                        .map(|(group, rule)| (group, rule, json!(level)))
+                        .collect()
+                } else {
+                    with_options
+                }
+            };
+
+            for (group, rule, configuration) in migrated_rules {
+                if let Value::Object(rules) = groups
+                    .entry(*group)
+                    .or_insert_with(|| Value::Object(Map::new()))
+                {
+                    rules.insert((*rule).to_string(), configuration);
+                }
+            }
+        }
+        Value::Object(groups)
+    }
+}
+
+/// ESLint accepts a single string where it accepts a list of strings
+fn string_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(value) => vec![value.clone()],
+        Value::Array(values) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Maps an ESLint severity, either a number or a string, to the level of a Biome rule
+fn migrate_severity(severity: &Value) -> Option<&'static str> {
+    match severity {
+        Value::Number(number) => match number.as_u64()? {
+            0 => Some("off"),
+            1 => Some("warn"),
+            2 => Some("error"),
+            _ => None,
+        },
+        Value::String(severity) => match severity.as_str() {
+            "off" => Some("off"),
+            "warn" => Some("warn"),
+            "error" => Some("error"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Translates the options of an ESLint rule to the options of the Biome rule `rule`.
+///
+/// It returns [None] when the options can't be translated.
+fn migrate_rule_options(rule: &str, options: &[Value]) -> Option<Value> {
+    match rule {
+        "noExcessiveCognitiveComplexity" => {
+            let threshold = options.first()?.as_u64()?;
+            Some(json!({ "maxAllowedComplexity": threshold }))
+        }
+        "noRestrictedGlobals" => {
+            let denied_globals: Option<Vec<_>> = options
+                .iter()
+                .map(|option| match option {
+                    Value::String(name) => Some(name.as_str()),
+                    Value::Object(option) => option.get("name")?.as_str(),
+                    _ => None,
+                })
+                .collect();
+            Some(json!({ "deniedGlobals": denied_globals? }))
+        }
+        "useConsistentArrayType" => {
+            let syntax = match options.first()?.get("default")?.as_str()? {
+                "array" => "shorthand",
+                "generic" => "generic",
+                _ => return None,
+            };
+            Some(json!({ "syntax": syntax }))
+        }
+        "useFilenamingConvention" => {
+            let option = options.first()?;
+            let cases: Vec<_> = match (option.get("case"), option.get("cases")) {
+                (Some(case), _) => vec![case.as_str()?],
+                (None, Some(Value::Object(cases))) => cases
+                    .iter()
+                    .filter(|(_, enabled)| enabled.as_bool() == Some(true))
+                    .map(|(case, _)| case.as_str())
+                    .collect(),
+                _ => return None,
+            };
+            let filename_cases: Option<Vec<_>> = cases
+                .into_iter()
+                .map(|case| match case {
+                    "camelCase" => Some("camelCase"),
+                    "kebabCase" => Some("kebab-case"),
+                    "pascalCase" => Some("PascalCase"),
+                    "snakeCase" => Some("snake_case"),
+                    _ => None,
+                })
+                .collect();
+            Some(json!({ "filenameCases": filename_cases? }))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the first ESLint configuration file of the project
+pub(crate) fn find_eslint_configuration_file(
+    fs: &DynRef<'_, dyn FileSystem>,
+) -> Option<&'static str> {
+    ESLINT_CONFIG_FILES
+        .into_iter()
+        .find(|config_name| fs.path_exists(Path::new(config_name)))
+}
+
+/// This function is in charge of reading the ESLint files, deserialize their contents and convert them in a Biome configuration type
+pub(crate) fn read_eslint_files(
+    fs: &DynRef<'_, dyn FileSystem>,
+    console: &mut dyn Console,
+) -> Result<FromEslintConfiguration, CliDiagnostic> {
+    let mut from_eslint_configuration = FromEslintConfiguration::default();
+    let mut eslint_config_content = String::new();
+    for config_name in ESLINT_CONFIG_FILES {
+        let path = Path::new(config_name);
+        let Ok(mut file) = fs.open_with_options(path, OpenOptions::default().read(true)) else {
+            continue;
+        };
+        file.read_to_string(&mut eslint_config_content)
+            .map_err(CliDiagnostic::io_error)?;
+        from_eslint_configuration.configuration_path = path.to_path_buf();
+        break;
+    }
+
+    if eslint_config_content.is_empty() {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Biome couldn't find an ESLint configuration file.".to_string(),
+        }));
+    }
+
+    let deserialized = deserialize_from_json_str::<EslintValue>(
+        eslint_config_content.as_str(),
+        JsonParserOptions::default()
+            .with_allow_trailing_commas()
+            .with_allow_comments(),
+        "",
+    );
+    let configuration_path = from_eslint_configuration
+        .configuration_path
+        .display()
+        .to_string();
+    let (eslint_configuration, diagnostics) = deserialized.consume();
+    if !diagnostics.is_empty() {
+        for diagnostic in diagnostics {
+            let diagnostic = diagnostic.with_file_path(configuration_path.as_str());
+            console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+        }
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not deserialize the ESLint configuration file".to_string(),
+        }));
+    }
+    let Some(EslintValue(Value::Object(eslint_configuration))) = eslint_configuration else {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "The ESLint configuration file must contain an object".to_string(),
+        }));
+    };
+
+    let mut ignore_file_content = String::new();
+    let path = Path::new(ESLINT_IGNORE_FILE);
+    if let Ok(mut file) = fs.open_with_options(path, OpenOptions::default().read(true)) {
+        file.read_to_string(&mut ignore_file_content)
+            .map_err(CliDiagnostic::io_error)?;
+        from_eslint_configuration.ignore_path = Some(path.to_path_buf());
+    }
+    let ignored_globs = ignore_file_content
+        .lines()
+        .filter(|line| !line.is_empty())
+        .filter(|line| !line.starts_with('#'))
+        .map(String::from)
+        .collect();
+
+    let rules = EslintRulesVisitor::new();
+    let configuration = EslintMigrator {
+        rules: &rules,
+        result: &mut from_eslint_configuration,
+    }
+    .migrate(&eslint_configuration, ignored_globs);
+
+    let deserialized = deserialize_from_json_str::<PartialConfiguration>(
+        configuration.to_string().as_str(),
+        JsonParserOptions::default(),
+        "",
+    );
+    if deserialized.has_errors() {
+        for diagnostic in deserialized.into_diagnostics() {
+            console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+        }
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Could not translate the ESLint configuration file".to_string(),
+        }));
+    }
+    from_eslint_configuration.configuration = deserialized.into_deserialized().unwrap_or_default();
+
+    Ok(from_eslint_configuration)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{migrate_rule_options, migrate_severity, EslintRulesVisitor};
+    use serde_json::json;
+
+    #[test]
+    fn maps_rules_by_source() {
+        let rules = EslintRulesVisitor::new();
+        assert_eq!(rules.get("no-debugger"), [("suspicious", "noDebugger")]);
+        assert_eq!(
+            rules.get("@typescript-eslint/no-loss-of-precision"),
+            [("correctness", "noPrecisionLoss")]
+        );
+        assert_eq!(
+            rules.get("@typescript-eslint/array-type"),
+            [
+                ("nursery", "useConsistentArrayType"),
+                ("style", "useShorthandArrayType")
+            ]
+        );
+        assert!(rules.get("no-unknown-rule").is_empty());
+    }
+
+    #[test]
+    fn severities() {
+        assert_eq!(migrate_severity(&json!(0)), Some("off"));
+        assert_eq!(migrate_severity(&json!(1)), Some("warn"));
+        assert_eq!(migrate_severity(&json!("error")), Some("error"));
+        assert_eq!(migrate_severity(&json!(3)), None);
+    }
+
+    #[test]
+    fn rule_options() {
+        assert_eq!(
+            migrate_rule_options(
+                "noRestrictedGlobals",
+                &[
+                    json!("event"),
+                    json!({ "name": "fdescribe", "message": "Focus" })
+                ]
+            ),
+            Some(json!({ "deniedGlobals": ["event", "fdescribe"] }))
+        );
+        assert_eq!(
+            migrate_rule_options(
+                "useFilenamingConvention",
+                &[
+                    json!({ "cases": { "kebabCase": true, "pascalCase": true, "camelCase": false } })
+                ]
+            ),
+            Some(json!({ "filenameCases": ["kebab-case", "PascalCase"] }))
+        );
+        assert_eq!(
+            migrate_rule_options(
+                "useConsistentArrayType",
+                &[json!({ "default": "array-simple" })]
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,602 @@
+//! Rewrites the ESLint suppression comments into Biome suppression comments.
+//!
+//! A Biome suppression comment applies to the next line, while ESLint comments can disable rules
+//! for the next line, for the current line, or for a range of lines. So:
+//! - `eslint-disable-next-line` comments are rewritten in place;
+//! - `eslint-disable-line` comments are moved to the line above;
+//! - `eslint-disable` and `eslint-enable` comments are removed, and a suppression comment is
+//!   inserted above each line of the range where a disabled rule emits a diagnostic.
+//!
+//! The inserted comments can't change the meaning of the code: in JSX children they are wrapped
+//! in a JSX expression, and in the text of a template they are inserted after a `${`.
+
+use crate::execute::migrate::eslint::EslintRulesVisitor;
+use biome_js_syntax::{JsSyntaxKind, JsSyntaxNode, JsxExpressionChild};
+use biome_rowan::{AstNode, Direction};
+use biome_text_size::{TextRange, TextSize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+/// The explanation of the suppression comments, when the ESLint comment doesn't have a description
+const DEFAULT_EXPLANATION: &str = "<explanation>";
+
+fn eslint_rules() -> &'static EslintRulesVisitor {
+    static RULES: OnceLock<EslintRulesVisitor> = OnceLock::new();
+    RULES.get_or_init(EslintRulesVisitor::new)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum DirectiveKind {
+    DisableNextLine,
+    DisableLine,
+    Disable,
+    Enable,
+}
+
+/// An ESLint comment that disables or enables rules
+#[derive(Debug, Eq, PartialEq)]
+struct Directive {
+    kind: DirectiveKind,
+    /// Range of the comment
+    range: TextRange,
+    /// The ESLint rules, empty when the comment applies to all the rules
+    rules: Vec<String>,
+    /// The text after `--`, which explains why the rules are disabled
+    description: Option<String>,
+    is_block_comment: bool,
+}
+
+impl Directive {
+    /// Parses the text of a comment, and returns [None] if it isn't an ESLint comment
+    fn parse(text: &str, range: TextRange) -> Option<Self> {
+        let (content, is_block_comment) = match text.strip_prefix("//") {
+            Some(content) => (content, false),
+            None => (text.strip_prefix("/*")?.strip_suffix("*/")?, true),
+        };
+        let content = content.trim();
+        // The longest names come first, because they start with the shortest ones
+        let (kind, rest) = [
+            ("eslint-disable-next-line", DirectiveKind::DisableNextLine),
+            ("eslint-disable-line", DirectiveKind::DisableLine),
+            ("eslint-disable", DirectiveKind::Disable),
+            ("eslint-enable", DirectiveKind::Enable),
+        ]
+        .into_iter()
+        .find_map(|(name, kind)| {
+            let rest = content.strip_prefix(name)?;
+            (rest.is_empty() || rest.starts_with(char::is_whitespace)).then_some((kind, rest))
+        })?;
+
+        // ESLint only reads `eslint-disable` and `eslint-enable` in block comments,
+        // and `eslint-disable-line` in comments that fit on a single line
+        match kind {
+            DirectiveKind::Disable | DirectiveKind::Enable if !is_block_comment => return None,
+            DirectiveKind::DisableLine if content.contains('\n') => return None,
+            _ => {}
+        }
+
+        let (rules, description) = match rest.split_once("--") {
+            Some((rules, description)) => (rules, Some(description.trim())),
+            None => (rest, None),
+        };
+        Some(Self {
+            kind,
+            range,
+            rules: rules
+                .split(',')
+                .map(str::trim)
+                .filter(|rule| !rule.is_empty())
+                .map(String::from)
+                .collect(),
+            description: description
+                .filter(|description| !description.is_empty())
+                .map(String::from),
+            is_block_comment,
+        })
+    }
+
+    fn explanation(&self) -> &str {
+        self.description.as_deref().unwrap_or(DEFAULT_EXPLANATION)
+    }
+
+    /// Returns the suppressions of the Biome rules that correspond to the ESLint rules of the
+    /// comment, and pushes the ESLint rules that don't have an equivalent to `unsupported_rules`
+    fn suppressions(&self, unsupported_rules: &mut Vec<(TextRange, String)>) -> Vec<String> {
+        if self.rules.is_empty() {
+            return vec!["lint".to_string()];
+        }
+        let mut suppressions = Vec::new();
+        for rule in &self.rules {
+            let biome_rules = eslint_rules().get(rule);
+            if biome_rules.is_empty() {
+                unsupported_rules.push((self.range, rule.clone()));
+            }
+            for (group, rule) in biome_rules {
+                push_unique(&mut suppressions, format!("lint/{group}/{rule}"));
+            }
+        }
+        suppressions
+    }
+}
+
+/// Where a suppression comment is inserted
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Placement {
+    /// A line comment on its own line, above the suppressed line
+    Line,
+    /// A JSX expression on its own line, above the suppressed line of JSX children
+    JsxChild,
+    /// A line comment after the `${` of a template, which moves the suppressed expressions of
+    /// the line to the next line without changing the text of the template
+    TemplateElement,
+}
+
+/// A range of lines where ESLint rules are disabled by an `eslint-disable` comment
+struct DisabledRange<'a> {
+    start: TextSize,
+    /// The disabled ESLint rules, empty when all the rules are disabled
+    rules: Vec<String>,
+    directive: &'a Directive,
+}
+
+/// The ESLint comments of a file
+#[derive(Debug, Default)]
+pub(crate) struct EslintComments {
+    directives: Vec<Directive>,
+}
+
+/// The result of the migration of the ESLint comments of a file
+#[derive(Debug)]
+pub(crate) struct MigratedComments {
+    /// The new content of the file
+    pub(crate) code: String,
+    /// The ESLint rules that don't have an equivalent in Biome, with the range of their comment
+    pub(crate) unsupported_rules: Vec<(TextRange, String)>,
+}
+
+impl EslintComments {
+    /// Collects the ESLint comments of the file of `root`
+    pub(crate) fn parse(root: &JsSyntaxNode) -> Self {
+        Self {
+            directives: root
+                .descendants_tokens(Direction::Next)
+                .flat_map(|token| {
+                    token
+                        .leading_trivia()
+                        .pieces()
+                        .chain(token.trailing_trivia().pieces())
+                })
+                .filter(|piece| piece.is_comments())
+                .filter_map(|piece| Directive::parse(piece.text(), piece.text_range()))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// Whether rules are disabled for a range of lines. The lint diagnostics of the file
+    /// are needed to know the lines of the range that need a suppression comment.
+    pub(crate) fn has_disabled_ranges(&self) -> bool {
+        self.directives
+            .iter()
+            .any(|directive| directive.kind == DirectiveKind::Disable)
+    }
+
+    /// Rewrites the ESLint comments of the file of `root`. `diagnostics` are the start and the
+    /// category of the lint diagnostics of the file, they are used to migrate the disabled ranges.
+    pub(crate) fn migrate(
+        &self,
+        root: &JsSyntaxNode,
+        diagnostics: &[(TextSize, &str)],
+    ) -> MigratedComments {
+        let code = &root.to_string();
+        let mut unsupported_rules = Vec::new();
+        let mut edits: Vec<(TextRange, String)> = Vec::new();
+        // The suppression comments to insert, by offset of insertion
+        let mut insertions: BTreeMap<TextSize, (Placement, Vec<String>, &str)> = BTreeMap::new();
+        // `offset` is in the line to suppress
+        let mut insert = |offset: TextSize, suppressions: Vec<String>, explanation| {
+            let Some((position, placement)) = placement(code, root, offset) else {
+                return;
+            };
+            let (_, existing, _) = insertions
+                .entry(position)
+                .or_insert_with(|| (placement, Vec::new(), explanation));
+            for suppression in suppressions {
+                push_unique(existing, suppression);
+            }
+        };
+
+        let mut open_ranges: Vec<DisabledRange> = Vec::new();
+        let mut closed_ranges: Vec<(TextRange, DisabledRange)> = Vec::new();
+        for directive in &self.directives {
+            match directive.kind {
+                DirectiveKind::DisableNextLine => {
+                    let suppressions = directive.suppressions(&mut unsupported_rules);
+                    if suppressions.is_empty() {
+                        edits.push((removal_range(code, root, directive.range), String::new()));
+                    } else {
+                        let comment = format!(
+                            "biome-ignore {}: {}",
+                            suppressions.join(" "),
+                            directive.explanation()
+                        );
+                        let comment = if directive.is_block_comment {
+                            format!("/* {comment} */")
+                        } else {
+                            format!("// {comment}")
+                        };
+                        edits.push((directive.range, comment));
+                    }
+                }
+                DirectiveKind::DisableLine => {
+                    edits.push((removal_range(code, root, directive.range), String::new()));
+                    let suppressions = directive.suppressions(&mut unsupported_rules);
+                    if !suppressions.is_empty() {
+                        insert(
+                            directive.range.start(),
+                            suppressions,
+                            directive.explanation(),
+                        );
+                    }
+                }
+                DirectiveKind::Disable => {
+                    edits.push((removal_range(code, root, directive.range), String::new()));
+                    // The rules without an equivalent are reported, and they can't have diagnostics
+                    directive.suppressions(&mut unsupported_rules);
+                    open_ranges.push(DisabledRange {
+                        start: directive.range.end(),
+                        rules: directive.rules.clone(),
+                        directive,
+                    });
+                }
+                DirectiveKind::Enable => {
+                    edits.push((removal_range(code, root, directive.range), String::new()));
+                    let end = directive.range.start();
+                    if directive.rules.is_empty() {
+                        closed_ranges.extend(
+                            open_ranges
+                                .drain(..)
+                                .map(|range| (TextRange::new(range.start, end), range)),
+                        );
+                        continue;
+                    }
+                    // Only the enabled rules are closed. A range that disables all the rules
+                    // stays open, because a suppression comment can't exclude rules.
+                    for range in &mut open_ranges {
+                        let (enabled, disabled) = range
+                            .rules
+                            .iter()
+                            .cloned()
+                            .partition::<Vec<_>, _>(|rule| directive.rules.contains(rule));
+                        if !enabled.is_empty() {
+                            range.rules = disabled;
+                            closed_ranges.push((
+                                TextRange::new(range.start, end),
+                                DisabledRange {
+                                    start: range.start,
+                                    rules: enabled,
+                                    directive: range.directive,
+                                },
+                            ));
+                        }
+                    }
+                    open_ranges.retain(|range| {
+                        !range.rules.is_empty() || range.directive.rules.is_empty()
+                    });
+                }
+            }
+        }
+        let end = TextSize::of(code);
+        closed_ranges.extend(
+            open_ranges
+                .into_iter()
+                .map(|range| (TextRange::new(range.start, end), range)),
+        );
+
+        for (text_range, range) in closed_ranges {
+            let suppressions: Vec<_> = range
+                .rules
+                .iter()
+                .flat_map(|rule| eslint_rules().get(rule))
+                .map(|(group, rule)| format!("lint/{group}/{rule}"))
+                .collect();
+            for (start, category) in diagnostics {
+                let is_disabled = if range.rules.is_empty() {
+                    category.starts_with("lint/")
+                } else {
+                    suppressions
+                        .iter()
+                        .any(|suppression| suppression == category)
+                };
+                if is_disabled && text_range.contains(*start) {
+                    insert(
+                        *start,
+                        vec![(*category).to_string()],
+                        range.directive.explanation(),
+                    );
+                }
+            }
+        }
+
+        for (position, (placement, suppressions, explanation)) in insertions {
+            let line = &code[usize::from(line_start(code, position))..];
+            let indentation = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+            let comment = format!("biome-ignore {}: {explanation}", suppressions.join(" "));
+            let text = match placement {
+                Placement::Line => format!("{indentation}// {comment}\n"),
+                Placement::JsxChild => format!("{indentation}{{/* {comment} */}}\n"),
+                Placement::TemplateElement => format!("// {comment}\n{indentation}"),
+            };
+            edits.push((TextRange::empty(position), text));
+        }
+
+        edits.sort_by_key(|(range, _)| (range.start(), range.end()));
+        let mut code = code.to_string();
+        for (range, text) in edits.into_iter().rev() {
+            code.replace_range(std::ops::Range::<usize>::from(range), &text);
+        }
+
+        MigratedComments {
+            code,
+            unsupported_rules,
+        }
+    }
+}
+
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+/// Returns the offset of the start of the line that contains `offset`
+fn line_start(code: &str, offset: TextSize) -> TextSize {
+    code[..usize::from(offset)]
+        .rfind('\n')
+        .map_or(TextSize::from(0), |index| TextSize::from(index as u32 + 1))
+}
+
+/// Returns where to insert the suppression comment of the line that contains `offset`, or [None]
+/// when the line starts inside a token that can't contain a comment, e.g. a string literal
+fn placement(code: &str, root: &JsSyntaxNode, offset: TextSize) -> Option<(TextSize, Placement)> {
+    let line_start = line_start(code, offset);
+    if line_start == TextSize::from(0) {
+        return Some((line_start, Placement::Line));
+    }
+    let line_break = TextRange::at(line_start - TextSize::from(1), TextSize::from(1));
+    let token = root.covering_element(line_break).into_token()?;
+    // The line break is a trivia piece, the line starts with code
+    if !token.text_trimmed_range().contains_range(line_break) {
+        return Some((line_start, Placement::Line));
+    }
+
+    match token.kind() {
+        JsSyntaxKind::JSX_TEXT_LITERAL => Some((line_start, Placement::JsxChild)),
+        JsSyntaxKind::TEMPLATE_CHUNK => {
+            // The comment goes after the first `${` of the line, before the suppressed expressions
+            let mut dollar_curly = None;
+            let mut current = root.token_at_offset(offset).right_biased();
+            while let Some(token) = current.filter(|token| token.text_range().start() >= line_start)
+            {
+                if token.kind() == JsSyntaxKind::DOLLAR_CURLY {
+                    dollar_curly = Some(token.text_trimmed_range().end());
+                }
+                current = token.prev_token();
+            }
+            dollar_curly.map(|position| (position, Placement::TemplateElement))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the range to delete to remove the comment at `range`: the whole line when the comment
+/// is alone on its line, otherwise the comment and the whitespace that precedes it. A JSX
+/// expression that only contains the comment is removed with it.
+fn removal_range(code: &str, root: &JsSyntaxNode, range: TextRange) -> TextRange {
+    let range = root
+        .covering_element(range)
+        .parent()
+        .and_then(JsxExpressionChild::cast)
+        .filter(|child| {
+            let comments = child
+                .syntax()
+                .descendants_tokens(Direction::Next)
+                .flat_map(|token| {
+                    token
+                        .leading_trivia()
+                        .pieces()
+                        .chain(token.trailing_trivia().pieces())
+                })
+                .filter(|piece| piece.is_comments())
+                .count();
+            child.expression().is_none() && comments == 1
+        })
+        .map_or(range, |child| child.syntax().text_trimmed_range());
+    let start = line_start(code, range.start());
+    let before = &code[TextRange::new(start, range.start())];
+    let after = &code[usize::from(range.end())..];
+    let after = &after[..after.find('\n').unwrap_or(after.len())];
+    if before.trim().is_empty() && after.trim().is_empty() {
+        let end = range.end() + TextSize::of(after);
+        // Removes the line break too, if any
+        let end = if usize::from(end) < code.len() {
+            end + TextSize::from(1)
+        } else {
+            end
+        };
+        TextRange::new(start, end)
+    } else {
+        let whitespace = before.len() - before.trim_end().len();
+        TextRange::new(
+            range.start() - TextSize::from(whitespace as u32),
+            range.end(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EslintComments;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+    use biome_text_size::TextSize;
+
+    fn migrate(code: &str, diagnostics: &[(TextSize, &str)]) -> (String, Vec<String>) {
+        let root = parse(code, JsFileSource::jsx(), JsParserOptions::default()).syntax();
+        let migrated = EslintComments::parse(&root).migrate(&root, diagnostics);
+        (
+            migrated.code,
+            migrated
+                .unsupported_rules
+                .into_iter()
+                .map(|(_, rule)| rule)
+                .collect(),
+        )
+    }
+
+    /// Returns the diagnostics of `category` that start at each occurrence of `pattern` in `code`
+    fn diagnostics_at<'a>(
+        code: &str,
+        pattern: &str,
+        category: &'a str,
+    ) -> Vec<(TextSize, &'a str)> {
+        code.match_indices(pattern)
+            .map(|(index, _)| (TextSize::from(index as u32), category))
+            .collect()
+    }
+
+    #[test]
+    fn next_line() {
+        let code = "// eslint-disable-next-line no-debugger -- tests\ndebugger;\n";
+        assert_eq!(
+            migrate(code, &[]),
+            (
+                "// biome-ignore lint/suspicious/noDebugger: tests\ndebugger;\n".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn next_line_all_rules() {
+        let code = "  /* eslint-disable-next-line */\n  debugger;\n";
+        assert_eq!(
+            migrate(code, &[]),
+            (
+                "  /* biome-ignore lint: <explanation> */\n  debugger;\n".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn next_line_unsupported_rules() {
+        let code = "// eslint-disable-next-line no-debugger, unknown-rule\ndebugger;\n// eslint-disable-next-line other-rule\nfoo();\n";
+        assert_eq!(
+            migrate(code, &[]),
+            (
+                "// biome-ignore lint/suspicious/noDebugger: <explanation>\ndebugger;\nfoo();\n"
+                    .to_string(),
+                vec!["unknown-rule".to_string(), "other-rule".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn line() {
+        let code = "function f() {\n    debugger; // eslint-disable-line no-debugger\n}\n";
+        assert_eq!(
+            migrate(code, &[]),
+            (
+                "function f() {\n    // biome-ignore lint/suspicious/noDebugger: <explanation>\n    debugger;\n}\n"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn disabled_range() {
+        let code = "/* eslint-disable no-debugger, no-var */\ndebugger;\nvar a = 1;\n/* eslint-enable no-debugger */\ndebugger;\nvar b = 1;\n";
+        let diagnostics: Vec<_> = code
+            .match_indices("\ndebugger")
+            .map(|(index, _)| (index, "lint/suspicious/noDebugger"))
+            .chain(
+                code.match_indices("\nvar")
+                    .map(|(index, _)| (index, "lint/style/noVar")),
+            )
+            .map(|(index, category)| (TextSize::from(index as u32 + 1), category))
+            .collect();
+        assert_eq!(
+            migrate(code, &diagnostics),
+            (
+                "// biome-ignore lint/suspicious/noDebugger: <explanation>\ndebugger;\n// biome-ignore lint/style/noVar: <explanation>\nvar a = 1;\ndebugger;\n// biome-ignore lint/style/noVar: <explanation>\nvar b = 1;\n"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn line_in_jsx_children() {
+        let code = "<div>\n    <img /> {/* eslint-disable-line jsx-a11y/alt-text */}\n</div>;\n";
+        assert_eq!(
+            migrate(code, &[]),
+            (
+                "<div>\n    {/* biome-ignore lint/a11y/useAltText: <explanation> */}\n    <img />\n</div>;\n"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn disabled_range_in_jsx_children() {
+        let code = "<div>\n    {/* eslint-disable jsx-a11y/alt-text */}\n    <img />\n</div>;\n";
+        let diagnostics = diagnostics_at(code, "<img", "lint/a11y/useAltText");
+        assert_eq!(
+            migrate(code, &diagnostics),
+            (
+                "<div>\n    {/* biome-ignore lint/a11y/useAltText: <explanation> */}\n    <img />\n</div>;\n"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn line_in_template() {
+        let code = "const s = `\n    text ${eval(a) /* eslint-disable-line no-eval */}\n`;\n";
+        assert_eq!(
+            migrate(code, &[]),
+            (
+                "const s = `\n    text ${// biome-ignore lint/nursery/noGlobalEval: <explanation>\n    eval(a)}\n`;\n"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn disabled_range_in_template() {
+        let code = "/* eslint-disable no-eval */\nconst s = `\n    ${a} ${eval(a)}\n`;\n";
+        let diagnostics = diagnostics_at(code, "eval(", "lint/nursery/noGlobalEval");
+        assert_eq!(
+            migrate(code, &diagnostics),
+            (
+                "const s = `\n    ${// biome-ignore lint/nursery/noGlobalEval: <explanation>\n    a} ${eval(a)}\n`;\n"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn not_eslint_comments() {
+        let code = "// eslint-disable no-debugger\n/* eslint-disabled */\ndebugger;\n";
+        assert_eq!(migrate(code, &[]), (code.to_string(), vec![]));
+    }
+}
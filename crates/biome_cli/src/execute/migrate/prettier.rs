@@ -2,19 +2,24 @@ use crate::diagnostics::MigrationDiagnostic;
 use crate::CliDiagnostic;
 use biome_console::{markup, Console, ConsoleExt};
 use biome_deserialize::json::deserialize_from_json_str;
-use biome_deserialize::StringSet;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    StringSet, Text, VisitableType,
+};
 use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::{DiagnosticExt, PrintDiagnostic};
 use biome_formatter::{LineEnding, LineWidth, QuoteStyle};
 use biome_fs::{FileSystem, OpenOptions};
 use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, Semicolons, TrailingComma};
 use biome_json_parser::JsonParserOptions;
+use biome_rowan::TextRange;
 use biome_service::configuration::{
-    PartialFormatterConfiguration, PartialJavascriptConfiguration, PartialJavascriptFormatter,
-    PlainIndentStyle,
+    OverrideFormatterConfiguration, OverridePattern, Overrides, PartialFormatterConfiguration,
+    PartialJavascriptConfiguration, PartialJavascriptFormatter, PlainIndentStyle,
 };
 use biome_service::{DynRef, PartialConfiguration};
 use indexmap::IndexSet;
+use serde_json::Value;
 use std::path::{Path, PathBuf};
 
 #[derive(Clone, Debug, Deserializable, Eq, PartialEq)]
@@ -43,6 +48,8 @@ pub(crate) struct PrettierConfiguration {
     arrow_parens: ArrowParens,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: EndOfLine,
+    /// https://prettier.io/docs/en/configuration#configuration-overrides
+    overrides: Vec<PrettierOverride>,
 }
 
 impl Default for PrettierConfiguration {
@@ -61,10 +68,89 @@ impl Default for PrettierConfiguration {
             jsx_single_quote: false,
             arrow_parens: ArrowParens::default(),
             end_of_line: EndOfLine::default(),
+            overrides: Vec::new(),
         }
     }
 }
 
+/// An entry of the Prettier `overrides`, which changes the options of the files matched by `files`
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+struct PrettierOverride {
+    #[deserializable(required)]
+    files: Globs,
+    exclude_files: Globs,
+    options: OverrideOptions,
+}
+
+/// The options of a Prettier override. Unlike [PrettierConfiguration], the options that aren't
+/// set are left unset, so they don't reset the options of the top-level configuration.
+#[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
+struct OverrideOptions {
+    print_width: Option<u16>,
+    use_tabs: Option<bool>,
+    trailing_comma: Option<PrettierTrailingComma>,
+    tab_width: Option<u8>,
+    semi: Option<bool>,
+    single_quote: Option<bool>,
+    bracket_spacing: Option<bool>,
+    bracket_line: Option<bool>,
+    quote_props: Option<QuoteProps>,
+    jsx_single_quote: Option<bool>,
+    arrow_parens: Option<ArrowParens>,
+    end_of_line: Option<EndOfLine>,
+}
+
+/// The `files` and `excludeFiles` of a Prettier override, which accept a glob or a list of globs
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Globs(Vec<String>);
+
+impl Globs {
+    fn into_string_set(self) -> Option<StringSet> {
+        (!self.0.is_empty()).then(|| StringSet::new(self.0.into_iter().collect()))
+    }
+}
+
+impl Deserializable for Globs {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(GlobsVisitor, name, diagnostics)
+    }
+}
+
+struct GlobsVisitor;
+impl DeserializationVisitor for GlobsVisitor {
+    type Output = Globs;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::ARRAY);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(Globs(vec![value.text().to_string()]))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let globs = items
+            .flatten()
+            .filter_map(|item| String::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(Globs(globs))
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
 enum EndOfLine {
     #[default]
@@ -149,16 +235,11 @@ impl From<QuoteProps> for QuoteProperties {
 impl TryFrom<PrettierConfiguration> for PartialFormatterConfiguration {
     type Error = String;
     fn try_from(value: PrettierConfiguration) -> Result<Self, Self::Error> {
-        let line_width = LineWidth::try_from(value.print_width).map_err(|err| err.to_string())?;
-        let indent_style = if value.use_tabs {
-            PlainIndentStyle::Tab
-        } else {
-            PlainIndentStyle::Space
-        };
+        let line_width = to_line_width(value.print_width)?;
         Ok(Self {
             indent_width: Some(value.tab_width),
             line_width: Some(line_width),
-            indent_style: Some(indent_style),
+            indent_style: Some(to_indent_style(value.use_tabs)),
             line_ending: Some(value.end_of_line.into()),
             format_with_errors: Some(false),
             ignore: None,
@@ -172,21 +253,6 @@ impl TryFrom<PrettierConfiguration> for PartialFormatterConfiguration {
 
 impl From<PrettierConfiguration> for PartialJavascriptFormatter {
     fn from(value: PrettierConfiguration) -> Self {
-        let semicolons = if value.semi {
-            Semicolons::Always
-        } else {
-            Semicolons::AsNeeded
-        };
-        let quote_style = if value.single_quote {
-            QuoteStyle::Single
-        } else {
-            QuoteStyle::Double
-        };
-        let jsx_quote_style = if value.jsx_single_quote {
-            QuoteStyle::Single
-        } else {
-            QuoteStyle::Double
-        };
         Self {
             indent_width: None,
             line_width: None,
@@ -199,16 +265,86 @@ impl From<PrettierConfiguration> for PartialJavascriptFormatter {
             // js ones
             bracket_same_line: Some(value.bracket_line),
             arrow_parentheses: Some(value.arrow_parens.into()),
-            semicolons: Some(semicolons),
+            semicolons: Some(to_semicolons(value.semi)),
             trailing_comma: Some(value.trailing_comma.into()),
-            quote_style: Some(quote_style),
+            quote_style: Some(to_quote_style(value.single_quote)),
             quote_properties: Some(value.quote_props.into()),
             bracket_spacing: Some(value.bracket_spacing),
-            jsx_quote_style: Some(jsx_quote_style),
+            jsx_quote_style: Some(to_quote_style(value.jsx_single_quote)),
         }
     }
 }
 
+impl TryFrom<PrettierOverride> for OverridePattern {
+    type Error = String;
+    fn try_from(value: PrettierOverride) -> Result<Self, Self::Error> {
+        let PrettierOverride {
+            files,
+            exclude_files,
+            options,
+        } = value;
+        let formatter = OverrideFormatterConfiguration {
+            indent_style: options.use_tabs.map(to_indent_style),
+            indent_width: options.tab_width,
+            line_ending: options.end_of_line.map(LineEnding::from),
+            line_width: options.print_width.map(to_line_width).transpose()?,
+            ..Default::default()
+        };
+        let javascript_formatter = PartialJavascriptFormatter {
+            bracket_same_line: options.bracket_line,
+            arrow_parentheses: options.arrow_parens.map(ArrowParentheses::from),
+            semicolons: options.semi.map(to_semicolons),
+            trailing_comma: options.trailing_comma.map(TrailingComma::from),
+            quote_style: options.single_quote.map(to_quote_style),
+            quote_properties: options.quote_props.map(QuoteProperties::from),
+            bracket_spacing: options.bracket_spacing,
+            jsx_quote_style: options.jsx_single_quote.map(to_quote_style),
+            ..Default::default()
+        };
+        Ok(Self {
+            include: files.into_string_set(),
+            ignore: exclude_files.into_string_set(),
+            formatter: (formatter != OverrideFormatterConfiguration::default())
+                .then_some(formatter),
+            javascript: (javascript_formatter != PartialJavascriptFormatter::default()).then(
+                || PartialJavascriptConfiguration {
+                    formatter: Some(javascript_formatter),
+                    ..Default::default()
+                },
+            ),
+            ..Default::default()
+        })
+    }
+}
+
+fn to_line_width(print_width: u16) -> Result<LineWidth, String> {
+    LineWidth::try_from(print_width).map_err(|err| err.to_string())
+}
+
+fn to_indent_style(use_tabs: bool) -> PlainIndentStyle {
+    if use_tabs {
+        PlainIndentStyle::Tab
+    } else {
+        PlainIndentStyle::Space
+    }
+}
+
+fn to_semicolons(semi: bool) -> Semicolons {
+    if semi {
+        Semicolons::Always
+    } else {
+        Semicolons::AsNeeded
+    }
+}
+
+fn to_quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote {
+        QuoteStyle::Single
+    } else {
+        QuoteStyle::Double
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct FromPrettierConfiguration {
     /// Path of the Prettier configuration file
@@ -222,6 +358,9 @@ pub(crate) struct FromPrettierConfiguration {
 
     /// The translated Biome configuration, from the Prettier configuration
     javascript_formatter_configuration: Option<PartialJavascriptFormatter>,
+
+    /// The translated Biome overrides, from the Prettier overrides
+    overrides: Vec<OverridePattern>,
 }
 
 impl FromPrettierConfiguration {
@@ -236,6 +375,10 @@ impl FromPrettierConfiguration {
         self.javascript_formatter_configuration = Some(javascript_formatter);
     }
 
+    pub(crate) fn store_overrides(&mut self, overrides: Vec<OverridePattern>) {
+        self.overrides = overrides;
+    }
+
     pub(crate) fn store_ignored_globs(&mut self, value: StringSet) {
         let formatter_configuration = self
             .formatter_configuration
@@ -263,12 +406,17 @@ impl FromPrettierConfiguration {
                 ..Default::default()
             });
         }
+        if !self.overrides.is_empty() {
+            configuration.overrides = Some(Overrides(self.overrides.clone()));
+        }
 
         configuration
     }
 
     pub(crate) fn has_configuration(&self) -> bool {
-        self.formatter_configuration.is_some() || self.javascript_formatter_configuration.is_some()
+        self.formatter_configuration.is_some()
+            || self.javascript_formatter_configuration.is_some()
+            || !self.overrides.is_empty()
     }
 
     pub(crate) fn get_configuration_path(&self) -> Option<&Path> {
@@ -276,8 +424,71 @@ impl FromPrettierConfiguration {
     }
 }
 
-const PRETTIER_CONFIG_FILES: [&str; 2] = [".prettierrc", ".prettierrc.json"];
+/// The format of a Prettier configuration file
+#[derive(Clone, Copy, Debug)]
+enum PrettierConfigFormat {
+    Json,
+    /// `.prettierrc` can be written in JSON or in YAML
+    JsonOrYaml,
+    Yaml,
+    Toml,
+    /// The configuration is the value of the `prettier` key of `package.json`
+    PackageJson,
+}
+
+impl PrettierConfigFormat {
+    /// Converts the content of a configuration file to JSON, so it can be deserialized
+    /// with [deserialize_from_json_str]. It returns `None` if the file doesn't contain
+    /// a Prettier configuration.
+    fn to_json(self, content: String) -> Result<Option<String>, String> {
+        let value: Value = match self {
+            Self::Json => return Ok(Some(content)),
+            Self::JsonOrYaml if content.trim_start().starts_with('{') => return Ok(Some(content)),
+            Self::JsonOrYaml | Self::Yaml => {
+                serde_yaml::from_str(&content).map_err(|err| err.to_string())?
+            }
+            Self::Toml => basic_toml::from_str(&content).map_err(|err| err.to_string())?,
+            Self::PackageJson => {
+                let package_json: Value =
+                    serde_json::from_str(&content).map_err(|err| err.to_string())?;
+                match package_json.get("prettier") {
+                    None => return Ok(None),
+                    Some(Value::String(shared_configuration)) => {
+                        return Err(format!(
+                            "The Prettier configuration extends the shared configuration {shared_configuration}, which Biome can't migrate."
+                        ))
+                    }
+                    Some(prettier) => prettier.clone(),
+                }
+            }
+        };
+        Ok(Some(value.to_string()))
+    }
+}
+
+/// The Prettier configuration files that Biome can read, in the order Prettier looks them up
+const PRETTIER_CONFIG_FILES: [(&str, PrettierConfigFormat); 6] = [
+    ("package.json", PrettierConfigFormat::PackageJson),
+    (".prettierrc", PrettierConfigFormat::JsonOrYaml),
+    (".prettierrc.json", PrettierConfigFormat::Json),
+    (".prettierrc.yaml", PrettierConfigFormat::Yaml),
+    (".prettierrc.yml", PrettierConfigFormat::Yaml),
+    (".prettierrc.toml", PrettierConfigFormat::Toml),
+];
 const PRETTIER_IGNORE_FILE: &str = ".prettierignore";
+
+/// Returns the first Prettier configuration file of the project. The `prettier` key of `package.json`
+/// isn't looked up.
+pub(crate) fn find_prettier_configuration_file(
+    fs: &DynRef<'_, dyn FileSystem>,
+) -> Option<&'static str> {
+    PRETTIER_CONFIG_FILES
+        .into_iter()
+        .filter(|(_, format)| !matches!(format, PrettierConfigFormat::PackageJson))
+        .map(|(config_name, _)| config_name)
+        .find(|config_name| fs.path_exists(Path::new(config_name)))
+}
+
 /// This function is in charge of reading prettier files, deserialize its contents and convert them in a Biome configuration type
 pub(crate) fn read_prettier_files(
     fs: &DynRef<'_, dyn FileSystem>,
@@ -285,16 +496,26 @@ pub(crate) fn read_prettier_files(
 ) -> Result<FromPrettierConfiguration, CliDiagnostic> {
     let mut from_prettier_configuration = FromPrettierConfiguration::default();
     let mut prettier_config_content = String::new();
-    for config_name in PRETTIER_CONFIG_FILES {
+    for (config_name, format) in PRETTIER_CONFIG_FILES {
         let open_options = OpenOptions::default().read(true);
         let path = Path::new(config_name);
         let file = fs.open_with_options(path, open_options);
         match file {
             Ok(mut file) => {
-                let result = file.read_to_string(&mut prettier_config_content);
+                let mut content = String::new();
+                let result = file.read_to_string(&mut content);
                 if let Err(err) = result {
                     return Err(CliDiagnostic::io_error(err));
                 }
+                let content = format.to_json(content).map_err(|err| {
+                    CliDiagnostic::MigrateError(MigrationDiagnostic {
+                        reason: format!("Could not parse the file {config_name}: {err}"),
+                    })
+                })?;
+                let Some(content) = content else {
+                    continue;
+                };
+                prettier_config_content = content;
                 from_prettier_configuration.store_configuration_path(path);
 
                 break;
@@ -336,7 +557,12 @@ pub(crate) fn read_prettier_files(
     } else {
         let prettier_configuration = deserialized.into_deserialized();
 
-        if let Some(prettier_configuration) = prettier_configuration {
+        if let Some(mut prettier_configuration) = prettier_configuration {
+            let overrides = std::mem::take(&mut prettier_configuration.overrides)
+                .into_iter()
+                .map(OverridePattern::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| CliDiagnostic::MigrateError(MigrationDiagnostic { reason: err }))?;
             let formatter_configuration = prettier_configuration
                 .clone()
                 .try_into()
@@ -344,6 +570,7 @@ pub(crate) fn read_prettier_files(
             let javascript_configuration = prettier_configuration.into();
             from_prettier_configuration
                 .store_configuration((formatter_configuration, javascript_configuration));
+            from_prettier_configuration.store_overrides(overrides);
         }
     }
 
@@ -376,7 +603,9 @@ pub(crate) fn read_prettier_files(
 
 #[cfg(test)]
 mod test {
-    use crate::execute::migrate::prettier::{PrettierConfiguration, PrettierTrailingComma};
+    use crate::execute::migrate::prettier::{
+        Globs, OverrideOptions, PrettierConfiguration, PrettierOverride, PrettierTrailingComma,
+    };
     use biome_deserialize::json::deserialize_from_json_str;
     use biome_json_parser::JsonParserOptions;
 
@@ -433,4 +662,44 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn overrides() {
+        let configuration = deserialize_from_json_str::<PrettierConfiguration>(
+            r#"
+{
+  "overrides": [
+    { "files": "*.test.js", "options": { "semi": true } },
+    { "files": ["legacy/**", "scripts/**"], "excludeFiles": "legacy/vendor/**", "options": { "tabWidth": 4 } }
+  ]
+}
+            "#,
+            JsonParserOptions::default(),
+            "",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        assert_eq!(
+            configuration.overrides,
+            vec![
+                PrettierOverride {
+                    files: Globs(vec!["*.test.js".to_string()]),
+                    exclude_files: Globs::default(),
+                    options: OverrideOptions {
+                        semi: Some(true),
+                        ..OverrideOptions::default()
+                    },
+                },
+                PrettierOverride {
+                    files: Globs(vec!["legacy/**".to_string(), "scripts/**".to_string()]),
+                    exclude_files: Globs(vec!["legacy/vendor/**".to_string()]),
+                    options: OverrideOptions {
+                        tab_width: Some(4),
+                        ..OverrideOptions::default()
+                    },
+                },
+            ]
+        )
+    }
 }
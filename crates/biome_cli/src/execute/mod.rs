@@ -1,16 +1,24 @@
+mod cache;
 mod diagnostics;
-mod migrate;
+pub(crate) mod migrate;
+mod patch;
 mod process_file;
+mod profile;
 mod std_in;
 mod traverse;
+mod watch;
 
+use crate::changed::ChangedLinesByFile;
 use crate::cli_options::CliOptions;
+pub(crate) use crate::execute::cache::settings_hash;
 use crate::execute::migrate::MigratePayload;
+pub(crate) use crate::execute::patch::PatchOutput;
 use crate::execute::traverse::traverse;
+pub(crate) use crate::execute::watch::watch;
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::{category, Category};
 use biome_fs::RomePath;
-use biome_service::workspace::{FeatureName, FixFileMode};
+use biome_service::workspace::{FeatureName, FixFileMode, RuleSelector};
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
@@ -25,6 +33,26 @@ pub(crate) struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// The hash of the resolved configuration. It's [Some] only when the results of the traversal
+    /// should be cached on disk
+    settings_hash: Option<u64>,
+
+    /// Whether only the files staged in the git index are processed. Their content is read
+    /// from the index, and the results of fixes and formatting are staged again
+    staged: bool,
+
+    /// The lines that changed in each file. When it's [Some], only the diagnostics and the
+    /// formatting of these lines are reported
+    changed_lines: Option<ChangedLinesByFile>,
+
+    /// Where the changes are printed as a unified diff. When it's [Some], the changes aren't
+    /// written to the files
+    patch_output: Option<PatchOutput>,
+
+    /// Whether the slowest files and the time spent by each rule and each phase of the
+    /// processing are printed after the traversal
+    profile: bool,
 }
 
 impl Execution {
@@ -91,6 +119,10 @@ pub(crate) enum TraversalMode {
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<Stdin>,
+        /// When not empty, only these rules run
+        only: Vec<RuleSelector>,
+        /// These rules don't run
+        skip: Vec<RuleSelector>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -118,6 +150,18 @@ pub(crate) enum TraversalMode {
         configuration_directory_path: PathBuf,
         /// Migrate from prettier
         prettier: bool,
+        /// Migrate from ESLint
+        eslint: bool,
+    },
+    /// This mode is enabled when running the command `biome migrate eslint-comments`
+    MigrateEslintComments {
+        /// Write result to disk
+        write: bool,
+    },
+    /// This mode is enabled when running the command `biome search`
+    Search {
+        /// The code to search, see [biome_service::workspace::SearchPatternParams]
+        pattern: String,
     },
 }
 
@@ -127,8 +171,11 @@ impl Display for TraversalMode {
             TraversalMode::Check { .. } => write!(f, "check"),
             TraversalMode::CI { .. } => write!(f, "ci"),
             TraversalMode::Format { .. } => write!(f, "format"),
-            TraversalMode::Migrate { .. } => write!(f, "migrate"),
+            TraversalMode::Migrate { .. } | TraversalMode::MigrateEslintComments { .. } => {
+                write!(f, "migrate")
+            }
             TraversalMode::Lint { .. } => write!(f, "lint"),
+            TraversalMode::Search { .. } => write!(f, "search"),
         }
     }
 }
@@ -149,6 +196,11 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            settings_hash: None,
+            staged: false,
+            changed_lines: None,
+            patch_output: None,
+            profile: false,
         }
     }
 
@@ -169,6 +221,11 @@ impl Execution {
                 },
             },
             max_diagnostics: 20,
+            settings_hash: None,
+            staged: false,
+            changed_lines: None,
+            patch_output: None,
+            profile: false,
         }
     }
 
@@ -178,9 +235,44 @@ impl Execution {
             traversal_mode,
             report_mode,
             max_diagnostics: 20,
+            settings_hash: None,
+            staged: false,
+            changed_lines: None,
+            patch_output: None,
+            profile: false,
         }
     }
 
+    /// Enables the cache of the results of the traversal, if `settings_hash` is [Some]
+    pub(crate) fn with_cache(mut self, settings_hash: Option<u64>) -> Self {
+        self.settings_hash = settings_hash;
+        self
+    }
+
+    /// Processes the content of the files that is staged in the git index
+    pub(crate) fn with_staged(mut self, staged: bool) -> Self {
+        self.staged = staged;
+        self
+    }
+
+    /// Restricts the diagnostics and the formatting to the lines that changed, if `changed_lines` is [Some]
+    pub(crate) fn with_changed_lines(mut self, changed_lines: Option<ChangedLinesByFile>) -> Self {
+        self.changed_lines = changed_lines;
+        self
+    }
+
+    /// Prints the changes as a unified diff instead of writing them, if `patch_output` is [Some]
+    pub(crate) fn with_patch_output(mut self, patch_output: Option<PatchOutput>) -> Self {
+        self.patch_output = patch_output;
+        self
+    }
+
+    /// Prints the profile of the traversal once it's done
+    pub(crate) fn with_profile(mut self, profile: bool) -> Self {
+        self.profile = profile;
+        self
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
@@ -194,6 +286,26 @@ impl Execution {
         self.max_diagnostics
     }
 
+    pub(crate) const fn settings_hash(&self) -> Option<u64> {
+        self.settings_hash
+    }
+
+    pub(crate) const fn is_staged(&self) -> bool {
+        self.staged
+    }
+
+    pub(crate) fn changed_lines(&self) -> Option<&ChangedLinesByFile> {
+        self.changed_lines.as_ref()
+    }
+
+    pub(crate) fn patch_output(&self) -> Option<&PatchOutput> {
+        self.patch_output.as_ref()
+    }
+
+    pub(crate) const fn is_profiled(&self) -> bool {
+        self.profile
+    }
+
     /// `true` only when running the traversal in [TraversalMode::Check] and `should_fix` is `true`
     pub(crate) fn as_fix_file_mode(&self) -> Option<&FixFileMode> {
         match &self.traversal_mode {
@@ -201,7 +313,35 @@ impl Execution {
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.as_ref(),
             TraversalMode::Format { .. }
             | TraversalMode::CI { .. }
-            | TraversalMode::Migrate { .. } => None,
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::MigrateEslintComments { .. }
+            | TraversalMode::Search { .. } => None,
+        }
+    }
+
+    /// The rules selected with `--only`. When it's not empty, the other rules don't run
+    pub(crate) fn only(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { only, .. } => only,
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::MigrateEslintComments { .. }
+            | TraversalMode::Search { .. } => &[],
+        }
+    }
+
+    /// The rules selected with `--skip`, they don't run
+    pub(crate) fn skip(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { skip, .. } => skip,
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::MigrateEslintComments { .. }
+            | TraversalMode::Search { .. } => &[],
         }
     }
 
@@ -211,7 +351,10 @@ impl Execution {
             TraversalMode::Lint { .. } => category!("lint"),
             TraversalMode::CI { .. } => category!("ci"),
             TraversalMode::Format { .. } => category!("format"),
-            TraversalMode::Migrate { .. } => category!("migrate"),
+            TraversalMode::Migrate { .. } | TraversalMode::MigrateEslintComments { .. } => {
+                category!("migrate")
+            }
+            TraversalMode::Search { .. } => category!("search"),
         }
     }
 
@@ -261,12 +404,18 @@ impl Execution {
 
     /// Whether the traversal mode requires write access to files
     pub(crate) const fn requires_write_access(&self) -> bool {
+        // The changes are printed as a patch
+        if self.patch_output.is_some() {
+            return false;
+        }
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write: dry_run, .. } => dry_run,
+            TraversalMode::MigrateEslintComments { write } => write,
+            TraversalMode::Search { .. } => false,
         }
     }
 
@@ -275,7 +424,10 @@ impl Execution {
             TraversalMode::Format { stdin, .. }
             | TraversalMode::Lint { stdin, .. }
             | TraversalMode::Check { stdin, .. } => stdin.as_ref(),
-            TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => None,
+            TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::MigrateEslintComments { .. }
+            | TraversalMode::Search { .. } => None,
         }
     }
 }
@@ -305,6 +457,7 @@ pub(crate) fn execute_mode(
         configuration_file_path,
         configuration_directory_path,
         prettier,
+        eslint,
     } = mode.traversal_mode
     {
         let payload = MigratePayload {
@@ -314,6 +467,7 @@ pub(crate) fn execute_mode(
             configuration_directory_path,
             verbose: cli_options.verbose,
             prettier,
+            eslint,
         };
         migrate::run(payload)
     } else {
@@ -0,0 +1,93 @@
+use similar::TextDiff;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// Where the changes are printed when the `--diff` or the `--output-patch` option is used
+#[derive(Debug)]
+pub(crate) enum PatchOutput {
+    /// The patch is printed to the standard output
+    Stdout,
+    /// The patch is written to the given file
+    File(PathBuf),
+}
+
+impl PatchOutput {
+    /// The name of the argument that enabled the patch, used by the diagnostics
+    pub(crate) const fn as_argument(&self) -> &'static str {
+        match self {
+            PatchOutput::Stdout => "--diff",
+            PatchOutput::File(_) => "--output-patch",
+        }
+    }
+}
+
+/// Collects the changes that the traversal would write to the files, and turns them into a
+/// unified diff that can be applied with `git apply`
+#[derive(Default)]
+pub(crate) struct Patch {
+    /// The original content and the new content of each changed file
+    files: Mutex<BTreeMap<PathBuf, (String, String)>>,
+}
+
+impl Patch {
+    /// Records the `new` content of the file at `path`. The original content of the file is
+    /// only read when the file is changed for the first time.
+    pub(crate) fn record(&self, path: &Path, original: impl FnOnce() -> String, new: String) {
+        let mut files = self.files.lock().unwrap();
+        match files.get_mut(path) {
+            Some((_, content)) => *content = new,
+            None => {
+                files.insert(path.to_path_buf(), (original(), new));
+            }
+        }
+    }
+
+    /// The number of files that changed
+    pub(crate) fn len(&self) -> usize {
+        self.files
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|(original, new)| original != new)
+            .count()
+    }
+
+    /// Returns the unified diff of all the changed files, sorted by path. The paths are made
+    /// relative to the `working_directory`.
+    pub(crate) fn to_unified_diff(&self, working_directory: Option<&Path>) -> String {
+        let files = self.files.lock().unwrap();
+        let mut patch = String::new();
+        for (path, (original, new)) in files.iter() {
+            if original == new {
+                continue;
+            }
+            let path = to_patch_path(path, working_directory);
+            let diff = TextDiff::from_lines(original.as_str(), new.as_str());
+            patch.push_str(
+                &diff
+                    .unified_diff()
+                    .header(&format!("a/{path}"), &format!("b/{path}"))
+                    .to_string(),
+            );
+        }
+        patch
+    }
+}
+
+/// Paths in a patch are relative and use forward slashes, e.g. `./src/index.js` and
+/// `/workspace/src/index.js` with `/workspace` as working directory become `src/index.js`
+fn to_patch_path(path: &Path, working_directory: Option<&Path>) -> String {
+    let path = working_directory
+        .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+        .unwrap_or(path);
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(_) | Component::ParentDir => {
+                Some(component.as_os_str().to_string_lossy())
+            }
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -1,19 +1,25 @@
 mod check;
+mod eslint_comments;
 mod format;
 mod lint;
 mod organize_imports;
+mod search;
 pub(crate) mod workspace_file;
 
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::process_file::check::check_file;
+use crate::execute::process_file::eslint_comments::migrate_eslint_comments;
 use crate::execute::process_file::format::format;
 use crate::execute::process_file::lint::lint;
+use crate::execute::process_file::search::search;
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use crate::CliDiagnostic;
 use biome_diagnostics::{category, DiagnosticExt, DiagnosticTags, Error};
-use biome_fs::RomePath;
-use biome_service::workspace::{FeatureName, FeaturesBuilder, SupportKind, SupportsFeatureParams};
+use biome_fs::{OpenOptions, RomePath};
+use biome_service::workspace::{
+    FeatureName, FeaturesBuilder, FileFeaturesResult, SupportKind, SupportsFeatureParams,
+};
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::path::Path;
@@ -60,6 +66,7 @@ impl Message {
 pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
+    EslintComments,
 }
 
 impl<D> From<D> for Message
@@ -205,8 +212,11 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
                         }),
                 ),
             TraversalMode::Format { .. } => file_features.support_kind_for(&FeatureName::Format),
-            TraversalMode::Lint { .. } => file_features.support_kind_for(&FeatureName::Lint),
-            TraversalMode::Migrate { .. } => None,
+            TraversalMode::Lint { .. } | TraversalMode::MigrateEslintComments { .. } => {
+                file_features.support_kind_for(&FeatureName::Lint)
+            }
+            // The pattern decides which files can be searched
+            TraversalMode::Migrate { .. } | TraversalMode::Search { .. } => None,
         };
 
         if let Some(reason) = unsupported_reason {
@@ -229,6 +239,10 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
         let shared_context = &SharedTraversalOptions::new(ctx);
         ctx.increment_processed();
 
+        if let Some(result) = process_cached_file(ctx, path, &file_features) {
+            return result;
+        }
+
         match ctx.execution.traversal_mode {
             TraversalMode::Lint { .. } => {
                 // the unsupported case should be handled already at this point
@@ -244,9 +258,82 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
             TraversalMode::CI { .. } => {
                 check_file(shared_context, path, &file_features, category!("ci"))
             }
+            TraversalMode::MigrateEslintComments { .. } => {
+                migrate_eslint_comments(shared_context, path)
+            }
+            TraversalMode::Search { ref pattern } => search(shared_context, path, pattern),
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
         }
     })
 }
+
+/// It replays the results stored in the cache by a previous run, if the file didn't change
+/// since then and the cache holds the results of all the features required by the traversal.
+///
+/// It returns [None] when the file needs to be processed.
+fn process_cached_file(
+    ctx: &TraversalOptions,
+    path: &Path,
+    file_features: &FileFeaturesResult,
+) -> Option<FileResult> {
+    // The cached diagnostics aren't restricted to the changed lines
+    if ctx.execution.changed_lines().is_some() {
+        return None;
+    }
+    let cache = ctx.cache?;
+    let mut content = String::new();
+    ctx.open_file(path, OpenOptions::default().read(true))
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .ok()?;
+    let entry = cache.get(path, &content)?;
+
+    let (needs_lint, needs_format, needs_organize_imports) = match ctx.execution.traversal_mode() {
+        TraversalMode::Lint { .. } => (true, false, false),
+        TraversalMode::Format { .. } => (false, true, false),
+        TraversalMode::Check { .. } | TraversalMode::CI { .. } => (
+            file_features.supports_for(&FeatureName::Lint),
+            file_features.supports_for(&FeatureName::Format),
+            file_features.supports_for(&FeatureName::OrganizeImports),
+        ),
+        TraversalMode::Migrate { .. }
+        | TraversalMode::MigrateEslintComments { .. }
+        | TraversalMode::Search { .. } => return None,
+    };
+
+    if (needs_format && !entry.is_formatted())
+        || (needs_organize_imports && !entry.has_imports_sorted())
+    {
+        return None;
+    }
+
+    if !needs_lint {
+        return Some(Ok(FileStatus::Success));
+    }
+
+    let lint = entry.lint()?;
+    if lint.diagnostics.is_empty() {
+        return Some(Ok(FileStatus::Success));
+    }
+    // the cached diagnostics might have fixes that need to be applied
+    if ctx.execution.as_fix_file_mode().is_some() {
+        return None;
+    }
+
+    let file_name = path.display().to_string();
+    ctx.push_message(Message::Diagnostics {
+        name: file_name.clone(),
+        content,
+        diagnostics: lint.diagnostics.iter().cloned().map(Error::from).collect(),
+        skipped_diagnostics: 0,
+    });
+
+    if lint.errors > 0 {
+        Some(Ok(FileStatus::Message(Message::ApplyError(
+            CliDiagnostic::file_check_error(file_name, ctx.execution.as_diagnostic_category()),
+        ))))
+    } else {
+        Some(Ok(FileStatus::Success))
+    }
+}
@@ -0,0 +1,94 @@
+use crate::execute::diagnostics::{ResultExt, UnsupportedEslintRuleDiagnostic};
+use crate::execute::migrate::eslint_comments::EslintComments;
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
+use crate::execute::TraversalMode;
+use biome_diagnostics::{category, Diagnostic, Error};
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use biome_service::workspace::RuleCategories;
+use std::path::Path;
+
+/// Rewrites the ESLint suppression comments of a single file and returns a [FileResult]
+pub(crate) fn migrate_eslint_comments<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    path: &Path,
+) -> FileResult {
+    // The linter also handles JSON files, which don't have ESLint comments
+    let Ok(file_source) = JsFileSource::try_from(path) else {
+        return Ok(FileStatus::Ignored);
+    };
+    let mut workspace_file = WorkspaceFile::new(ctx, path)?;
+    let input = workspace_file.input()?;
+
+    let root = parse(&input, file_source, JsParserOptions::default()).syntax();
+    let comments = EslintComments::parse(&root);
+    if comments.is_empty() {
+        return Ok(FileStatus::Success);
+    }
+
+    let diagnostics = if comments.has_disabled_ranges() {
+        workspace_file
+            .guard()
+            .pull_diagnostics(RuleCategories::LINT, u64::MAX, Vec::new(), Vec::new())
+            .with_file_path_and_code(
+                workspace_file.path.display().to_string(),
+                category!("migrate"),
+            )?
+            .diagnostics
+    } else {
+        Vec::new()
+    };
+    let diagnostics: Vec<_> = diagnostics
+        .iter()
+        .filter_map(|diagnostic| {
+            Some((
+                diagnostic.location().span?.start(),
+                diagnostic.category()?.name(),
+            ))
+        })
+        .collect();
+
+    let migrated = comments.migrate(&root, &diagnostics);
+    let file_name = workspace_file.path.display().to_string();
+
+    if !migrated.unsupported_rules.is_empty() {
+        ctx.push_message(Message::Diagnostics {
+            name: file_name.clone(),
+            content: input.clone(),
+            diagnostics: migrated
+                .unsupported_rules
+                .into_iter()
+                .map(|(span, rule)| {
+                    Error::from(UnsupportedEslintRuleDiagnostic {
+                        span,
+                        message: format!(
+                            "The ESLint rule {rule} doesn't have an equivalent in Biome, it was removed from the suppression comment."
+                        ),
+                    })
+                })
+                .collect(),
+            skipped_diagnostics: 0,
+        });
+    }
+
+    if migrated.code == input {
+        return Ok(FileStatus::Success);
+    }
+    if matches!(
+        ctx.execution.traversal_mode(),
+        TraversalMode::MigrateEslintComments { write: true }
+    ) {
+        workspace_file.update_file(migrated.code)?;
+        Ok(FileStatus::Success)
+    } else {
+        Ok(FileStatus::Message(Message::Diff {
+            file_name,
+            old: input,
+            new: migrated.code,
+            diff_kind: DiffKind::EslintComments,
+        }))
+    }
+}
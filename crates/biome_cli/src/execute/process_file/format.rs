@@ -6,6 +6,8 @@ use crate::execute::process_file::{
 use crate::execute::TraversalMode;
 use biome_diagnostics::{category, DiagnosticExt};
 use biome_service::workspace::RuleCategories;
+use biome_service::WorkspaceError;
+use biome_text_size::{TextRange, TextSize};
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use tracing::debug;
@@ -25,7 +27,12 @@ pub(crate) fn format_with_guard<'ctx>(
             debug!("Pulling diagnostics from parsed file");
             let diagnostics_result = workspace_file
                 .guard()
-                .pull_diagnostics(RuleCategories::SYNTAX, max_diagnostics.into())
+                .pull_diagnostics(
+                    RuleCategories::SYNTAX,
+                    max_diagnostics.into(),
+                    Vec::new(),
+                    Vec::new(),
+                )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("format"),
@@ -37,7 +44,8 @@ pub(crate) fn format_with_guard<'ctx>(
                     write,
                     ignore_errors,
                     ..
-                } => (write, ignore_errors),
+                    // The changes printed as a patch are "written" to the patch
+                } => (write || ctx.patch.is_some(), ignore_errors),
 
                 _ => (
                     ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe(),
@@ -53,15 +61,22 @@ pub(crate) fn format_with_guard<'ctx>(
                 ));
             }
 
-            let printed = workspace_file
-                .guard()
-                .format_file()
-                .with_file_path_and_code(
-                    workspace_file.path.display().to_string(),
-                    category!("format"),
-                )?;
-
-            let output = printed.into_code();
+            let changed_lines = ctx.execution.changed_lines();
+            let output = match changed_lines {
+                Some(changed_lines) => format_changed_lines(
+                    workspace_file,
+                    &input,
+                    changed_lines.text_ranges(&workspace_file.path, &input),
+                ),
+                None => workspace_file
+                    .guard()
+                    .format_file()
+                    .map(|printed| printed.into_code()),
+            }
+            .with_file_path_and_code(
+                workspace_file.path.display().to_string(),
+                category!("format"),
+            )?;
 
             // NOTE: ignoring the
             if ignore_errors {
@@ -79,8 +94,49 @@ pub(crate) fn format_with_guard<'ctx>(
                         diff_kind: DiffKind::Format,
                     }));
                 }
+            } else if let Some(cache) = ctx.cache {
+                // Only some ranges are formatted when the changed lines are given
+                if changed_lines.is_none() {
+                    cache.record_formatted(&workspace_file.path, &input);
+                }
             }
             Ok(FileStatus::Success)
         },
     )
 }
+
+/// Formats the given `ranges` of the file, and returns the new content of the whole file
+fn format_changed_lines(
+    workspace_file: &WorkspaceFile,
+    input: &str,
+    ranges: Vec<TextRange>,
+) -> Result<String, WorkspaceError> {
+    // The formatter can extend a range to the nodes it covers, so the formatted ranges can overlap.
+    // Overlapping ranges are merged and formatted again.
+    let mut edits: Vec<(TextRange, String)> = Vec::new();
+    for range in ranges {
+        let mut range = range;
+        loop {
+            let printed = workspace_file.guard().format_range(range)?;
+            let formatted_range = printed
+                .range()
+                .unwrap_or_else(|| TextRange::up_to(TextSize::of(input)));
+            match edits.last() {
+                Some((last_range, _)) if last_range.intersect(formatted_range).is_some() => {
+                    range = last_range.cover(formatted_range);
+                    edits.pop();
+                }
+                _ => {
+                    edits.push((formatted_range, printed.into_code()));
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut output = input.to_string();
+    for (range, code) in edits.into_iter().rev() {
+        output.replace_range(std::ops::Range::<usize>::from(range), &code);
+    }
+    Ok(output)
+}
@@ -1,9 +1,10 @@
+use crate::changed::ChangedLinesByFile;
 use crate::execute::diagnostics::ResultExt;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use crate::CliDiagnostic;
-use biome_diagnostics::{category, Error};
-use biome_service::workspace::RuleCategories;
+use biome_diagnostics::{category, Diagnostic, Error, LineIndexBuf, Severity};
+use biome_service::workspace::{PullDiagnosticsResult, RuleCategories};
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
@@ -25,7 +26,12 @@ pub(crate) fn lint_with_guard<'ctx>(
             if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
                 let fix_result = workspace_file
                     .guard()
-                    .fix_file(*fix_mode, false)
+                    .fix_file(
+                        *fix_mode,
+                        false,
+                        ctx.execution.only().to_vec(),
+                        ctx.execution.skip().to_vec(),
+                    )
                     .with_file_path_and_code(
                         workspace_file.path.display().to_string(),
                         category!("lint"),
@@ -43,14 +49,47 @@ pub(crate) fn lint_with_guard<'ctx>(
             }
 
             let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
-            let pull_diagnostics_result = workspace_file
+            let changed_lines = ctx.execution.changed_lines();
+            let mut pull_diagnostics_result = workspace_file
                 .guard()
-                .pull_diagnostics(RuleCategories::LINT, max_diagnostics.into())
+                .pull_diagnostics(
+                    RuleCategories::LINT,
+                    // The diagnostics outside the changed lines are dropped, so all of them are needed
+                    if changed_lines.is_some() {
+                        u64::MAX
+                    } else {
+                        max_diagnostics.into()
+                    },
+                    ctx.execution.only().to_vec(),
+                    ctx.execution.skip().to_vec(),
+                )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
+            if let Some(cache) = ctx.cache {
+                // the result is stored only if it's complete
+                if pull_diagnostics_result.skipped_diagnostics == 0 {
+                    cache.record_lint(
+                        &workspace_file.path,
+                        &input,
+                        pull_diagnostics_result.diagnostics.clone(),
+                        pull_diagnostics_result.errors,
+                    );
+                }
+            }
+
+            if let Some(changed_lines) = changed_lines {
+                retain_changed_lines(
+                    &mut pull_diagnostics_result,
+                    changed_lines,
+                    &workspace_file.path,
+                    &input,
+                    max_diagnostics.into(),
+                );
+            }
+
             let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
                 && pull_diagnostics_result.skipped_diagnostics == 0;
             errors += pull_diagnostics_result.errors;
@@ -90,3 +129,27 @@ pub(crate) fn lint_with_guard<'ctx>(
         },
     )
 }
+
+/// Drops the diagnostics that don't touch any of the changed lines. Diagnostics without a span
+/// concern the whole file, and they are kept.
+fn retain_changed_lines(
+    result: &mut PullDiagnosticsResult,
+    changed_lines: &ChangedLinesByFile,
+    path: &Path,
+    input: &str,
+    max_diagnostics: usize,
+) {
+    let line_index = LineIndexBuf::from_source_text(input);
+    result.diagnostics.retain(|diagnostic| {
+        diagnostic.location().span.map_or(true, |span| {
+            changed_lines.intersects(path, &line_index, span)
+        })
+    });
+    result.errors = result
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity() >= Severity::Error)
+        .count();
+    result.skipped_diagnostics = result.diagnostics.len().saturating_sub(max_diagnostics) as u64;
+    result.diagnostics.truncate(max_diagnostics);
+}
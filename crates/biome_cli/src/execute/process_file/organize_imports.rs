@@ -32,6 +32,8 @@ pub(crate) fn organize_imports_with_guard<'ctx>(
                         diff_kind: DiffKind::OrganizeImports,
                     }));
                 }
+            } else if let Some(cache) = ctx.cache {
+                cache.record_imports_sorted(&workspace_file.path, &input);
             }
 
             Ok(FileStatus::Success)
@@ -0,0 +1,43 @@
+use crate::execute::diagnostics::{
+    InvalidSearchPatternDiagnostic, ResultExt, SearchMatchDiagnostic,
+};
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use biome_diagnostics::{category, Error};
+use std::path::Path;
+
+/// Searches a pattern in a single file and returns a [FileResult]
+pub(crate) fn search<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    path: &Path,
+    pattern: &str,
+) -> FileResult {
+    let workspace_file = WorkspaceFile::new(ctx, path)?;
+    let result = workspace_file
+        .guard()
+        .search_pattern(pattern)
+        .with_file_path_and_code(path.display().to_string(), category!("search"))?;
+
+    // The pattern can be written in another language, for example a JavaScript pattern in a JSON file
+    if !result.valid_pattern {
+        ctx.push_message(InvalidSearchPatternDiagnostic {
+            file_path: workspace_file.path.display().to_string(),
+        });
+        return Ok(FileStatus::Ignored);
+    }
+
+    if !result.matches.is_empty() {
+        ctx.push_message(Message::Diagnostics {
+            name: workspace_file.path.display().to_string(),
+            content: workspace_file.input()?,
+            diagnostics: result
+                .matches
+                .into_iter()
+                .map(|span| Error::from(SearchMatchDiagnostic { span }))
+                .collect(),
+            skipped_diagnostics: 0,
+        });
+    }
+
+    Ok(FileStatus::Success)
+}
@@ -1,4 +1,5 @@
 use crate::execute::diagnostics::{ResultExt, ResultIoExt};
+use crate::execute::patch::Patch;
 use crate::execute::process_file::SharedTraversalOptions;
 use biome_diagnostics::{category, Error};
 use biome_fs::{File, OpenOptions, RomePath};
@@ -12,6 +13,11 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
     guard: FileGuard<'app, dyn Workspace + 'ctx>,
     file: Box<dyn File>,
     pub(crate) path: PathBuf,
+    /// The version of the content of the file in the workspace. The file on disk isn't written
+    /// when the changes are printed as a patch, so its version can't be used
+    version: i32,
+    /// Collects the changes instead of writing them, if they're printed as a patch
+    patch: Option<&'ctx Patch>,
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
@@ -26,8 +32,7 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             .read(true)
             .write(ctx.execution.requires_write_access());
         let mut file = ctx
-            .fs
-            .open_with_options(path, open_options)
+            .open_file(path, open_options)
             .with_file_path(path.display().to_string())?;
 
         let mut input = String::new();
@@ -49,6 +54,8 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             file,
             guard,
             path: PathBuf::from(path),
+            version: 0,
+            patch: ctx.patch,
         })
     }
 
@@ -60,14 +67,24 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         self.guard().get_file_content()
     }
 
-    /// It updates the workspace file with `new_content`
+    /// It updates the workspace file with `new_content`. When the changes are printed as a patch,
+    /// the file on disk isn't written.
     pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
         let new_content = new_content.into();
-        self.file
-            .set_content(new_content.as_bytes())
-            .with_file_path(self.path.display().to_string())?;
-        self.guard
-            .change_file(self.file.file_version(), new_content)?;
+        if let Some(patch) = self.patch {
+            let guard = &self.guard;
+            patch.record(
+                &self.path,
+                || guard.get_file_content().unwrap_or_default(),
+                new_content.clone(),
+            );
+        } else {
+            self.file
+                .set_content(new_content.as_bytes())
+                .with_file_path(self.path.display().to_string())?;
+        }
+        self.version += 1;
+        self.guard.change_file(self.version, new_content)?;
         Ok(())
     }
 }
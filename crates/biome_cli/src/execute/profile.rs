@@ -0,0 +1,105 @@
+use biome_analyze::profiling;
+use biome_console::{markup, Console, ConsoleExt};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The number of files listed in the "slowest files" table
+const SLOWEST_FILES: usize = 10;
+
+/// Collects the time spent processing each file when the `--profile` option is used, and
+/// prints it along with the timings recorded by the analyzer and the workspace
+pub(crate) struct Profiler {
+    files: Mutex<Vec<(PathBuf, Duration)>>,
+}
+
+impl Profiler {
+    /// Starts profiling. The timings recorded by a previous run, e.g. in watch mode, are discarded
+    pub(crate) fn start() -> Self {
+        profiling::enable();
+        profiling::drain();
+        Self {
+            files: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn record_file(&self, path: &Path, duration: Duration) {
+        self.files
+            .lock()
+            .unwrap()
+            .push((path.to_path_buf(), duration));
+    }
+
+    /// Prints the slowest files, and the cumulative time spent by each phase and by each lint rule
+    pub(crate) fn print(self, console: &mut dyn Console) {
+        let profile = profiling::drain();
+
+        let mut files = self.files.into_inner().unwrap();
+        files.sort_by(|(_, left), (_, right)| right.cmp(left));
+        files.truncate(SLOWEST_FILES);
+
+        console.log(markup! {
+            <Emphasis>"Slowest files"</Emphasis>
+        });
+        for (path, duration) in files {
+            let duration = format_duration(duration);
+            let path = path.display().to_string();
+            console.log(markup! {
+                "  "{duration}"  "{path}
+            });
+        }
+
+        let mut phases: Vec<_> = profile.phases.into_iter().collect();
+        phases.sort_by(|(_, left), (_, right)| right.total.cmp(&left.total));
+        print_table(
+            console,
+            "Time spent per phase",
+            phases
+                .into_iter()
+                .map(|(phase, timing)| (phase.to_string(), format_duration(timing.total))),
+        );
+
+        let mut rules: Vec<_> = profile.rules.into_iter().collect();
+        rules.sort_by(|(left_key, left), (right_key, right)| {
+            right.total.cmp(&left.total).then(left_key.cmp(right_key))
+        });
+        print_table(
+            console,
+            "Time spent per rule",
+            rules.into_iter().map(|(rule, timing)| {
+                (
+                    format!("lint/{}/{}", rule.group(), rule.rule_name()),
+                    format!(
+                        "{}  ({} run(s))",
+                        format_duration(timing.total),
+                        timing.count
+                    ),
+                )
+            }),
+        );
+    }
+}
+
+fn print_table(
+    console: &mut dyn Console,
+    title: &str,
+    rows: impl Iterator<Item = (String, String)>,
+) {
+    let rows: Vec<_> = rows.collect();
+    let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+
+    console.log(markup! {
+        <Emphasis>{title}</Emphasis>
+    });
+    for (name, value) in rows {
+        let row = format!("  {name:<width$}  {value}");
+        console.log(markup! {
+            {row}
+        });
+    }
+}
+
+/// Formats a duration in milliseconds, right-aligned so the tables line up
+fn format_duration(duration: Duration) -> String {
+    format!("{:>10.3}ms", duration.as_secs_f64() * 1000.0)
+}
@@ -1,16 +1,20 @@
+use super::cache::FileCache;
+use super::patch::{Patch, PatchOutput};
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
+use super::profile::Profiler;
 use super::ExecutionEnvironment;
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
-    FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    EslintCommentsDiffDiagnostic, FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic,
+    PanicDiagnostic,
 };
 use crate::{CliDiagnostic, CliSession, Execution, FormatterReportSummary, Report, TraversalMode};
 use biome_console::{fmt, markup, Console, ConsoleExt};
 use biome_diagnostics::PrintGitHubDiagnostic;
 use biome_diagnostics::{category, DiagnosticExt, Error, PrintDiagnostic, Resource, Severity};
 use biome_diagnostics::{Diagnostic, DiagnosticTags};
-use biome_fs::{FileSystem, PathInterner, RomePath};
+use biome_fs::{File, FileSystem, FileSystemExt, OpenOptions, PathInterner, RomePath};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams};
 use biome_service::{
@@ -80,6 +84,14 @@ pub(crate) fn traverse(
 
     let mut report = Report::default();
 
+    let cache = execution
+        .settings_hash()
+        .map(|settings_hash| FileCache::load(fs, &execution, settings_hash));
+
+    let profiler = execution.is_profiled().then(Profiler::start);
+
+    let patch = execution.patch_output().map(|_| Patch::default());
+
     let printer = DiagnosticsPrinter::new(&execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
@@ -107,6 +119,9 @@ pub(crate) fn traverse(
                 skipped: &skipped,
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
+                cache: cache.as_ref(),
+                profiler: profiler.as_ref(),
+                patch: patch.as_ref(),
             },
         );
         // wait for the main thread to finish
@@ -115,19 +130,50 @@ pub(crate) fn traverse(
         elapsed
     });
 
+    if let Some(cache) = cache {
+        cache.persist(fs);
+    }
+
+    if let (Some(patch), Some(PatchOutput::File(path))) = (&patch, execution.patch_output()) {
+        let diff = patch.to_unified_diff(fs.working_directory().as_deref());
+        fs.create(path)
+            .and_then(|mut file| file.set_content(diff.as_bytes()))
+            .map_err(CliDiagnostic::io_error)?;
+    }
+
     let errors = printer.errors();
     let warnings = printer.warnings();
     let count = processed.load(Ordering::Relaxed);
     let skipped = skipped.load(Ordering::Relaxed);
 
     if execution.should_report_to_terminal() {
-        match execution.traversal_mode() {
-            TraversalMode::Check { .. } | TraversalMode::Lint { .. } => {
-                if execution.as_fix_file_mode().is_some() {
-                    console.log(markup! {
-                        <Info>"Fixed "{count}" file(s) in "{duration}</Info>
-                    });
-                } else {
+        if let Some(patch_output) = execution.patch_output() {
+            // The standard output only contains the patch
+            if let (Some(patch), PatchOutput::File(path)) = (&patch, patch_output) {
+                let changed = patch.len();
+                let path = path.display().to_string();
+                console.log(markup! {
+                    <Info>"Wrote "{path}" with the changes of "{changed}" file(s) in "{duration}</Info>
+                });
+            }
+        } else {
+            match execution.traversal_mode() {
+                TraversalMode::Check { .. } | TraversalMode::Lint { .. } => {
+                    if execution.as_fix_file_mode().is_some() {
+                        console.log(markup! {
+                            <Info>"Fixed "{count}" file(s) in "{duration}</Info>
+                        });
+                    } else {
+                        console.log(markup!({
+                            CheckResult {
+                                count,
+                                duration,
+                                errors,
+                            }
+                        }));
+                    }
+                }
+                TraversalMode::CI { .. } => {
                     console.log(markup!({
                         CheckResult {
                             count,
@@ -136,37 +182,46 @@ pub(crate) fn traverse(
                         }
                     }));
                 }
-            }
-            TraversalMode::CI { .. } => {
-                console.log(markup!({
-                    CheckResult {
-                        count,
-                        duration,
-                        errors,
-                    }
-                }));
-            }
-            TraversalMode::Format { write: false, .. } => {
-                console.log(markup! {
-                    <Info>"Compared "{count}" file(s) in "{duration}</Info>
-                });
-            }
-            TraversalMode::Format { write: true, .. } => {
-                console.log(markup! {
-                    <Info>"Formatted "{count}" file(s) in "{duration}</Info>
-                });
-            }
+                TraversalMode::Format { write: false, .. } => {
+                    console.log(markup! {
+                        <Info>"Compared "{count}" file(s) in "{duration}</Info>
+                    });
+                }
+                TraversalMode::Format { write: true, .. } => {
+                    console.log(markup! {
+                        <Info>"Formatted "{count}" file(s) in "{duration}</Info>
+                    });
+                }
 
-            TraversalMode::Migrate { write: false, .. } => {
-                console.log(markup! {
-                    <Info>"Checked your configuration file in "{duration}</Info>
-                });
-            }
+                TraversalMode::Migrate { write: false, .. } => {
+                    console.log(markup! {
+                        <Info>"Checked your configuration file in "{duration}</Info>
+                    });
+                }
 
-            TraversalMode::Migrate { write: true, .. } => {
-                console.log(markup! {
-                    <Info>"Migrated your configuration file in "{duration}</Info>
-                });
+                TraversalMode::Migrate { write: true, .. } => {
+                    console.log(markup! {
+                        <Info>"Migrated your configuration file in "{duration}</Info>
+                    });
+                }
+
+                TraversalMode::MigrateEslintComments { write: false } => {
+                    console.log(markup! {
+                        <Info>"Checked "{count}" file(s) in "{duration}</Info>
+                    });
+                }
+
+                TraversalMode::MigrateEslintComments { write: true } => {
+                    console.log(markup! {
+                        <Info>"Migrated the comments of "{count}" file(s) in "{duration}</Info>
+                    });
+                }
+
+                TraversalMode::Search { .. } => {
+                    console.log(markup! {
+                        <Info>"Searched "{count}" file(s) in "{duration}</Info>
+                    });
+                }
             }
         }
     } else {
@@ -187,12 +242,23 @@ pub(crate) fn traverse(
         return Ok(());
     }
 
+    if let Some(profiler) = profiler {
+        profiler.print(console);
+    }
+
     if skipped > 0 {
         console.log(markup! {
             <Warn>"Skipped "{skipped}" file(s)"</Warn>
         });
     }
 
+    if let (Some(patch), Some(PatchOutput::Stdout)) = (&patch, execution.patch_output()) {
+        let diff = patch.to_unified_diff(fs.working_directory().as_deref());
+        console.append(markup! {
+            {diff}
+        });
+    }
+
     let should_exit_on_warnings = warnings > 0 && cli_options.error_on_warnings;
     // Processing emitted error diagnostics, exit with a non-zero code
     if count.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
@@ -466,14 +532,19 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     new,
                     diff_kind,
                 } => {
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    // The migration of the comments only proposes its changes
+                    let is_migration = matches!(diff_kind, DiffKind::EslintComments);
+                    let is_error = !is_migration
+                        && (self.execution.is_ci() || !self.execution.is_format_write());
                     // A diff is an error in CI mode and in format check mode
-                    if self.execution.is_ci() || !self.execution.is_format_write() {
+                    if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if is_migration {
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -508,6 +579,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
                                 }
+                                DiffKind::EslintComments => {
+                                    let diag = EslintCommentsDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(Error::from(diag))
+                                }
                             };
                         } else {
                             match diff_kind {
@@ -531,6 +612,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(Error::from(diag))
                                 }
+                                DiffKind::EslintComments => {
+                                    let diag = EslintCommentsDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(Error::from(diag))
+                                }
                             };
                         }
                     }
@@ -597,9 +688,25 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU16,
+    /// The cache of the results of previous runs, if enabled
+    pub(crate) cache: Option<&'ctx FileCache>,
+    /// Records the time spent processing each file, if profiling is enabled
+    profiler: Option<&'ctx Profiler>,
+    /// Collects the changes to the files instead of writing them, if they're printed as a patch
+    pub(crate) patch: Option<&'ctx Patch>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
+    /// Opens the file at `path`. When only the staged files are processed, the content of the
+    /// file is read from the git index.
+    pub(crate) fn open_file(&self, path: &Path, options: OpenOptions) -> io::Result<Box<dyn File>> {
+        if self.execution.is_staged() {
+            self.fs.open_staged(path)
+        } else {
+            self.fs.open_with_options(path, options)
+        }
+    }
+
     pub(crate) fn increment_processed(&self) {
         self.processed.fetch_add(1, Ordering::Relaxed);
     }
@@ -688,9 +795,16 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                     || file_features.supports_for(&FeatureName::OrganizeImports)
             }
             TraversalMode::Format { .. } => file_features.supports_for(&FeatureName::Format),
-            TraversalMode::Lint { .. } => file_features.supports_for(&FeatureName::Lint),
+            TraversalMode::Lint { .. } | TraversalMode::MigrateEslintComments { .. } => {
+                file_features.supports_for(&FeatureName::Lint)
+            }
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
+            TraversalMode::Search { .. } => {
+                !file_features.is_ignored()
+                    && !file_features.is_protected()
+                    && !file_features.is_not_supported()
+            }
         }
     }
 
@@ -703,7 +817,13 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &Path) {
-    match catch_unwind(move || process_file(ctx, path)) {
+    let start = Instant::now();
+    let result = catch_unwind(move || process_file(ctx, path));
+    if let Some(profiler) = ctx.profiler {
+        profiler.record_file(path, start.elapsed());
+    }
+
+    match result {
         Ok(Ok(FileStatus::Success)) => {}
         Ok(Ok(FileStatus::Message(msg))) => {
             ctx.push_message(msg);
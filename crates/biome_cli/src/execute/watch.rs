@@ -0,0 +1,180 @@
+use crate::cli_options::CliOptions;
+use crate::execute::cache::content_hash;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::{Diagnostic, PrintDiagnostic};
+use biome_fs::{FileSystemExt, RomePath};
+use biome_service::configuration::load_configuration;
+use biome_service::workspace::{FeaturesBuilder, SupportsFeatureParams};
+use rustc_hash::FxHashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Runs a command in watch mode.
+///
+/// The command is run a first time on `paths`, then Biome watches these paths and runs the command
+/// again on the files that change. When a configuration file changes, the command is run on all
+/// the `paths` again, so the new configuration is applied to every file.
+///
+/// The command reloads the configuration every time it's run.
+///
+/// The files written by a run, with `--write` or `--apply` for example, are changes too. They're
+/// skipped when their content is still the one left by the run, so a run doesn't trigger another
+/// run. After a run on all the `paths`, the watcher is restarted instead, like after the first run.
+pub(crate) fn watch(
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+    mut run: impl FnMut(CliSession, Vec<OsString>) -> Result<(), CliDiagnostic>,
+) -> Result<(), CliDiagnostic> {
+    let result = run(session.reborrow(), paths.clone());
+    print_result(&mut session, cli_options, result);
+
+    let mut watched_configuration_files = configuration_files(&session, cli_options);
+    // The hashes of the contents of the files, as they were left by the run that processed them
+    let mut processed_contents = FxHashMap::default();
+    'watch: loop {
+        let watched_paths: Vec<_> = paths
+            .iter()
+            .map(PathBuf::from)
+            .chain(watched_configuration_files.iter().cloned())
+            .collect();
+        let watcher = session.app.fs.watch(&watched_paths)?;
+        print_watching(&mut session);
+
+        for changes in watcher {
+            let configuration_changed = changes.iter().any(|path| {
+                is_configuration_file(&session, path)
+                    || watched_configuration_files
+                        .iter()
+                        .any(|file| is_same_path(&session, file, path))
+            });
+
+            if configuration_changed {
+                session.app.console.log(markup! {
+                    <Info>"The configuration changed, processing all the files again."</Info>
+                });
+                let result = run(session.reborrow(), paths.clone());
+                print_result(&mut session, cli_options, result);
+
+                // The watcher is restarted, so the files written by the run aren't reported as
+                // changes. The configuration might extend different files now, they're watched too.
+                watched_configuration_files = configuration_files(&session, cli_options);
+                processed_contents.clear();
+                continue 'watch;
+            } else {
+                let changed_paths: Vec<_> = changes
+                    .into_iter()
+                    .filter(|path| {
+                        can_process(&session, path)
+                            && processed_contents.get(path).copied()
+                                != read_content_hash(&session, path)
+                    })
+                    .collect();
+                if changed_paths.is_empty() {
+                    continue;
+                }
+                let result = run(
+                    session.reborrow(),
+                    changed_paths.iter().map(OsString::from).collect(),
+                );
+                print_result(&mut session, cli_options, result);
+
+                for path in changed_paths {
+                    if let Some(hash) = read_content_hash(&session, &path) {
+                        processed_contents.insert(path, hash);
+                    }
+                }
+            }
+            print_watching(&mut session);
+        }
+
+        // The watcher stopped emitting changes
+        return Ok(());
+    }
+}
+
+fn print_watching(session: &mut CliSession) {
+    session.app.console.log(markup! {
+        <Info>"Watching for file changes..."</Info>
+    });
+}
+
+/// Prints the error returned by a run of the command, watch mode doesn't stop on errors
+fn print_result(
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    result: Result<(), CliDiagnostic>,
+) {
+    if let Err(error) = result {
+        let console = &mut session.app.console;
+        if error.tags().is_verbose() && cli_options.verbose {
+            console.error(markup! {{PrintDiagnostic::verbose(&error)}})
+        } else {
+            console.error(markup! {{PrintDiagnostic::simple(&error)}})
+        }
+    }
+}
+
+/// Returns the paths of the configuration file and of the files it extends
+fn configuration_files(session: &CliSession, cli_options: &CliOptions) -> Vec<PathBuf> {
+    let Ok(loaded_configuration) =
+        load_configuration(&session.app.fs, cli_options.as_configuration_base_path())
+    else {
+        return vec![];
+    };
+    let mut files = vec![];
+    if let Some(directory_path) = loaded_configuration.directory_path.as_deref() {
+        if let Some(extends) = loaded_configuration.configuration.extends.as_ref() {
+            files.extend(extends.iter().map(|path| directory_path.join(path)));
+        }
+    }
+    files.extend(loaded_configuration.file_path);
+    files
+}
+
+fn is_configuration_file(session: &CliSession, path: &Path) -> bool {
+    path.file_name().is_some_and(|file_name| {
+        file_name == session.app.fs.config_name()
+            || file_name == session.app.fs.deprecated_config_name()
+    })
+}
+
+/// Whether two paths point to the same file, once they are resolved against the working directory
+fn is_same_path(session: &CliSession, left: &Path, right: &Path) -> bool {
+    let working_directory = session.app.fs.working_directory().unwrap_or_default();
+    let resolve = |path: &Path| -> PathBuf { working_directory.join(path).components().collect() };
+    resolve(left) == resolve(right)
+}
+
+/// Returns the hash of the content of the file at `path`, or [None] if it can't be read
+fn read_content_hash(session: &CliSession, path: &Path) -> Option<u64> {
+    let mut content = String::new();
+    session
+        .app
+        .fs
+        .open(path)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .ok()?;
+    Some(content_hash(&content))
+}
+
+/// Whether a changed file should be processed: removed files, and files that are ignored or
+/// not supported by Biome, are skipped
+fn can_process(session: &CliSession, path: &Path) -> bool {
+    if !session.app.fs.path_is_file(path) {
+        return false;
+    }
+    session
+        .app
+        .workspace
+        .file_features(SupportsFeatureParams {
+            path: RomePath::new(path),
+            feature: FeaturesBuilder::new()
+                .with_linter()
+                .with_formatter()
+                .with_organize_imports()
+                .build(),
+        })
+        .is_ok_and(|file_features| !file_features.is_not_processed())
+}
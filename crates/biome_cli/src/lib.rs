@@ -27,6 +27,7 @@ use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
+use crate::commands::search::SearchCommandPayload;
 pub use crate::commands::{biome_command, BiomeCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
@@ -66,6 +67,18 @@ impl<'app> CliSession<'app> {
         })
     }
 
+    /// Creates a new session that borrows the file system, the console and the workspace of this one.
+    /// It's used to run a command multiple times, e.g. in watch mode
+    pub(crate) fn reborrow(&mut self) -> CliSession<'_> {
+        CliSession {
+            app: App::new(
+                DynRef::Borrowed(&mut *self.app.fs),
+                &mut *self.app.console,
+                WorkspaceRef::Borrowed(&*self.app.workspace),
+            ),
+        }
+    }
+
     /// Main function to run Biome CLI
     pub fn run(self, command: BiomeCommand) -> Result<(), CliDiagnostic> {
         let has_metrics = command.has_metrics();
@@ -81,6 +94,8 @@ impl<'app> CliSession<'app> {
             BiomeCommand::Check {
                 apply,
                 apply_unsafe,
+                diff,
+                output_patch,
                 cli_options,
                 configuration,
                 paths,
@@ -89,12 +104,19 @@ impl<'app> CliSession<'app> {
                 organize_imports_enabled,
                 formatter_enabled,
                 changed,
+                changed_lines,
                 since,
+                staged,
+                watch,
+                cache,
+                profile,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
                     apply_unsafe,
                     apply,
+                    diff,
+                    output_patch,
                     cli_options,
                     configuration,
                     paths,
@@ -103,33 +125,56 @@ impl<'app> CliSession<'app> {
                     organize_imports_enabled,
                     formatter_enabled,
                     changed,
+                    changed_lines,
                     since,
+                    staged,
+                    watch,
+                    cache,
+                    profile,
                 },
             ),
             BiomeCommand::Lint {
                 apply,
                 apply_unsafe,
+                diff,
+                output_patch,
                 cli_options,
                 linter_configuration,
+                only,
+                skip,
                 paths,
                 stdin_file_path,
                 vcs_configuration,
                 files_configuration,
                 changed,
+                changed_lines,
                 since,
+                staged,
+                watch,
+                cache,
+                profile,
             } => commands::lint::lint(
                 self,
                 LintCommandPayload {
                     apply_unsafe,
                     apply,
+                    diff,
+                    output_patch,
                     cli_options,
                     linter_configuration,
+                    only,
+                    skip,
                     paths,
                     stdin_file_path,
                     vcs_configuration,
                     files_configuration,
                     changed,
+                    changed_lines,
                     since,
+                    staged,
+                    watch,
+                    cache,
+                    profile,
                 },
             ),
             BiomeCommand::Ci {
@@ -140,7 +185,10 @@ impl<'app> CliSession<'app> {
                 paths,
                 cli_options,
                 changed,
+                changed_lines,
                 since,
+                cache,
+                profile,
             } => commands::ci::ci(
                 self,
                 CiCommandPayload {
@@ -151,7 +199,10 @@ impl<'app> CliSession<'app> {
                     paths,
                     cli_options,
                     changed,
+                    changed_lines,
                     since,
+                    cache,
+                    profile,
                 },
             ),
             BiomeCommand::Format {
@@ -159,6 +210,8 @@ impl<'app> CliSession<'app> {
                 formatter_configuration,
                 stdin_file_path,
                 write,
+                diff,
+                output_patch,
                 cli_options,
                 paths,
                 vcs_configuration,
@@ -166,7 +219,12 @@ impl<'app> CliSession<'app> {
                 json_formatter,
                 css_formatter,
                 changed,
+                changed_lines,
                 since,
+                staged,
+                watch,
+                cache,
+                profile,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
@@ -174,6 +232,8 @@ impl<'app> CliSession<'app> {
                     formatter_configuration,
                     stdin_file_path,
                     write,
+                    diff,
+                    output_patch,
                     cli_options,
                     paths,
                     vcs_configuration,
@@ -181,17 +241,40 @@ impl<'app> CliSession<'app> {
                     json_formatter,
                     css_formatter,
                     changed,
+                    changed_lines,
                     since,
+                    staged,
+                    watch,
+                    cache,
+                    profile,
+                },
+            ),
+            BiomeCommand::Search {
+                vcs_configuration,
+                files_configuration,
+                cli_options,
+                pattern,
+                paths,
+            } => commands::search::search(
+                self,
+                SearchCommandPayload {
+                    cli_options,
+                    vcs_configuration,
+                    files_configuration,
+                    pattern,
+                    paths,
                 },
             ),
+            BiomeCommand::Config(sub_command) => commands::config::config(self, sub_command),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
-            BiomeCommand::Init => commands::init::init(self),
+            BiomeCommand::Init { yes } => commands::init::init(self, yes),
             BiomeCommand::LspProxy(config_path) => commands::daemon::lsp_proxy(config_path),
             BiomeCommand::Migrate {
                 cli_options,
                 write,
                 prettier,
-            } => commands::migrate::migrate(self, cli_options, write, prettier),
+                sub_command,
+            } => commands::migrate::migrate(self, cli_options, write, prettier, sub_command),
             BiomeCommand::RunServer {
                 stop_on_disconnect,
                 config_path,
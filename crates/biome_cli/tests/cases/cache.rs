@@ -0,0 +1,72 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn lint_reports_cached_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // the second run reads the diagnostics from the cache
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_cached_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_is_invalidated_when_the_file_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), "statement();\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_is_invalidated_when_the_file_changes",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2,11 +2,15 @@
 //! case that affects many commands
 
 mod biome_json_support;
+mod cache;
 mod config_extends;
 mod diagnostics;
 mod included_files;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
+mod plugins;
+mod profile;
 mod protected_files;
 mod unknown_files;
+mod watch;
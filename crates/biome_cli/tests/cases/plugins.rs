@@ -0,0 +1,262 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const NO_DIRECT_FETCH: &str = r#"{
+    "rules": [
+        {
+            "name": "noDirectFetch",
+            "pattern": "fetch($$$args)",
+            "message": "Use apiClient instead of calling fetch directly.",
+            "rewrite": "apiClient.fetch($$$args)"
+        }
+    ]
+}"#;
+
+#[test]
+fn plugin_reports_the_matched_code() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["plugins/fetch.json"] }"#,
+    );
+    fs.insert(Path::new("plugins/fetch.json").into(), NO_DIRECT_FETCH);
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"fetch("/users", { method: "GET" });
+apiClient.fetch("/users");
+// biome-ignore lint/plugin(noDirectFetch): the client isn't available here
+fetch("/health");
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_reports_the_matched_code",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_applies_the_rewrite() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["plugins/fetch.json"] }"#,
+    );
+    fs.insert(Path::new("plugins/fetch.json").into(), NO_DIRECT_FETCH);
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"const response = await fetch("/users", { method: "GET" });
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply-unsafe"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"const response = await apiClient.fetch("/users", { method: "GET" });
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_applies_the_rewrite",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_checks_the_constraints() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["plugins/console.json"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/console.json").into(),
+        r#"{
+    "rules": [
+        {
+            "name": "noConsoleExceptError",
+            "pattern": "console.$method($$$)",
+            "where": { "method": { "notMatches": "^error$" } },
+            "message": "Only console.error is allowed.",
+            "severity": "warn"
+        }
+    ]
+}"#,
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"console.log("debug");
+console.error("failure");
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_checks_the_constraints",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_with_an_invalid_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["plugins/invalid.json"] }"#,
+    );
+    fs.insert(
+        Path::new("plugins/invalid.json").into(),
+        r#"{
+    "rules": [
+        {
+            "name": "no-direct-fetch",
+            "pattern": "fetch($$$)",
+            "message": "Don't call fetch."
+        },
+        {
+            "name": "noFetchOfUsers",
+            "pattern": "fetch($url)",
+            "where": { "url": { "matches": "(users" } },
+            "message": "Don't fetch the users."
+        }
+    ]
+}"#,
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), r#"fetch("/users");"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_with_an_invalid_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_file_not_found() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["plugins/missing.json"] }"#,
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), r#"fetch("/users");"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_file_not_found",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn plugin_compiled_to_webassembly_is_not_supported() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "plugins": ["plugins/rules.wasm"] }"#,
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), r#"fetch("/users");"#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "plugin_compiled_to_webassembly_is_not_supported",
+        fs,
+        console,
+        result,
+    ));
+}
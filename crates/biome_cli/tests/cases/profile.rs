@@ -0,0 +1,59 @@
+use crate::run_cli;
+use biome_console::{BufferConsole, LogLevel};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn lint_prints_the_profile() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "function f() {\n\treturn;\n\tdebugger;\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--profile"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The timings change at each run, so the output can't be snapshotted
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Log)
+        .map(|message| format!("{:?}", message.content))
+        .collect();
+
+    for expected in [
+        "Slowest files",
+        "file.js",
+        "Time spent per phase",
+        "parse",
+        "semantic model",
+        "control flow graph",
+        "rules",
+        "Time spent per rule",
+        "lint/suspicious/noDebugger",
+        "lint/correctness/noUnreachable",
+    ] {
+        assert!(
+            messages.iter().any(|message| message.contains(expected)),
+            "the profile doesn't contain {expected:?}: {messages:#?}"
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn lint_processes_the_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement();\n".as_bytes());
+    let other_file_path = Path::new("other.js");
+    fs.insert(other_file_path.into(), "statement();\n".as_bytes());
+    fs.set_watch_changes(vec![vec![(
+        PathBuf::from(file_path),
+        "debugger;\n".as_bytes().to_vec(),
+    )]]);
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--watch",
+                file_path.as_os_str().to_str().unwrap(),
+                other_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_processes_the_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_skips_the_files_written_by_the_previous_run() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement();\n".as_bytes());
+    fs.set_watch_changes(vec![
+        vec![(
+            PathBuf::from(file_path),
+            "statement( )\n".as_bytes().to_vec(),
+        )],
+        // The change reported for the file written by the previous run
+        vec![(
+            PathBuf::from(file_path),
+            "statement();\n".as_bytes().to_vec(),
+        )],
+    ]);
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--watch",
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_skips_the_files_written_by_the_previous_run",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_processes_all_files_when_the_configuration_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), "{}".as_bytes());
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "function f() {\n\treturn 1;\n}\n".as_bytes(),
+    );
+    fs.set_watch_changes(vec![vec![(
+        PathBuf::from(config_path),
+        r#"{ "formatter": { "indentStyle": "space" } }"#.as_bytes().to_vec(),
+    )]]);
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--watch",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_processes_all_files_when_the_configuration_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn watch_is_incompatible_with_changed() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement();\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--watch",
+                "--changed",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "watch_is_incompatible_with_changed",
+        fs,
+        console,
+        result,
+    ));
+}
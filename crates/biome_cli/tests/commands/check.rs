@@ -2753,3 +2753,30 @@ fn use_literal_keys_should_emit_correct_ast_issue_266() {
         result,
     ));
 }
+
+#[test]
+fn diff_prints_the_fixes_and_the_formatting_as_a_patch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "let a = 1;\nlet b = a\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--diff", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "let a = 1;\nlet b = a\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "diff_prints_the_fixes_and_the_formatting_as_a_patch",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,117 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn config_print_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_without_configuration_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "file.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_without_configuration_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_shows_the_source_of_each_value() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("base.json").into(),
+        r#"{
+    "formatter": { "indentWidth": 8 },
+    "linter": {
+        "rules": {
+            "recommended": false,
+            "suspicious": { "noDebugger": "warn" }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "extends": ["base.json"],
+    "formatter": { "lineWidth": 100 },
+    "javascript": { "formatter": { "quoteStyle": "single" } },
+    "linter": {
+        "rules": {
+            "style": {
+                "useNamingConvention": {
+                    "level": "error",
+                    "options": { "strictCase": false }
+                }
+            }
+        }
+    },
+    "overrides": [
+        {
+            "include": ["scripts/**"],
+            "javascript": { "formatter": { "semicolons": "asNeeded" } }
+        },
+        {
+            "include": ["src/**"],
+            "formatter": { "indentWidth": 2 },
+            "linter": { "rules": { "suspicious": { "noDebugger": "error" } } }
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("config"), "print", "--quote-style=double", "src/index.ts"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_shows_the_source_of_each_value",
+        fs,
+        console,
+        result,
+    ));
+}
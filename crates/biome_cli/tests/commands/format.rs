@@ -8,7 +8,7 @@ use crate::{
     assert_cli_snapshot, run_cli, CUSTOM_FORMAT_BEFORE, FORMATTED, LINT_ERROR, UNFORMATTED,
 };
 use biome_console::{markup, BufferConsole, MarkupBuf};
-use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_fs::{ChangedLines, FileSystemExt, MemoryFileSystem};
 use biome_service::DynRef;
 use bpaf::Args;
 use std::path::{Path, PathBuf};
@@ -2826,3 +2826,224 @@ fn format_package_json() {
         result,
     ));
 }
+
+#[test]
+fn format_write_staged_files_keeps_unstaged_changes() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    // The working copy contains changes that aren't staged
+    let partially_staged = Path::new("partially_staged.js");
+    fs.insert(
+        partially_staged.into(),
+        "statement( );\nunstaged( );\n".as_bytes(),
+    );
+    fs.insert_staged(partially_staged.into(), "statement( );\n".as_bytes());
+
+    let fully_staged = Path::new("fully_staged.js");
+    fs.insert(fully_staged.into(), "statement( );\n".as_bytes());
+    fs.insert_staged(fully_staged.into(), "statement( );\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--write", "--staged"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_eq!(
+        fs.staged_content(partially_staged).as_deref(),
+        Some("statement();\n".as_bytes())
+    );
+    assert_eq!(
+        fs.staged_content(fully_staged).as_deref(),
+        Some("statement();\n".as_bytes())
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_write_staged_files_keeps_unstaged_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_write_only_formats_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_changed_lines(vec![ChangedLines {
+        path: String::from("file.js"),
+        lines: vec![1..2, 3..4],
+    }]);
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "statement( );\nchanged( );\nstatement( );\nchanged( );\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--write", "--changed-lines", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "statement( );\nchanged();\nstatement( );\nchanged();\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_write_only_formats_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_diff_prints_a_patch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--diff",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_diff_prints_a_patch",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_output_patch_writes_the_patch_to_a_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("src/format.js");
+    fs.insert(file_path.into(), CUSTOM_FORMAT_BEFORE.as_bytes());
+    let formatted_path = Path::new("src/formatted.js");
+    fs.insert(formatted_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--output-patch=changes.patch", "./src"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, CUSTOM_FORMAT_BEFORE);
+    assert_file_contents(
+        &fs,
+        Path::new("changes.patch"),
+        "--- a/src/format.js\n+++ b/src/format.js\n@@ -1,4 +1,3 @@\n-\n function f() {\n-return { something }\n+\treturn { something };\n }\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_output_patch_writes_the_patch_to_a_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_diff_prints_paths_relative_to_the_working_directory() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let working_directory = Path::new("/workspace");
+    fs.set_working_directory(working_directory);
+    let file_path = working_directory.join("src/format.js");
+    fs.insert(file_path.clone(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--diff",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, &file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_diff_prints_paths_relative_to_the_working_directory",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_diff_and_write_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--diff",
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_diff_and_write_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -99,3 +99,132 @@ fn creates_config_file_when_biome_installed_via_package_manager() {
         result,
     ));
 }
+
+#[test]
+fn applies_the_project_settings_with_yes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "app",
+    "dependencies": { "react": "^18.2.0" },
+    "devDependencies": { "typescript": "^5.3.0" }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new(".editorconfig").into(),
+        "root = true\n\n[*]\nindent_style = space\nindent_size = 4\nend_of_line = lf\n\n[*.md]\nindent_size = 2\n"
+            .as_bytes(),
+    );
+    fs.insert(
+        Path::new(".prettierrc").into(),
+        r#"{ "singleQuote": true, "printWidth": 100 }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new(".git/HEAD").into(),
+        "ref: refs/heads/main\n".as_bytes(),
+    );
+    fs.insert(Path::new("dist/index.js").into(), "".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), "--yes"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_the_project_settings_with_yes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn asks_before_applying_the_project_settings() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new(".git/HEAD").into(),
+        "ref: refs/heads/main\n".as_bytes(),
+    );
+    fs.insert(Path::new("build/index.js").into(), "".as_bytes());
+    // The VCS integration is declined, the build output folder is ignored
+    console.in_buffer.push("n".to_string());
+    console.in_buffer.push(String::new());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "asks_before_applying_the_project_settings",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_ask_when_the_console_is_not_interactive() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new(".git/HEAD").into(),
+        "ref: refs/heads/main\n".as_bytes(),
+    );
+    fs.insert(Path::new("build/index.js").into(), "".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_ask_when_the_console_is_not_interactive",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_ask_when_the_configuration_exists() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), "{}".as_bytes());
+    fs.insert(
+        Path::new(".git/HEAD").into(),
+        "ref: refs/heads/main\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init")].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_ask_when_the_configuration_exists",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -17,7 +17,7 @@ use crate::configs::{
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
-use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
+use biome_fs::{ChangedLines, ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
 use biome_service::DynRef;
 
 const ERRORS: &str = r#"
@@ -2639,3 +2639,275 @@ fn should_not_process_ignored_file_even_if_its_changed() {
         result,
     ));
 }
+
+#[test]
+fn should_only_process_staged_files_when_staged_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), r#"console.log('file');"#.as_bytes());
+    fs.insert_staged(file_path.into(), r#"debugger;"#.as_bytes());
+
+    let file_path2 = Path::new("file2.js");
+    fs.insert(file_path2.into(), r#"debugger;"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--staged"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_process_staged_files_when_staged_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_error_if_staged_and_changed_flags_are_used_together() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), r#"console.log('file');"#.as_bytes());
+    fs.insert_staged(file_path.into(), r#"console.log('file');"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--staged", "--changed", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_staged_and_changed_flags_are_used_together",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_only_report_diagnostics_of_changed_lines_when_changed_lines_flag_is_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_changed_lines(vec![ChangedLines {
+        path: String::from("file.js"),
+        lines: vec![1..2, 2..3],
+    }]);
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nstatement();\ndebugger;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--changed-lines", "--since=main"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_only_report_diagnostics_of_changed_lines_when_changed_lines_flag_is_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn only_runs_the_selected_rules_even_when_they_are_turned_off() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "suspicious": { "noDebugger": "off" }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nvar a = 1;\na == 2;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--only=suspicious/noDebugger",
+                "--only=style",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "only_runs_the_selected_rules_even_when_they_are_turned_off",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn skip_does_not_run_the_selected_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nvar a = 1;\na == 2;\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--skip=suspicious/noDebugger",
+                "--skip=style",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "skip_does_not_run_the_selected_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn only_applies_the_fixes_of_the_selected_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\nvar a = 1;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--apply-unsafe",
+                "--only=suspicious/noDebugger",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(buffer, "\nvar a = 1;\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "only_applies_the_fixes_of_the_selected_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn only_with_an_unknown_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--only=suspicious/noUnknownRule",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "only_with_an_unknown_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn diff_prints_the_safe_fixes_as_a_patch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "let a = 1;\nlet b = a;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--diff", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "let a = 1;\nlet b = a;\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "diff_prints_the_safe_fixes_as_a_patch",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -253,7 +253,7 @@ fn prettier_migrate_yml_file() {
         Args::from([("migrate"), "--prettier"].as_slice()),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -336,3 +336,396 @@ generated/*.spec.js
         result,
     ));
 }
+
+#[test]
+fn prettier_migrate_toml_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"
+useTabs = true
+printWidth = 120
+trailingComma = "es5"
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc.toml");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_toml_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let package_json = r#"{
+    "name": "project",
+    "prettier": { "semi": true, "bracketSpacing": false }
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let package_json_path = Path::new("package.json");
+    fs.insert(package_json_path.into(), package_json.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_package_json_without_prettier_key() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let package_json = r#"{ "name": "project" }"#;
+    let prettier = r#"{ "useTabs": true }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let package_json_path = Path::new("package.json");
+    fs.insert(package_json_path.into(), package_json.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc.json");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_package_json_without_prettier_key",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prettier_migrate_write_with_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let prettier = r#"
+semi: true
+overrides:
+  - files: "*.test.js"
+    options:
+      semi: false
+  - files:
+      - "legacy/**/*.js"
+      - "scripts/*.js"
+    excludeFiles: "legacy/vendor/**"
+    options:
+      tabWidth: 4
+      printWidth: 100
+      singleQuote: false
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let prettier_path = Path::new(".prettierrc.yaml");
+    fs.insert(prettier_path.into(), prettier.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--prettier", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prettier_migrate_write_with_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let eslint = r#"{
+    // ESLint configuration files accept comments
+    "extends": ["eslint:recommended"],
+    "rules": {
+        "no-debugger": "off",
+        "eqeqeq": 2,
+        "no-restricted-globals": ["warn", "event", { "name": "fdescribe", "message": "Don't focus tests" }],
+        "sonarjs/cognitive-complexity": ["error", 20],
+        "@typescript-eslint/array-type": ["error", { "default": "generic" }],
+        "no-extra-semi": "error",
+        "eslint-plugin-unknown/rule": 1,
+    }
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let eslint_path = Path::new(".eslintrc.json");
+    fs.insert(eslint_path.into(), eslint.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "eslint"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate_write_with_overrides_and_ignore_patterns() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{
+    "overrides": [{ "include": ["scripts/**"], "linter": { "enabled": false } }]
+}"#;
+    let eslint = r#"{
+    "ignorePatterns": ["dist/**"],
+    "rules": { "no-console": "error" },
+    "overrides": [
+        {
+            "files": "*.test.js",
+            "excludedFiles": ["fixtures/**"],
+            "rules": { "no-console": "off", "jest/no-focused-tests": ["error"] }
+        }
+    ]
+}"#;
+    let eslint_ignore = r#"
+# Generated files
+generated/**
+"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let eslint_path = Path::new(".eslintrc");
+    fs.insert(eslint_path.into(), eslint.as_bytes());
+
+    let eslint_ignore_path = Path::new(".eslintignore");
+    fs.insert(eslint_ignore_path.into(), eslint_ignore.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "eslint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        configuration_path,
+        r#"{
+	"linter": {
+		"rules": { "suspicious": { "noConsoleLog": "error" } },
+		"ignore": ["dist/**", "generated/**"]
+	},
+	"overrides": [
+		{ "include": ["scripts/**"], "linter": { "enabled": false } },
+		{
+			"ignore": ["fixtures/**"],
+			"include": ["*.test.js"],
+			"linter": {
+				"rules": {
+					"nursery": { "noFocusedTests": "error" },
+					"suspicious": { "noConsoleLog": "off" }
+				}
+			}
+		}
+	]
+}
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate_write_with_overrides_and_ignore_patterns",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_migrate_no_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "eslint"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_migrate_no_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_comments_migrate() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"// eslint-disable-next-line no-debugger, unknown-rule -- debugging the tests
+debugger;
+if (a == b) {} // eslint-disable-line eqeqeq
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("migrate"),
+                "eslint-comments",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_comments_migrate",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn eslint_comments_migrate_write_disabled_range() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"/* eslint-disable no-debugger, eqeqeq */
+function f() {
+    debugger;
+    if (a == b) {}
+}
+/* eslint-enable no-debugger */
+if (a == b) {}
+debugger;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("migrate"),
+                "--write",
+                "eslint-comments",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"function f() {
+    // biome-ignore lint/suspicious/noDebugger: <explanation>
+    debugger;
+    // biome-ignore lint/suspicious/noDoubleEquals: <explanation>
+    if (a == b) {}
+}
+// biome-ignore lint/suspicious/noDoubleEquals: <explanation>
+if (a == b) {}
+debugger;
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "eslint_comments_migrate_write_disabled_range",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,5 +1,6 @@
 mod check;
 mod ci;
+mod config;
 mod explain;
 mod format;
 mod init;
@@ -7,4 +8,5 @@ mod lint;
 mod lsp_proxy;
 mod migrate;
 mod rage;
+mod search;
 mod version;
@@ -0,0 +1,127 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn search_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_javascript_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"console.log("a");
+console
+    // a comment
+    .error("b", c);
+logger.log("a");
+"#
+        .as_bytes(),
+    );
+    let jsx_file_path = Path::new("file.jsx");
+    fs.insert(
+        jsx_file_path.into(),
+        r#"function App() {
+    useEffect(() => console.info(props), []);
+    return <div />;
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                "console.$method($$$args)",
+                file_path.as_os_str().to_str().unwrap(),
+                jsx_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_javascript_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_json_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("package.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+    "name": "project",
+    "scripts": { "build": "tsc", "test": "vitest" }
+}
+"#
+        .as_bytes(),
+    );
+    let js_file_path = Path::new("file.js");
+    fs.insert(
+        js_file_path.into(),
+        r#"const a = { "build": "tsc" };"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                r#"{ "build": "$build", "test": "$test" }"#,
+                file_path.as_os_str().to_str().unwrap(),
+                js_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_json_files",
+        fs,
+        console,
+        result,
+    ));
+}
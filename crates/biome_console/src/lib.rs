@@ -1,5 +1,5 @@
 use std::io;
-use std::io::{stderr, stdin, stdout, IsTerminal, Read, Stdin, Write};
+use std::io::{stderr, stdin, stdout, BufRead, IsTerminal, Read, Stdin, Write};
 use std::panic::RefUnwindSafe;
 use termcolor::{ColorChoice, StandardStream};
 use write::Termcolor;
@@ -36,6 +36,13 @@ pub trait Console: Send + Sync + RefUnwindSafe {
 
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// It reads a line from a source, e.g. the answer to a prompt, without its line terminator.
+    /// It returns [None] if the source isn't interactive or doesn't have any line left.
+    fn read_line(&mut self) -> Option<String>;
+
+    /// Whether the source of [Console::read_line] is interactive, so prompts can be answered
+    fn is_interactive(&self) -> bool;
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -181,6 +188,24 @@ impl Console for EnvConsole {
             None
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // Reading a redirected stdin would block scripts that keep it open
+        if !self.is_interactive() {
+            return None;
+        }
+        // The prompt is printed without a new line, so it needs to be flushed
+        self.out.flush().ok()?;
+        let mut buffer = String::new();
+        match self.r#in.lock().read_line(&mut buffer) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(buffer.trim_end_matches(['\n', '\r']).to_string()),
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        stdin().is_terminal()
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
@@ -220,6 +245,20 @@ impl Console for BufferConsole {
             Some(self.in_buffer[0].clone())
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // Each message is a line, they're consumed in order
+        if self.in_buffer.is_empty() {
+            None
+        } else {
+            Some(self.in_buffer.remove(0))
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        // The answers to the prompts are in the buffer
+        !self.in_buffer.is_empty()
+    }
 }
 
 /// A horizontal line with the given print width
@@ -222,6 +222,7 @@ define_categories! {
     "configuration",
     "organizeImports",
     "migrate",
+    "search",
     "deserialize",
     "project",
     "internalError/io",
@@ -240,6 +241,7 @@ define_categories! {
     "lint/correctness",
     "lint/nursery",
     "lint/performance",
+    "lint/plugin",
     "lint/security",
     "lint/style",
     "lint/suspicious",
@@ -5,6 +5,7 @@ pub use memory::{ErrorEntry, MemoryFileSystem};
 pub use os::OsFileSystem;
 use serde::{Deserialize, Serialize};
 use std::io;
+use std::ops::Range;
 use std::panic::RefUnwindSafe;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -46,6 +47,11 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path is a regular file
     fn path_is_file(&self, path: &Path) -> bool;
 
+    /// Creates the directory at `path`, and its parents that don't exist
+    ///
+    /// Equivalent to [std::fs::create_dir_all]
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
     /// Method that takes a path to a folder `file_path`, and a `file_name`. It attempts to find
     /// and read the file from that folder and if not found, it reads the parent directories recursively
     /// until:
@@ -137,6 +143,58 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     }
 
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
+
+    /// Returns the lines of the files in the working tree that changed since the commit where the
+    /// current branch forked from `base`, including the uncommitted changes
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedLines>>;
+
+    /// Returns the paths of the files that are staged in the git index, relative to the working directory
+    fn get_staged_files(&self) -> io::Result<Vec<String>>;
+
+    /// Opens the content of the file at `path` that is staged in the git index.
+    ///
+    /// Writing to the returned file updates the index. The working copy is updated too, but only
+    /// when it doesn't contain unstaged changes.
+    fn open_staged(&self, path: &Path) -> io::Result<Box<dyn File>>;
+
+    /// Starts watching the given paths for changes. Directories are watched recursively.
+    ///
+    /// The returned [FileWatcher] yields the paths of the files that were created, modified
+    /// or removed since the watch started.
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<FileWatcher>;
+}
+
+/// An iterator over the changes of the file system, returned by [FileSystem::watch]
+///
+/// Each item is a batch of paths of the files that changed. The iterator blocks until some files
+/// change, and it ends when the file system can't report any further change.
+pub struct FileWatcher {
+    next_changes: Box<dyn FnMut() -> Option<Vec<PathBuf>> + Send>,
+}
+
+impl FileWatcher {
+    pub fn new(next_changes: impl FnMut() -> Option<Vec<PathBuf>> + Send + 'static) -> Self {
+        Self {
+            next_changes: Box::new(next_changes),
+        }
+    }
+}
+
+impl Iterator for FileWatcher {
+    type Item = Vec<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.next_changes)()
+    }
+}
+
+/// The lines of a file that changed, returned by [FileSystem::get_changed_lines]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChangedLines {
+    /// The path of the file
+    pub path: String,
+    /// The ranges of the lines that changed, zero-based and sorted. The end of a range is exclusive.
+    pub lines: Vec<Range<u32>>,
 }
 
 /// Result of the auto search
@@ -301,9 +359,29 @@ where
         T::path_is_file(self, path)
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        T::create_dir_all(self, path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         T::get_changed_files(self, base)
     }
+
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedLines>> {
+        T::get_changed_lines(self, base)
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        T::get_staged_files(self)
+    }
+
+    fn open_staged(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        T::open_staged(self, path)
+    }
+
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<FileWatcher> {
+        T::watch(self, paths)
+    }
 }
 
 #[derive(Debug, Diagnostic, Deserialize, Serialize)]
@@ -1,6 +1,7 @@
 use rustc_hash::FxHashMap;
 use std::collections::hash_map::{Entry, IntoIter};
 use std::io;
+use std::mem;
 use std::panic::{AssertUnwindSafe, RefUnwindSafe};
 use std::path::{Path, PathBuf};
 use std::str;
@@ -10,7 +11,7 @@ use biome_diagnostics::{Error, Severity};
 use parking_lot::{lock_api::ArcMutexGuard, Mutex, RawMutex, RwLock};
 
 use crate::fs::OpenOptions;
-use crate::{FileSystem, RomePath, TraversalContext, TraversalScope};
+use crate::{ChangedLines, FileSystem, FileWatcher, RomePath, TraversalContext, TraversalScope};
 
 use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
 
@@ -28,8 +29,18 @@ pub struct MemoryFileSystem {
     errors: FxHashMap<PathBuf, ErrorEntry>,
     allow_write: bool,
     on_get_changed_files: OnGetChangedFiles,
+    watch_changes: AssertUnwindSafe<Mutex<Vec<WatchChanges>>>,
+    /// The lines returned by [FileSystem::get_changed_lines]
+    changed_lines: Vec<ChangedLines>,
+    /// The files staged in the git index, mapped by path
+    staged_files: AssertUnwindSafe<FxHashMap<PathBuf, FileEntry>>,
+    /// The directory returned by [FileSystem::working_directory]
+    working_directory: Option<PathBuf>,
 }
 
+/// A batch of changes reported by the watcher of a [MemoryFileSystem], with the new content of the files
+type WatchChanges = Vec<(PathBuf, Vec<u8>)>;
+
 impl Default for MemoryFileSystem {
     fn default() -> Self {
         Self {
@@ -37,6 +48,10 @@ impl Default for MemoryFileSystem {
             errors: Default::default(),
             allow_write: true,
             on_get_changed_files: None,
+            watch_changes: Default::default(),
+            changed_lines: Default::default(),
+            staged_files: Default::default(),
+            working_directory: None,
         }
     }
 }
@@ -86,6 +101,19 @@ impl MemoryFileSystem {
         files.insert(path, Arc::new(Mutex::new(content.into())));
     }
 
+    /// Create or update a file in the git index of the filesystem
+    pub fn insert_staged(&mut self, path: PathBuf, content: impl Into<Vec<u8>>) {
+        self.staged_files
+            .insert(path, Arc::new(Mutex::new(content.into())));
+    }
+
+    /// Returns the content of a file in the git index of the filesystem
+    pub fn staged_content(&self, path: &Path) -> Option<Vec<u8>> {
+        self.staged_files
+            .get(path)
+            .map(|entry| entry.lock().clone())
+    }
+
     /// Create or update an error in the filesystem
     pub fn insert_error(&mut self, path: PathBuf, kind: ErrorEntry) {
         self.errors.insert(path, kind);
@@ -107,6 +135,24 @@ impl MemoryFileSystem {
     ) {
         self.on_get_changed_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
+
+    /// Sets the directory returned by [FileSystem::working_directory]
+    pub fn set_working_directory(&mut self, working_directory: impl Into<PathBuf>) {
+        self.working_directory = Some(working_directory.into());
+    }
+
+    /// Sets the lines returned by [FileSystem::get_changed_lines]
+    pub fn set_changed_lines(&mut self, changed_lines: Vec<ChangedLines>) {
+        self.changed_lines = changed_lines;
+    }
+
+    /// Sets the changes reported by the watcher returned by [FileSystem::watch], one batch at
+    /// a time. The content of the files is updated when their batch is reported.
+    ///
+    /// Only files that exist in the file system can be changed.
+    pub fn set_watch_changes(&mut self, changes: Vec<WatchChanges>) {
+        *self.watch_changes.0.get_mut() = changes;
+    }
 }
 
 impl FileSystem for MemoryFileSystem {
@@ -176,11 +222,13 @@ impl FileSystem for MemoryFileSystem {
     }
 
     fn working_directory(&self) -> Option<PathBuf> {
-        None
+        self.working_directory.clone()
     }
 
     fn path_exists(&self, path: &Path) -> bool {
-        self.path_is_file(path)
+        // Directories aren't stored, a directory exists if it contains a file
+        let files = self.files.0.read();
+        files.keys().any(|file_path| file_path.starts_with(path))
     }
 
     fn path_is_file(&self, path: &Path) -> bool {
@@ -188,6 +236,17 @@ impl FileSystem for MemoryFileSystem {
         files.get(path).is_some()
     }
 
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        // Directories aren't stored, the files can be created in any directory
+        if !self.allow_write {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "cannot create a directory in read-only filesystem",
+            ));
+        }
+        Ok(())
+    }
+
     fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_changed_files.as_ref().unwrap().clone();
 
@@ -197,6 +256,108 @@ impl FileSystem for MemoryFileSystem {
 
         Ok(cb())
     }
+
+    fn get_changed_lines(&self, _base: &str) -> io::Result<Vec<ChangedLines>> {
+        Ok(self.changed_lines.clone())
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        let mut staged_files: Vec<_> = self
+            .staged_files
+            .keys()
+            .map(|path| path.display().to_string())
+            .collect();
+        staged_files.sort();
+        Ok(staged_files)
+    }
+
+    fn open_staged(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        let entry = self.staged_files.get(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("path {path:?} is not in the git index of the memory filesystem"),
+            )
+        })?;
+        let index = entry.lock_arc();
+
+        // The working copy is updated only when it doesn't have unstaged changes
+        let working_copy = self
+            .files
+            .0
+            .read()
+            .get(path)
+            .filter(|working_copy| *working_copy.lock() == *index)
+            .map(|working_copy| working_copy.lock_arc());
+
+        Ok(Box::new(MemoryStagedFile {
+            index,
+            working_copy,
+            version: 0,
+        }))
+    }
+
+    fn watch(&self, _paths: &[PathBuf]) -> io::Result<FileWatcher> {
+        let files = self.files.0.read();
+        let mut batches = vec![];
+        for changes in mem::take(&mut *self.watch_changes.0.lock()) {
+            let changes = changes
+                .into_iter()
+                .map(|(path, content)| {
+                    let entry = files.get(&path).cloned().ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::NotFound,
+                            format!("path {path:?} does not exists in memory filesystem"),
+                        )
+                    })?;
+                    Ok((path, entry, content))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+            batches.push(changes);
+        }
+
+        let mut batches = batches.into_iter();
+        Ok(FileWatcher::new(move || {
+            let changes = batches.next()?;
+            Some(
+                changes
+                    .into_iter()
+                    .map(|(path, entry, content)| {
+                        *entry.lock() = content;
+                        path
+                    })
+                    .collect(),
+            )
+        }))
+    }
+}
+
+/// A file staged in the git index of a [MemoryFileSystem]
+struct MemoryStagedFile {
+    index: ArcMutexGuard<RawMutex, Vec<u8>>,
+    working_copy: Option<ArcMutexGuard<RawMutex, Vec<u8>>>,
+    version: i32,
+}
+
+impl File for MemoryStagedFile {
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()> {
+        let content = str::from_utf8(&self.index)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        buffer.push_str(content);
+        Ok(())
+    }
+
+    fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
+        *self.index = content.to_vec();
+        if let Some(working_copy) = self.working_copy.as_mut() {
+            **working_copy = content.to_vec();
+        }
+        self.version += 1;
+        Ok(())
+    }
+
+    fn file_version(&self) -> i32 {
+        self.version
+    }
 }
 
 struct MemoryFile {
@@ -341,6 +502,15 @@ mod tests {
                 assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
             }
         }
+
+        match fs.create_dir_all(Path::new("directory")) {
+            Ok(_) => {
+                panic!("fs.create_dir_all() for a read-only filesystem should return an error")
+            }
+            Err(error) => {
+                assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+            }
+        }
     }
 
     #[test]
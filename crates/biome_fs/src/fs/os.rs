@@ -3,18 +3,22 @@ use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
 use crate::fs::OpenOptions;
 use crate::{
     fs::{TraversalContext, TraversalScope},
-    FileSystem, RomePath,
+    ChangedLines, FileSystem, FileWatcher, RomePath,
 };
 use biome_diagnostics::{adapters::IoError, DiagnosticExt, Error, Severity};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use rayon::{scope, Scope};
 use std::ffi::OsStr;
 use std::fs::{DirEntry, FileType};
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::{
     env, fs,
     io::{self, ErrorKind as IoErrorKind, Read, Seek, Write},
     mem,
     path::{Path, PathBuf},
+    str,
+    sync::mpsc,
+    time::Duration,
 };
 
 const MAX_SYMLINK_DEPTH: u8 = 3;
@@ -58,6 +62,10 @@ impl FileSystem for OsFileSystem {
         path.is_file()
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         let output = Command::new("git")
             .arg("diff")
@@ -76,6 +84,199 @@ impl FileSystem for OsFileSystem {
             .map(|l| l.to_string())
             .collect())
     }
+
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedLines>> {
+        // The files are linted and formatted as they are in the working tree, so the line numbers
+        // come from the diff between the working tree and the commit where `HEAD` forked from `base`
+        let merge_base = run_git(&["merge-base", base, "HEAD"], None, self.0.as_deref())?;
+        let merge_base = String::from_utf8_lossy(&merge_base);
+        let output = run_git(
+            &[
+                // Only the paths with special characters are quoted, e.g. not the non-ASCII ones
+                "-c",
+                "core.quotePath=false",
+                "diff",
+                "--unified=0",
+                "--no-color",
+                "--no-ext-diff",
+                "--relative",
+                // The prefixes can be changed by the configuration of the user
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                "--diff-filter=ACMR",
+                merge_base.trim(),
+            ],
+            None,
+            self.0.as_deref(),
+        )?;
+
+        Ok(parse_changed_lines(&String::from_utf8_lossy(&output)))
+    }
+
+    fn get_staged_files(&self) -> io::Result<Vec<String>> {
+        let output = run_git(
+            &[
+                "diff",
+                "--cached",
+                "--name-only",
+                "--relative",
+                "--diff-filter=ACMR",
+                // Separates the paths with NUL characters, and doesn't quote them
+                "-z",
+            ],
+            None,
+            self.0.as_deref(),
+        )?;
+
+        Ok(String::from_utf8_lossy(&output)
+            .split_terminator('\0')
+            .map(|l| l.to_string())
+            .collect())
+    }
+
+    fn open_staged(&self, path: &Path) -> io::Result<Box<dyn File>> {
+        tracing::debug_span!("OsFileSystem::open_staged", path = ?path).in_scope(
+            move || -> io::Result<Box<dyn File>> {
+                let working_directory = self.0.as_deref();
+                let path =
+                    working_directory.map_or_else(|| path.to_path_buf(), |dir| dir.join(path));
+                // The output has the format `<mode> <object> <stage>\t<path>\0`, where the path
+                // is relative to the root of the repository. The path isn't a pattern, even when
+                // it contains characters like `*` or `[`.
+                let pathspec = format!(":(literal){}", path.to_string_lossy());
+                let output = run_git(
+                    &["ls-files", "--stage", "--full-name", "-z", "--", &pathspec],
+                    None,
+                    working_directory,
+                )?;
+                let output = String::from_utf8_lossy(&output);
+                let entry = output.split_terminator('\0').next().unwrap_or_default();
+                let (stage, index_path) = entry.split_once('\t').unwrap_or_default();
+                let mut fields = stage.split_whitespace();
+                let (Some(mode), Some(object)) = (fields.next(), fields.next()) else {
+                    return Err(io::Error::new(
+                        IoErrorKind::NotFound,
+                        format!("path {path:?} is not in the git index"),
+                    ));
+                };
+
+                let content = run_git(&["cat-file", "blob", object], None, working_directory)?;
+                let has_unstaged_changes =
+                    fs::read(&path).map_or(true, |working_copy| working_copy != content);
+
+                Ok(Box::new(OsStagedFile {
+                    path,
+                    index_path: index_path.to_string(),
+                    working_directory: working_directory.map(Path::to_path_buf),
+                    mode: mode.to_string(),
+                    content,
+                    has_unstaged_changes,
+                    version: 0,
+                }))
+            },
+        )
+    }
+
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<FileWatcher> {
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(notify_error)?;
+        let current_directory = env::current_dir()?;
+        let mut roots = Vec::with_capacity(paths.len());
+        for path in paths {
+            let absolute_path = current_directory.join(path);
+            if absolute_path.is_dir() {
+                watch_directory(&mut watcher, &absolute_path);
+            } else if let Some(parent) = absolute_path.parent() {
+                // The parent directory is watched, so the file is still watched after an editor
+                // replaced it with a new file
+                watcher
+                    .watch(parent, RecursiveMode::NonRecursive)
+                    .map_err(notify_error)?;
+            }
+            roots.push((path.clone(), absolute_path));
+        }
+
+        Ok(FileWatcher::new(move || loop {
+            let mut events = vec![receiver.recv().ok()?];
+            // A file is often written in several steps, the events that follow closely are
+            // reported in the same batch
+            while let Ok(event) = receiver.recv_timeout(WATCH_BATCH_DELAY) {
+                events.push(event);
+            }
+
+            let mut changes = vec![];
+            for event in events.into_iter().flatten() {
+                if matches!(event.kind, EventKind::Access(_)) {
+                    continue;
+                }
+                for path in event.paths {
+                    if matches!(event.kind, EventKind::Create(_)) && path.is_dir() {
+                        watch_directory(&mut watcher, &path);
+                    }
+                    if let Some(path) = to_watched_path(&roots, &path) {
+                        changes.push(path);
+                    }
+                }
+            }
+            changes.sort();
+            changes.dedup();
+            if !changes.is_empty() {
+                return Some(changes);
+            }
+        }))
+    }
+}
+
+/// The delay after an event during which the following events are reported in the same batch
+const WATCH_BATCH_DELAY: Duration = Duration::from_millis(50);
+
+/// Watches `path` and its subdirectories, except the directories ignored by default.
+///
+/// Each directory is watched on its own instead of recursively, so the directories such as
+/// `node_modules` don't use any of the watches the OS allows. Directories behind symbolic links
+/// aren't watched, this avoids to loop over cycles.
+fn watch_directory(watcher: &mut RecommendedWatcher, path: &Path) {
+    if is_ignored_by_default(path) || watcher.watch(path, RecursiveMode::NonRecursive).is_err() {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            watch_directory(watcher, &entry.path());
+        }
+    }
+}
+
+/// Maps a path reported by the OS to the watched path it belongs to, so the changes are reported
+/// relative to the paths that were passed to [FileSystem::watch]. Returns [None] if the path isn't
+/// watched or if it's ignored by default.
+fn to_watched_path(roots: &[(PathBuf, PathBuf)], path: &Path) -> Option<PathBuf> {
+    roots.iter().find_map(|(root, absolute_root)| {
+        let relative_path = path.strip_prefix(absolute_root).ok()?;
+        if relative_path.as_os_str().is_empty() {
+            return Some(root.clone());
+        }
+        if !absolute_root.is_dir() || relative_path.ancestors().any(is_ignored_by_default) {
+            return None;
+        }
+        Some(root.join(relative_path))
+    })
+}
+
+/// Whether the file name of `path` is one of the [DEFAULT_IGNORE] names
+fn is_ignored_by_default(path: &Path) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .is_some_and(|file_name| DEFAULT_IGNORE.contains(&file_name))
+}
+
+fn notify_error(error: notify::Error) -> io::Error {
+    match error.kind {
+        notify::ErrorKind::Io(error) => error,
+        _ => io::Error::new(io::ErrorKind::Other, error.to_string()),
+    }
 }
 
 struct OsFile {
@@ -113,6 +314,175 @@ impl File for OsFile {
     }
 }
 
+/// Runs a git command in `current_dir` and returns its output, `input` is written to the standard
+/// input of the command
+fn run_git(args: &[&str], input: Option<&[u8]>, current_dir: Option<&Path>) -> io::Result<Vec<u8>> {
+    let mut command = Command::new("git");
+    if let Some(current_dir) = current_dir {
+        command.current_dir(current_dir);
+    }
+    let mut child = command
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(input) = input {
+        child
+            .stdin
+            .take()
+            .expect("the standard input is piped")
+            .write_all(input)?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::new(
+            IoErrorKind::Other,
+            format!(
+                "the command `git {}` failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+    Ok(output.stdout)
+}
+
+/// Extracts the changed lines of each file from the output of `git diff --unified=0`
+fn parse_changed_lines(diff: &str) -> Vec<ChangedLines> {
+    let mut changed_lines: Vec<ChangedLines> = vec![];
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            if let Some(path) = unquote_path(path).strip_prefix("b/") {
+                changed_lines.push(ChangedLines {
+                    path: path.to_string(),
+                    lines: vec![],
+                });
+            }
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            // The header of a hunk has the format `@@ -<start>[,<count>] +<start>[,<count>] @@`
+            let Some(new_lines) = hunk
+                .split_whitespace()
+                .find_map(|range| range.strip_prefix('+'))
+            else {
+                continue;
+            };
+            let (start, count) = new_lines.split_once(',').unwrap_or((new_lines, "1"));
+            let (Ok(start), Ok(count)) = (start.parse::<u32>(), count.parse::<u32>()) else {
+                continue;
+            };
+            // Hunks that only remove lines don't change any line of the new file
+            if count == 0 {
+                continue;
+            }
+            if let Some(file) = changed_lines.last_mut() {
+                // Line numbers in the diff are one-based
+                file.lines.push(start - 1..start - 1 + count);
+            }
+        }
+    }
+    changed_lines
+}
+
+/// Returns the path of a header of `git diff`. The paths with special characters are quoted and
+/// escaped like C strings, and the paths with spaces end with a tab.
+fn unquote_path(path: &str) -> String {
+    let Some(quoted) = path
+        .strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+    else {
+        return path.strip_suffix('\t').unwrap_or(path).to_string();
+    };
+
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('a') => bytes.push(0x07),
+            Some('b') => bytes.push(0x08),
+            Some('f') => bytes.push(0x0c),
+            Some('n') => bytes.push(b'\n'),
+            Some('r') => bytes.push(b'\r'),
+            Some('t') => bytes.push(b'\t'),
+            Some('v') => bytes.push(0x0b),
+            // The bytes that aren't printable are escaped with three octal digits
+            Some(digit @ '0'..='7') => {
+                let octal: String = std::iter::once(digit)
+                    .chain(chars.by_ref().take(2))
+                    .collect();
+                bytes.extend(u8::from_str_radix(&octal, 8).ok());
+            }
+            Some(c) => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+            None => {}
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// A file staged in the git index, see [FileSystem::open_staged]
+struct OsStagedFile {
+    path: PathBuf,
+    /// The path of the file relative to the root of the repository, as expected by the index
+    index_path: String,
+    /// The directory where the git commands run
+    working_directory: Option<PathBuf>,
+    /// The mode of the file in the index, e.g. `100644`
+    mode: String,
+    content: Vec<u8>,
+    /// Whether the working copy of the file differs from the content in the index
+    has_unstaged_changes: bool,
+    version: i32,
+}
+
+impl File for OsStagedFile {
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()> {
+        let content = str::from_utf8(&self.content)
+            .map_err(|err| io::Error::new(IoErrorKind::InvalidData, err))?;
+        buffer.push_str(content);
+        Ok(())
+    }
+
+    fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
+        tracing::debug_span!("OsStagedFile::set_content").in_scope(move || {
+            let working_directory = self.working_directory.as_deref();
+            let object = run_git(
+                &["hash-object", "-w", "--stdin"],
+                Some(content),
+                working_directory,
+            )?;
+            let object = String::from_utf8_lossy(&object);
+            let cache_info = format!("{},{},{}", self.mode, object.trim(), self.index_path);
+            run_git(
+                &["update-index", "--cacheinfo", &cache_info],
+                None,
+                working_directory,
+            )?;
+
+            // Updating a working copy with unstaged changes would lose them
+            if !self.has_unstaged_changes {
+                fs::write(&self.path, content)?;
+            }
+
+            self.content = content.to_vec();
+            self.version += 1;
+            Ok(())
+        })
+    }
+
+    fn file_version(&self) -> i32 {
+        self.version
+    }
+}
+
 #[repr(transparent)]
 pub struct OsTraversalScope<'scope> {
     scope: Scope<'scope>,
@@ -372,3 +742,249 @@ impl From<fs::FileType> for ErrorKind {
         Self::UnknownFileType
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_changed_lines, run_git, OsFileSystem};
+    use crate::{ChangedLines, FileSystem};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// Creates an empty git repository in a new temporary directory
+    fn git_repository(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        run_git(&["init", "--quiet"], None, Some(&root)).unwrap();
+        root
+    }
+
+    /// Commits all the changes of the repository at `root`
+    fn git_commit(root: &Path) {
+        run_git(&["add", "--all"], None, Some(root)).unwrap();
+        let args = [
+            "-c",
+            "user.name=Biome",
+            "-c",
+            "user.email=biome@example.com",
+            "commit",
+            "--quiet",
+            "--message=commit",
+        ];
+        run_git(&args, None, Some(root)).unwrap();
+    }
+
+    #[test]
+    fn get_changed_lines_from_a_subdirectory() {
+        let root = git_repository("biome_fs_changed_lines");
+        fs::create_dir(root.join("sub")).unwrap();
+        fs::write(root.join("sub/file.js"), "a();\nb();\nc();\nd();\n").unwrap();
+        fs::write(root.join("other.js"), "a();\n").unwrap();
+        git_commit(&root);
+        run_git(&["branch", "base"], None, Some(&root)).unwrap();
+        fs::write(root.join("sub/file.js"), "e();\nb();\nc();\nf();\n").unwrap();
+        fs::write(root.join("other.js"), "c();\n").unwrap();
+        git_commit(&root);
+
+        let fs = OsFileSystem(Some(root.join("sub")));
+        assert_eq!(
+            fs.get_changed_lines("base").unwrap(),
+            vec![ChangedLines {
+                path: "file.js".to_string(),
+                lines: vec![0..1, 3..4],
+            }]
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn get_changed_lines_of_the_working_tree() {
+        let root = git_repository("biome_fs_changed_lines_working_tree");
+        fs::write(root.join("file.js"), "a();\nb();\nc();\nd();\n").unwrap();
+        git_commit(&root);
+        run_git(&["branch", "base"], None, Some(&root)).unwrap();
+        fs::write(root.join("file.js"), "e();\nb();\nc();\nd();\n").unwrap();
+        git_commit(&root);
+        // The uncommitted changes move the committed ones
+        fs::write(root.join("file.js"), "x();\ne();\nb();\nc();\nf();\n").unwrap();
+
+        let fs = OsFileSystem(Some(root.clone()));
+        assert_eq!(
+            fs.get_changed_lines("base").unwrap(),
+            vec![ChangedLines {
+                path: "file.js".to_string(),
+                lines: vec![0..2, 4..5],
+            }]
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    // The names of files can't contain `"` on Windows
+    #[cfg(unix)]
+    fn get_changed_lines_with_the_diff_configuration_of_the_user() {
+        let root = git_repository("biome_fs_changed_lines_configuration");
+        let names = ["my file.js", "ünïcode.js", "quote\".js"];
+        for name in names {
+            fs::write(root.join(name), "a();\nb();\nc();\n").unwrap();
+        }
+        git_commit(&root);
+        run_git(&["branch", "base"], None, Some(&root)).unwrap();
+        run_git(&["config", "diff.noprefix", "true"], None, Some(&root)).unwrap();
+        run_git(&["config", "core.quotePath", "true"], None, Some(&root)).unwrap();
+        for name in names {
+            fs::write(root.join(name), "x();\nb();\ny();\n").unwrap();
+        }
+
+        let fs = OsFileSystem(Some(root.clone()));
+        let mut changed_lines = fs.get_changed_lines("base").unwrap();
+        changed_lines.sort_by(|a, b| a.path.cmp(&b.path));
+        assert_eq!(
+            changed_lines,
+            vec![
+                ChangedLines {
+                    path: "my file.js".to_string(),
+                    lines: vec![0..1, 2..3],
+                },
+                ChangedLines {
+                    path: "quote\".js".to_string(),
+                    lines: vec![0..1, 2..3],
+                },
+                ChangedLines {
+                    path: "ünïcode.js".to_string(),
+                    lines: vec![0..1, 2..3],
+                },
+            ]
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn update_staged_files_from_a_subdirectory() {
+        let root = git_repository("biome_fs_staged");
+        fs::create_dir(root.join("sub")).unwrap();
+        fs::write(root.join("sub/my file.js"), "a").unwrap();
+        fs::write(root.join("sub/ünïcode.js"), "a").unwrap();
+        run_git(&["add", "--all"], None, Some(&root)).unwrap();
+
+        let fs = OsFileSystem(Some(root.join("sub")));
+        let mut staged_files = fs.get_staged_files().unwrap();
+        staged_files.sort();
+        assert_eq!(staged_files, vec!["my file.js", "ünïcode.js"]);
+
+        for path in &staged_files {
+            let mut file = fs.open_staged(Path::new(path)).unwrap();
+            file.set_content(b"b").unwrap();
+
+            let index_path = format!(":sub/{path}");
+            let staged = run_git(&["show", &index_path], None, Some(&root)).unwrap();
+            assert_eq!(staged, b"b");
+            assert_eq!(fs::read(root.join("sub").join(path)).unwrap(), b"b");
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    // The names of files can't contain `*` or `?` on Windows
+    #[cfg(unix)]
+    fn update_staged_files_with_pattern_characters() {
+        let root = git_repository("biome_fs_staged_patterns");
+        let names = ["*.js", "?.js", "[ab].js", ":(glob)a.js"];
+        for name in names {
+            fs::write(root.join(name), "a").unwrap();
+        }
+        // These files match the names above when they are patterns
+        fs::write(root.join("a.js"), "a").unwrap();
+        fs::write(root.join("b.js"), "a").unwrap();
+        run_git(&["add", "--all"], None, Some(&root)).unwrap();
+
+        let fs = OsFileSystem(Some(root.clone()));
+        for name in names {
+            let mut file = fs.open_staged(Path::new(name)).unwrap();
+            file.set_content(name.as_bytes()).unwrap();
+        }
+
+        for name in names.into_iter().chain(["a.js", "b.js"]) {
+            let expected = if names.contains(&name) { name } else { "a" };
+            let staged = run_git(&["show", &format!(":{name}")], None, Some(&root)).unwrap();
+            assert_eq!(staged, expected.as_bytes(), "staged content of {name}");
+            let working_copy = fs::read(root.join(name)).unwrap();
+            assert_eq!(working_copy, expected.as_bytes(), "content of {name}");
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn parse_changed_lines_of_diff() {
+        let diff = r#"diff --git a/file.js b/file.js
+index 3b18e51..a3c4b5e 100644
+--- a/file.js
++++ b/file.js
+@@ -1 +1 @@
+-let a = 1;
++let a = 2;
+@@ -5,0 +6,2 @@ function f() {
++    g();
++    h();
+@@ -10,2 +11,0 @@ function f() {
+-    i();
+-    j();
+diff --git a/other.js b/other.js
+index 2c2f3b1..9d1e5c2 100644
+--- a/other.js
++++ b/other.js
+@@ -1 +1 @@
+-a();
++b();
+@@ -3,0 +4,2 @@
++c();
++d();
+"#;
+
+        assert_eq!(
+            parse_changed_lines(diff),
+            vec![
+                ChangedLines {
+                    path: "file.js".to_string(),
+                    lines: vec![0..1, 5..7],
+                },
+                ChangedLines {
+                    path: "other.js".to_string(),
+                    lines: vec![0..1, 3..5],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn watch_reports_the_changed_files() {
+        let root = std::env::temp_dir().join(format!("biome_fs_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("node_modules/dependency")).unwrap();
+        fs::write(root.join("biome.json"), "{}").unwrap();
+        fs::write(root.join("other.json"), "{}").unwrap();
+
+        let mut watcher = OsFileSystem::default()
+            .watch(&[
+                root.join("src"),
+                root.join("node_modules"),
+                root.join("biome.json"),
+            ])
+            .unwrap();
+        fs::write(root.join("node_modules/dependency/index.js"), "a();\n").unwrap();
+        fs::write(root.join("other.json"), "{ }").unwrap();
+        fs::write(root.join("src/file.js"), "a();\n").unwrap();
+        fs::write(root.join("biome.json"), "{ }").unwrap();
+
+        assert_eq!(
+            watcher.next(),
+            Some(vec![root.join("biome.json"), root.join("src/file.js")])
+        );
+    }
+}
@@ -5,9 +5,9 @@ mod path;
 
 pub use dir::ensure_cache_dir;
 pub use fs::{
-    AutoSearchResult, ErrorEntry, File, FileSystem, FileSystemDiagnostic, FileSystemExt,
-    MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext, TraversalScope, BIOME_JSON,
-    ROME_JSON,
+    AutoSearchResult, ChangedLines, ErrorEntry, File, FileSystem, FileSystemDiagnostic,
+    FileSystemExt, FileWatcher, MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext,
+    TraversalScope, BIOME_JSON, ROME_JSON,
 };
 pub use interner::PathInterner;
 pub use path::RomePath;
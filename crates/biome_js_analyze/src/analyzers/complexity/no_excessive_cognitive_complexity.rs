@@ -6,7 +6,7 @@ use biome_console::markup;
 use biome_deserialize_macros::Deserializable;
 use biome_js_syntax::{
     AnyFunctionLike, JsBreakStatement, JsContinueStatement, JsElseClause, JsLanguage,
-    JsLogicalExpression, JsLogicalOperator,
+    JsLogicalExpression, JsLogicalOperator, JsSyntaxToken,
 };
 use biome_rowan::{AstNode, Language, SyntaxNode, TextRange, WalkEvent};
 use serde::{Deserialize, Serialize};
@@ -98,19 +98,7 @@ impl Rule for NoExcessiveCognitiveComplexity {
             max_allowed_complexity,
         } = ctx.options();
 
-        let range = function_like
-            .name_range()
-            .or_else(|| {
-                function_like
-                    .function_token()
-                    .map(|token| token.text_range())
-            })
-            .or_else(|| {
-                function_like
-                    .fat_arrow_token()
-                    .map(|token| token.text_range())
-            })
-            .or_else(|| function_like.body().ok().map(|body| body.range()))?;
+        let range = reported_range(function_like, false)?;
 
         Some(
             RuleDiagnostic::new(
@@ -185,11 +173,15 @@ impl Visitor for CognitiveComplexityVisitor {
     fn visit(
         &mut self,
         event: &WalkEvent<SyntaxNode<Self::Language>>,
-        ctx: VisitorContext<Self::Language>,
+        mut ctx: VisitorContext<Self::Language>,
     ) {
         match event {
             WalkEvent::Enter(node) => self.on_enter(node),
-            WalkEvent::Leave(node) => self.on_leave(node, ctx),
+            WalkEvent::Leave(node) => {
+                if let Some(complexity) = self.on_leave(node) {
+                    ctx.match_query(complexity);
+                }
+            }
         }
     }
 }
@@ -258,11 +250,12 @@ impl CognitiveComplexityVisitor {
         }
     }
 
-    fn on_leave(&mut self, node: &SyntaxNode<JsLanguage>, mut ctx: VisitorContext<JsLanguage>) {
+    /// Returns the complexity of the function when the visitor leaves it
+    fn on_leave(&mut self, node: &SyntaxNode<JsLanguage>) -> Option<CognitiveComplexity> {
         if let Some(exit_node) = AnyFunctionLike::cast_ref(node) {
             if let Some(function_state) = self.stack.pop() {
                 if function_state.function_like == exit_node {
-                    ctx.match_query(CognitiveComplexity {
+                    return Some(CognitiveComplexity {
                         function_like: exit_node,
                         score: ComplexityScore {
                             calculated_score: function_state.score,
@@ -290,7 +283,60 @@ impl CognitiveComplexityVisitor {
                 }
             }
         }
+
+        None
+    }
+}
+
+/// Returns the range of the function that the rule reports: its name, or its `function` keyword
+/// or its arrow when it's anonymous. The trivia of the tokens is excluded when `trimmed` is `true`.
+fn reported_range(function_like: &AnyFunctionLike, trimmed: bool) -> Option<TextRange> {
+    let token_range = |token: JsSyntaxToken| {
+        if trimmed {
+            token.text_trimmed_range()
+        } else {
+            token.text_range()
+        }
+    };
+    function_like
+        .name_range()
+        .or_else(|| function_like.function_token().map(token_range))
+        .or_else(|| function_like.fat_arrow_token().map(token_range))
+        .or_else(|| function_like.body().ok().map(|body| body.range()))
+}
+
+/// The cognitive complexity score of a function
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FunctionComplexity {
+    /// The range of the name of the function, or of its `function` keyword or its arrow when
+    /// it's anonymous
+    pub range: TextRange,
+    /// The score of the function. [u8::MAX] means that the function is too complex or too
+    /// deeply nested to calculate an accurate score.
+    pub score: u8,
+}
+
+/// Returns the cognitive complexity score of the functions of a file, with the same rules as
+/// `noExcessiveCognitiveComplexity`, in the order of the functions in the file
+pub fn cognitive_complexity(root: &SyntaxNode<JsLanguage>) -> Vec<FunctionComplexity> {
+    let mut visitor = CognitiveComplexityVisitor::default();
+    let mut functions = Vec::new();
+    for event in root.preorder() {
+        match event {
+            WalkEvent::Enter(node) => visitor.on_enter(&node),
+            WalkEvent::Leave(node) => {
+                let complexity = visitor.on_leave(&node).and_then(|complexity| {
+                    Some(FunctionComplexity {
+                        range: reported_range(&complexity.function_like, true)?,
+                        score: complexity.score.calculated_score,
+                    })
+                });
+                functions.extend(complexity);
+            }
+        }
     }
+    functions.sort_by_key(|function| function.range.start());
+    functions
 }
 
 /// Returns whether the node is considered to increase the nesting level inside
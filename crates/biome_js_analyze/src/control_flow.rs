@@ -1,5 +1,5 @@
 use biome_analyze::QueryMatch;
-use biome_analyze::{AddVisitor, Phases, Queryable, ServiceBag};
+use biome_analyze::{profiling::ProfiledVisitor, AddVisitor, Phases, Queryable, ServiceBag};
 use biome_js_syntax::AnyJsRoot;
 use biome_js_syntax::JsLanguage;
 use biome_js_syntax::TextRange;
@@ -31,7 +31,9 @@ impl Queryable for ControlFlowGraph {
     type Services = ();
 
     fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _: &AnyJsRoot) {
-        analyzer.add_visitor(Phases::Syntax, make_visitor);
+        analyzer.add_visitor(Phases::Syntax, || {
+            ProfiledVisitor::new("control flow graph", make_visitor())
+        });
     }
 
     fn unwrap_match(_: &ServiceBag, query: &ControlFlowGraph) -> Self::Output {
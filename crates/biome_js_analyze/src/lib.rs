@@ -2,9 +2,9 @@
 
 use crate::suppression_action::apply_suppression_comment;
 use biome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    SuppressionKind,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerPlugin, AnalyzerSignal,
+    ControlFlow, InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction,
+    RuleRegistry, SuppressionKind,
 };
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Diagnostic, Error as DiagnosticError};
@@ -30,6 +30,9 @@ mod suppression_action;
 mod syntax;
 pub mod utils;
 
+pub use crate::analyzers::complexity::no_excessive_cognitive_complexity::{
+    cognitive_complexity, ComplexityOptions, FunctionComplexity,
+};
 pub use crate::control_flow::ControlFlowGraph;
 pub use crate::registry::visit_registry;
 
@@ -53,12 +56,14 @@ pub fn metadata() -> &'static MetadataRegistry {
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action.
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
-/// processed by the lint rules registry
+/// processed by the lint rules registry, and the `plugins` that run alongside
+/// the rules of the registry
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    plugins: Vec<Box<dyn AnalyzerPlugin<JsLanguage> + 'a>>,
     source_type: JsFileSource,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
@@ -93,6 +98,8 @@ where
                     } else {
                         result.push(Ok(SuppressionKind::Everything));
                     }
+                } else if let Some(rule) = value.filter(|_| key == category!("lint/plugin")) {
+                    result.push(Ok(SuppressionKind::Plugin(rule)));
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
@@ -127,6 +134,12 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    for plugin in plugins {
+        if filter.match_plugin(plugin.name()) {
+            analyzer.add_plugin(plugin);
+        }
+    }
+
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
     services.insert_service(source_type);
@@ -155,7 +168,15 @@ where
     F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, source_type, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        Vec::new(),
+        source_type,
+        emit_signal,
+    )
 }
 
 /// Series of errors encountered when running rules on a file
@@ -16,7 +16,7 @@ use biome_analyze::RuleKey;
 use biome_console::markup;
 use biome_deserialize::{Deserializable, DeserializableValue, DeserializationDiagnostic};
 #[cfg(feature = "schemars")]
-use schemars::JsonSchema;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
@@ -49,115 +49,74 @@ impl Default for PossibleOptions {
     }
 }
 
-impl PossibleOptions {
-    pub fn extract_option(&self, rule_key: &RuleKey) -> RuleOptions {
-        match rule_key.rule_name() {
-            "noExcessiveCognitiveComplexity" => {
-                let options = match self {
-                    PossibleOptions::Complexity(options) => options.clone(),
-                    _ => ComplexityOptions::default(),
-                };
-                RuleOptions::new(options)
+/// Implements the functions of [PossibleOptions] from the list of the rules that have options,
+/// written as `"ruleName" => Variant(Options)`
+macro_rules! impl_possible_options {
+    ($($rule_name:literal => $variant:ident($options:ty),)*) => {
+        impl PossibleOptions {
+            pub fn extract_option(&self, rule_key: &RuleKey) -> RuleOptions {
+                match rule_key.rule_name() {
+                    $(
+                        $rule_name => {
+                            let options = match self {
+                                PossibleOptions::$variant(options) => options.clone(),
+                                _ => <$options>::default(),
+                            };
+                            RuleOptions::new(options)
+                        }
+                    )*
+                    // TODO: review error
+                    _ => panic!("This rule {:?} doesn't have options", rule_key),
+                }
             }
-            "useConsistentArrayType" => {
-                let options = match self {
-                    PossibleOptions::ConsistentArrayType(options) => options.clone(),
-                    _ => ConsistentArrayTypeOptions::default(),
-                };
-                RuleOptions::new(options)
-            }
-            "useExhaustiveDependencies" => {
-                let options = match self {
-                    PossibleOptions::Hooks(options) => options.clone(),
-                    _ => HooksOptions::default(),
-                };
-                RuleOptions::new(options)
-            }
-            "useFilenamingConvention" => {
-                let options = match self {
-                    PossibleOptions::FilenamingConvention(options) => options.clone(),
-                    _ => FilenamingConventionOptions::default(),
-                };
-                RuleOptions::new(options)
-            }
-            "useHookAtTopLevel" => {
-                let options = match self {
-                    PossibleOptions::DeprecatedHooks(options) => options.clone(),
-                    _ => DeprecatedHooksOptions::default(),
-                };
-                RuleOptions::new(options)
-            }
-            "useNamingConvention" => {
-                let options = match self {
-                    PossibleOptions::NamingConvention(options) => options.clone(),
-                    _ => NamingConventionOptions::default(),
-                };
-                RuleOptions::new(options)
-            }
-            "noRestrictedGlobals" => {
-                let options = match self {
-                    PossibleOptions::RestrictedGlobals(options) => options.clone(),
-                    _ => RestrictedGlobalsOptions::default(),
-                };
-                RuleOptions::new(options)
-            }
-            "useValidAriaRole" => {
-                let options = match self {
-                    PossibleOptions::ValidAriaRole(options) => options.clone(),
-                    _ => ValidAriaRoleOptions::default(),
-                };
-                RuleOptions::new(options)
-            }
-            "useSortedClasses" => {
-                let options = match self {
-                    PossibleOptions::UtilityClassSorting(options) => options.clone(),
-                    _ => UtilityClassSortingOptions::default(),
-                };
-                RuleOptions::new(options)
+
+            /// Returns the schema of the options of a rule, or [None] if the rule doesn't have options
+            #[cfg(feature = "schemars")]
+            pub fn rule_options_schema(
+                rule_name: &str,
+                gen: &mut SchemaGenerator,
+            ) -> Option<Schema> {
+                match rule_name {
+                    $($rule_name => Some(gen.subschema_for::<$options>()),)*
+                    _ => None,
+                }
             }
-            // TODO: review error
-            _ => panic!("This rule {:?} doesn't have options", rule_key),
         }
-    }
-}
 
-impl Deserializable for PossibleOptions {
-    fn deserialize(
-        value: &impl DeserializableValue,
-        rule_name: &str,
-        diagnostics: &mut Vec<DeserializationDiagnostic>,
-    ) -> Option<Self> {
-        match rule_name {
-            "noExcessiveCognitiveComplexity" => {
-                Deserializable::deserialize(value, "options", diagnostics).map(Self::Complexity)
-            }
-            "noRestrictedGlobals" => Deserializable::deserialize(value, "options", diagnostics)
-                .map(Self::RestrictedGlobals),
-            "useConsistentArrayType" => Deserializable::deserialize(value, "options", diagnostics)
-                .map(Self::ConsistentArrayType),
-            "useExhaustiveDependencies" => {
-                Deserializable::deserialize(value, "options", diagnostics).map(Self::Hooks)
-            }
-            "useHookAtTopLevel" => Deserializable::deserialize(value, "options", diagnostics)
-                .map(Self::DeprecatedHooks),
-            "useFilenamingConvention" => Deserializable::deserialize(value, "options", diagnostics)
-                .map(Self::FilenamingConvention),
-            "useNamingConvention" => Deserializable::deserialize(value, "options", diagnostics)
-                .map(Self::NamingConvention),
-            "useValidAriaRole" => {
-                Deserializable::deserialize(value, "options", diagnostics).map(Self::ValidAriaRole)
-            }
-            "useSortedClasses" => Deserializable::deserialize(value, "options", diagnostics)
-                .map(Self::UtilityClassSorting),
-            _ => {
-                diagnostics.push(
-                    DeserializationDiagnostic::new(markup! {
-                        "The rule "<Emphasis>{rule_name}</Emphasis>" doesn't accept any options."
-                    })
-                    .with_range(value.range()),
-                );
-                None
+        impl Deserializable for PossibleOptions {
+            fn deserialize(
+                value: &impl DeserializableValue,
+                rule_name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self> {
+                match rule_name {
+                    $(
+                        $rule_name => Deserializable::deserialize(value, "options", diagnostics)
+                            .map(Self::$variant),
+                    )*
+                    _ => {
+                        diagnostics.push(
+                            DeserializationDiagnostic::new(markup! {
+                                "The rule "<Emphasis>{rule_name}</Emphasis>" doesn't accept any options."
+                            })
+                            .with_range(value.range()),
+                        );
+                        None
+                    }
+                }
             }
         }
-    }
+    };
+}
+
+impl_possible_options! {
+    "noExcessiveCognitiveComplexity" => Complexity(ComplexityOptions),
+    "noRestrictedGlobals" => RestrictedGlobals(RestrictedGlobalsOptions),
+    "useConsistentArrayType" => ConsistentArrayType(ConsistentArrayTypeOptions),
+    "useExhaustiveDependencies" => Hooks(HooksOptions),
+    "useFilenamingConvention" => FilenamingConvention(FilenamingConventionOptions),
+    "useHookAtTopLevel" => DeprecatedHooks(DeprecatedHooksOptions),
+    "useNamingConvention" => NamingConvention(NamingConventionOptions),
+    "useSortedClasses" => UtilityClassSorting(UtilityClassSortingOptions),
+    "useValidAriaRole" => ValidAriaRole(ValidAriaRoleOptions),
 }
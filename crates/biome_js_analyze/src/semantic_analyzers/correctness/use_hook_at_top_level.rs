@@ -1,10 +1,12 @@
 use crate::react::hooks::{is_react_component, is_react_hook, is_react_hook_call};
-use crate::semantic_services::{SemanticModelBuilderVisitor, SemanticServices};
+use crate::semantic_services::{
+    SemanticModelBuilderVisitor, SemanticServices, SEMANTIC_MODEL_PHASE,
+};
 use biome_analyze::RuleSource;
 use biome_analyze::{
-    context::RuleContext, declare_rule, AddVisitor, FromServices, MissingServicesDiagnostic, Phase,
-    Phases, QueryMatch, Queryable, Rule, RuleDiagnostic, RuleKey, ServiceBag, Visitor,
-    VisitorContext, VisitorFinishContext,
+    context::RuleContext, declare_rule, profiling::ProfiledVisitor, AddVisitor, FromServices,
+    MissingServicesDiagnostic, Phase, Phases, QueryMatch, Queryable, Rule, RuleDiagnostic, RuleKey,
+    ServiceBag, Visitor, VisitorContext, VisitorFinishContext,
 };
 use biome_console::markup;
 use biome_deserialize::{
@@ -381,7 +383,9 @@ impl Queryable for FunctionCall {
         analyzer: &mut impl AddVisitor<Self::Language>,
         root: &<Self::Language as Language>::Root,
     ) {
-        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Syntax, || {
+            ProfiledVisitor::new(SEMANTIC_MODEL_PHASE, SemanticModelBuilderVisitor::new(root))
+        });
         analyzer.add_visitor(Phases::Syntax, EarlyReturnDetectionVisitor::default);
         analyzer.add_visitor(Phases::Semantic, FunctionCallVisitor::default);
     }
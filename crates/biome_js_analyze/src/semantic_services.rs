@@ -1,6 +1,7 @@
 use biome_analyze::{
-    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, QueryMatch,
-    Queryable, RuleKey, ServiceBag, SyntaxVisitor, Visitor, VisitorContext, VisitorFinishContext,
+    profiling::ProfiledVisitor, AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases,
+    QueryKey, QueryMatch, Queryable, RuleKey, ServiceBag, SyntaxVisitor, Visitor, VisitorContext,
+    VisitorFinishContext,
 };
 use biome_js_semantic::{SemanticEventExtractor, SemanticModel, SemanticModelBuilder};
 use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode, TextRange, WalkEvent};
@@ -46,7 +47,9 @@ impl Queryable for SemanticServices {
     type Services = Self;
 
     fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, root: &AnyJsRoot) {
-        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Syntax, || {
+            ProfiledVisitor::new(SEMANTIC_MODEL_PHASE, SemanticModelBuilderVisitor::new(root))
+        });
         analyzer.add_visitor(Phases::Semantic, || SemanticModelVisitor);
     }
 
@@ -73,7 +76,9 @@ where
     type Services = SemanticServices;
 
     fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, root: &AnyJsRoot) {
-        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Syntax, || {
+            ProfiledVisitor::new(SEMANTIC_MODEL_PHASE, SemanticModelBuilderVisitor::new(root))
+        });
         analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default);
     }
 
@@ -86,6 +91,9 @@ where
     }
 }
 
+/// The profiling phase that contains the time spent building the [SemanticModel]
+pub(crate) const SEMANTIC_MODEL_PHASE: &str = "semantic model";
+
 pub(crate) struct SemanticModelBuilderVisitor {
     extractor: SemanticEventExtractor,
     builder: SemanticModelBuilder,
@@ -1,11 +1,13 @@
 use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_diagnostics::{Diagnostic, Location, Severity};
+use biome_js_factory::make;
 use biome_js_semantic::{ReferencesExtensions, SemanticModel};
 use biome_js_syntax::{
-    binding_ext::AnyJsIdentifierBinding, JsIdentifierAssignment, JsIdentifierBinding, JsLanguage,
-    JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TextRange,
-    TsIdentifierBinding,
+    binding_ext::AnyJsIdentifierBinding, AnyJsBinding, AnyJsNamedImportSpecifier,
+    JsIdentifierAssignment, JsIdentifierBinding, JsLanguage, JsReferenceIdentifier,
+    JsShorthandNamedImportSpecifier, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TextRange,
+    TsIdentifierBinding, T,
 };
 use biome_rowan::{AstNode, BatchMutation, SyntaxNodeCast, TriviaPiece};
 use serde::{Deserialize, Serialize};
@@ -79,6 +81,8 @@ pub enum RenameError {
         original_range: TextRange,
         new_name: String,
     },
+    /// The new name isn't a valid identifier, or it's a reserved word
+    InvalidName(String),
 }
 
 impl std::fmt::Display for RenameError {
@@ -101,6 +105,9 @@ impl std::fmt::Display for RenameError {
                     "encountered an error finding a declaration at the specified position"
                 )
             }
+            RenameError::InvalidName(new_name) => {
+                write!(f, "\"{}\" isn't a valid name for a binding", new_name)
+            }
         }
     }
 }
@@ -122,6 +129,11 @@ impl Diagnostic for RenameError {
                     markup! { "Can't rename from "<Emphasis>{{original_name}}</Emphasis>" to "<Emphasis>{{new_name}}</Emphasis>"" }
                 )
             }
+            RenameError::InvalidName(new_name) => {
+                fmt.write_markup(
+                    markup! { <Emphasis>{{new_name}}</Emphasis>" isn't a valid name for a binding: it's a reserved word or it isn't an identifier." }
+                )
+            }
         }
     }
 
@@ -309,8 +321,30 @@ impl RenameSymbolExtensions for BatchMutation<JsLanguage> {
             return false;
         };
 
-        let next_name_token = token_with_new_text(&name_token, new_name);
-        self.replace_token(prev_name_token, next_name_token);
+        if let Some(specifier) = syntax
+            .parent()
+            .and_then(JsShorthandNamedImportSpecifier::cast)
+        {
+            // Keep the imported name: `import { a }` becomes `import { a as b }`
+            let next_specifier = make::js_named_import_specifier(
+                make::js_literal_export_name(make::ident(name_token.text_trimmed())),
+                make::token_decorated_with_space(T![as]),
+                AnyJsBinding::JsIdentifierBinding(make::js_identifier_binding(make::ident(
+                    new_name,
+                ))),
+            );
+            let next_specifier = match specifier.type_token() {
+                Some(type_token) => next_specifier.with_type_token(type_token).build(),
+                None => next_specifier.build(),
+            };
+            self.replace_node(
+                AnyJsNamedImportSpecifier::from(specifier),
+                AnyJsNamedImportSpecifier::from(next_specifier),
+            );
+        } else {
+            let next_name_token = token_with_new_text(&name_token, new_name);
+            self.replace_token(prev_name_token, next_name_token);
+        }
 
         // Rename all references
         for (prev_token, next_token) in changes {
@@ -356,6 +390,9 @@ mod tests {
         ok_rename_function_same_name,
             "function a() { function b() {console.log(2)}; console.log(1); b(); } a();",
             "function b() { function b() {console.log(2)}; console.log(1); b(); } b();",
+        ok_rename_shorthand_import_keeps_the_imported_name,
+            "import { a, c } from 'mod'; a;",
+            "import { a as b, c } from 'mod'; b;",
     }
 
     assert_rename_nok! {
@@ -388,6 +425,14 @@ mod tests {
         )
     }
 
+    #[test]
+    fn invalid_name() {
+        snap_diagnostic(
+            "invalid_name",
+            &RenameError::InvalidName("class".to_string()).with_file_path("example.js"),
+        )
+    }
+
     #[test]
     fn cannot_be_renamed() {
         let source_code = "async function f() {}";
@@ -12,11 +12,11 @@ pub struct WorkspaceSettings {
     #[serde(default)]
     pub unstable: bool,
 
-    /// Enable rename capability
-    pub rename: Option<bool>,
-
     /// Only run Biome if a `biome.json` configuration file exists.
     pub require_configuration: Option<bool>,
+
+    /// Show the cognitive complexity score of every function as an inlay hint.
+    pub complexity_hints: Option<bool>,
 }
 
 /// The `biome.*` extension settings
@@ -45,4 +45,8 @@ impl ExtensionSettings {
     pub(crate) fn requires_configuration(&self) -> bool {
         self.settings.require_configuration.unwrap_or_default()
     }
+
+    pub(crate) fn shows_complexity_hints(&self) -> bool {
+        self.settings.complexity_hints.unwrap_or_default()
+    }
 }
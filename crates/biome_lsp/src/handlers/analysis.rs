@@ -181,6 +181,8 @@ fn fix_all(
         path: rome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        only: Vec::new(),
+        skip: Vec::new(),
     })?;
 
     if fixed.actions.is_empty() {
@@ -0,0 +1,206 @@
+use crate::converters::{from_proto, to_proto};
+use crate::session::Session;
+use anyhow::{bail, Result};
+use biome_service::workspace::{
+    CognitiveComplexityParams, FeatureName, FeaturesBuilder, GetFileContentParams,
+    SupportsFeatureParams, SuppressionsParams,
+};
+use biome_service::WorkspaceError;
+use serde_json::{json, Value};
+use tower_lsp::lsp_types as lsp;
+use tracing::warn;
+
+/// The command of the code lenses that remove a suppression comment. Its arguments are the URL
+/// of the document, the range of the comment and the version of the document.
+pub(crate) const REMOVE_SUPPRESSION_COMMAND: &str = "biome.removeSuppression";
+
+/// Returns the code lenses of the suppression comments, which tell whether the comments still
+/// suppress a diagnostic and remove them, and the code lenses of the functions reported by
+/// `noExcessiveCognitiveComplexity`, which show their score
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn code_lens(
+    session: &Session,
+    params: lsp::CodeLensParams,
+) -> Result<Option<Vec<lsp::CodeLens>>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    if session.is_linting_and_formatting_disabled() {
+        return Ok(None);
+    }
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeaturesBuilder::new().with_linter().build(),
+    })?;
+    if !file_features.supports_for(&FeatureName::Lint) {
+        return Ok(None);
+    }
+
+    let suppressions = match session.workspace.suppressions(SuppressionsParams {
+        path: rome_path.clone(),
+    }) {
+        Ok(result) => result.suppressions,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let complexity = session
+        .workspace
+        .cognitive_complexity(CognitiveComplexityParams { path: rome_path })?;
+
+    let mut code_lenses = Vec::new();
+    for suppression in suppressions {
+        let range = to_proto::range(&doc.line_index, suppression.range, position_encoding)?;
+        let title = if suppression.unused {
+            "Suppression no longer needed"
+        } else {
+            "Suppression still needed"
+        };
+        code_lenses.push(title_code_lens(range, title.to_string()));
+        // Only the suppressions that are no longer needed are offered to be removed
+        if !suppression.unused {
+            continue;
+        }
+        code_lenses.push(lsp::CodeLens {
+            range,
+            command: Some(lsp::Command {
+                title: String::from("Remove suppression"),
+                command: String::from(REMOVE_SUPPRESSION_COMMAND),
+                arguments: Some(vec![json!(url), json!(range), json!(doc.version)]),
+            }),
+            data: None,
+        });
+    }
+
+    if let Some(max_allowed_complexity) = complexity.max_allowed_complexity {
+        for function in complexity.functions {
+            if function.score <= max_allowed_complexity {
+                continue;
+            }
+            let range = to_proto::range(&doc.line_index, function.range, position_encoding)?;
+            let title = format!(
+                "Cognitive complexity {} (max {max_allowed_complexity})",
+                score_label(function.score)
+            );
+            code_lenses.push(title_code_lens(range, title));
+        }
+    }
+
+    code_lenses.sort_by_key(|code_lens| code_lens.range.start);
+
+    Ok(Some(code_lenses))
+}
+
+/// Returns a code lens that only shows its title: the clients don't run a command without
+/// identifier
+fn title_code_lens(range: lsp::Range, title: String) -> lsp::CodeLens {
+    lsp::CodeLens {
+        range,
+        command: Some(lsp::Command {
+            title,
+            command: String::new(),
+            arguments: None,
+        }),
+        data: None,
+    }
+}
+
+/// Returns the text of a cognitive complexity score. The maximum score means that the function
+/// is too complex to calculate an accurate score.
+pub(crate) fn score_label(score: u8) -> String {
+    if score == u8::MAX {
+        format!("{score}+")
+    } else {
+        score.to_string()
+    }
+}
+
+/// Runs the commands of the code lenses
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn execute_command(
+    session: &Session,
+    params: lsp::ExecuteCommandParams,
+) -> Result<Option<Value>> {
+    if params.command != REMOVE_SUPPRESSION_COMMAND {
+        bail!("unknown command {}", params.command);
+    }
+
+    let (url, range, version): (lsp::Url, lsp::Range, i32) =
+        serde_json::from_value(Value::Array(params.arguments))?;
+    let edit = remove_suppression(session, &url, range, version)?;
+
+    let response = session.client.apply_edit(edit).await?;
+    if !response.applied {
+        warn!(
+            "The client didn't remove the suppression comment: {}",
+            response.failure_reason.unwrap_or_default()
+        );
+    }
+
+    Ok(None)
+}
+
+/// Returns the edit that removes a suppression comment. The line of the comment is removed when
+/// the comment is alone on its line.
+fn remove_suppression(
+    session: &Session,
+    url: &lsp::Url,
+    range: lsp::Range,
+    version: i32,
+) -> Result<lsp::WorkspaceEdit> {
+    let doc = session.document(url)?;
+    if doc.version != version {
+        bail!("the document {url} changed since the suppression comment was found");
+    }
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        path: session.file_path(url)?,
+    })?;
+    let position_encoding = session.position_encoding();
+
+    let start = usize::from(from_proto::offset(
+        &doc.line_index,
+        range.start,
+        position_encoding,
+    )?);
+    let end = usize::from(from_proto::offset(
+        &doc.line_index,
+        range.end,
+        position_encoding,
+    )?);
+
+    let line_start = content[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = content[end..]
+        .find('\n')
+        .map_or(content.len(), |index| end + index + 1);
+    let before = &content[line_start..start];
+    let after = &content[end..line_end];
+
+    let (start, end) = if before.trim().is_empty() && after.trim().is_empty() {
+        (line_start, line_end)
+    } else {
+        // The spaces between the code and a trailing comment are removed with the comment
+        (line_start + before.trim_end().len(), end)
+    };
+
+    let edit = lsp::TextEdit {
+        range: lsp::Range::new(
+            to_proto::position(&doc.line_index, start.try_into()?, position_encoding)?,
+            to_proto::position(&doc.line_index, end.try_into()?, position_encoding)?,
+        ),
+        new_text: String::new(),
+    };
+
+    Ok(lsp::WorkspaceEdit {
+        document_changes: Some(lsp::DocumentChanges::Edits(vec![lsp::TextDocumentEdit {
+            text_document: lsp::OptionalVersionedTextDocumentIdentifier {
+                uri: url.clone(),
+                version: Some(version),
+            },
+            edits: vec![lsp::OneOf::Left(edit)],
+        }])),
+        ..lsp::WorkspaceEdit::default()
+    })
+}
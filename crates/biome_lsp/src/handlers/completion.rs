@@ -0,0 +1,64 @@
+use crate::converters::{from_proto, to_proto};
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_service::workspace::{CompletionItemKind, CompletionsParams};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{self as lsp, MarkupContent, MarkupKind};
+
+/// Completes the keys and the values of the configuration file, computed from the schema of the
+/// configuration
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn completion(
+    session: &Session,
+    params: lsp::CompletionParams,
+) -> Result<Option<lsp::CompletionResponse>> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let result = match session.workspace.completions(CompletionsParams {
+        path: rome_path,
+        offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if result.items.is_empty() {
+        return Ok(None);
+    }
+
+    let range = to_proto::range(&doc.line_index, result.range, position_encoding)?;
+    let items = result
+        .items
+        .into_iter()
+        .map(|item| lsp::CompletionItem {
+            kind: Some(match item.kind {
+                CompletionItemKind::Property => lsp::CompletionItemKind::PROPERTY,
+                CompletionItemKind::Value => lsp::CompletionItemKind::VALUE,
+            }),
+            // The text typed by the user starts with the quote of the key or the value
+            filter_text: Some(item.text.trim_end_matches([':', ' ']).to_string()),
+            text_edit: Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+                range,
+                new_text: item.text,
+            })),
+            documentation: item.documentation.map(|documentation| {
+                lsp::Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: documentation,
+                })
+            }),
+            label: item.label,
+            ..lsp::CompletionItem::default()
+        })
+        .collect();
+
+    Ok(Some(lsp::CompletionResponse::Array(items)))
+}
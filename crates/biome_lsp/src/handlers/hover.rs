@@ -0,0 +1,218 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto, PositionEncoding};
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_analyze::{RuleCategories, RuleMetadata, RuleSourceKind};
+use biome_diagnostics::{Category, Diagnostic};
+use biome_fs::RomePath;
+use biome_rowan::{TextRange, TextSize};
+use biome_service::documentation::Doc;
+use biome_service::workspace::{
+    BindingKind, FeatureName, FeaturesBuilder, HoverBinding, HoverParams, PullDiagnosticsParams,
+    SupportsFeatureParams,
+};
+use biome_service::WorkspaceError;
+use std::fmt::Write;
+use tower_lsp::lsp_types::{self as lsp, HoverContents, MarkupContent, MarkupKind};
+
+/// Shows the documentation of the rules that emitted a diagnostic under the cursor, and the
+/// declaration of the binding under the cursor. In the configuration file, it shows the
+/// documentation of the rule under the cursor.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn hover(session: &Session, params: lsp::HoverParams) -> Result<Option<lsp::Hover>> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let mut sections = Vec::new();
+    let mut range = None;
+
+    for (group_name, rule_name, diagnostic_range) in
+        rules_of_diagnostics_at(session, &rome_path, offset)?
+    {
+        if let Ok(Doc::Rule(metadata)) = rule_name.parse() {
+            sections.push(rule_documentation(&group_name, &metadata));
+            range = Some(diagnostic_range);
+        }
+    }
+
+    let result = match session.workspace.hover(HoverParams {
+        path: rome_path,
+        offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            Default::default()
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    if let Some((group_name, rule_name)) = &result.rule_name {
+        if let Ok(Doc::Rule(metadata)) = rule_name.parse() {
+            sections.push(rule_documentation(group_name, &metadata));
+        }
+    }
+    if let Some(binding) = &result.binding {
+        sections.push(binding_documentation(
+            binding,
+            &doc.line_index,
+            position_encoding,
+        )?);
+    }
+    if result.range.is_some() {
+        range = result.range;
+    }
+
+    if sections.is_empty() {
+        return Ok(None);
+    }
+
+    let range = range
+        .map(|range| to_proto::range(&doc.line_index, range, position_encoding))
+        .transpose()?;
+
+    Ok(Some(lsp::Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: sections.join("\n\n---\n\n"),
+        }),
+        range,
+    }))
+}
+
+/// Returns the group, the name and the range of the lint rules that emitted a diagnostic at `offset`
+fn rules_of_diagnostics_at(
+    session: &Session,
+    rome_path: &RomePath,
+    offset: TextSize,
+) -> Result<Vec<(String, String, TextRange)>> {
+    if session.is_linting_and_formatting_disabled() {
+        return Ok(Vec::new());
+    }
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeaturesBuilder::new().with_linter().build(),
+    })?;
+    if !file_features.supports_for(&FeatureName::Lint) {
+        return Ok(Vec::new());
+    }
+
+    let result = session.workspace.pull_diagnostics(PullDiagnosticsParams {
+        path: rome_path.clone(),
+        categories: RuleCategories::LINT,
+        max_diagnostics: u64::MAX,
+        only: Vec::new(),
+        skip: Vec::new(),
+    })?;
+
+    let mut rules: Vec<(String, String, TextRange)> = Vec::new();
+    for diagnostic in result.diagnostics {
+        let Some(span) = diagnostic.location().span else {
+            continue;
+        };
+        if !span.contains_inclusive(offset) {
+            continue;
+        }
+        let Some((group_name, rule_name)) = diagnostic
+            .category()
+            .and_then(|category| category.name().strip_prefix("lint/"))
+            .and_then(|name| name.split_once('/'))
+        else {
+            continue;
+        };
+        // A rule can emit several diagnostics at the same place
+        if rules.iter().all(|(_, name, _)| name != rule_name) {
+            rules.push((group_name.to_string(), rule_name.to_string(), span));
+        }
+    }
+
+    Ok(rules)
+}
+
+/// Returns the documentation of a rule as Markdown, with a link to the rule it comes from. The
+/// attributes of the code blocks, e.g. `expect_diagnostic`, are removed so that the editors can
+/// highlight the code.
+fn rule_documentation(group_name: &str, metadata: &RuleMetadata) -> String {
+    let name = format!("lint/{group_name}/{}", metadata.name);
+    let mut markdown = match name
+        .parse::<&'static Category>()
+        .ok()
+        .and_then(Category::link)
+    {
+        Some(link) => format!("**[{name}]({link})**\n\n"),
+        None => format!("**{name}**\n\n"),
+    };
+
+    for line in metadata.docs.lines() {
+        // The documentation comments start with a space
+        let line = line.strip_prefix(' ').unwrap_or(line);
+        match line.strip_prefix("```") {
+            Some(attributes) => {
+                let language = attributes.split(',').next().unwrap_or_default();
+                let _ = writeln!(markdown, "```{}", language.trim());
+            }
+            None => {
+                let _ = writeln!(markdown, "{line}");
+            }
+        }
+    }
+
+    if let Some(source) = &metadata.source {
+        let (source_rule_url, source_rule_name) = source.as_url_and_rule_name();
+        let label = if metadata
+            .source_kind
+            .as_ref()
+            .is_some_and(RuleSourceKind::is_inspired)
+        {
+            "Inspired from"
+        } else {
+            "Source"
+        };
+        let _ = writeln!(
+            markdown,
+            "\n{label}: [`{source_rule_name}`]({source_rule_url})"
+        );
+    }
+
+    markdown.trim_end().to_string()
+}
+
+/// Returns the declaration of a binding as Markdown, e.g. `const a`, and where it's declared
+fn binding_documentation(
+    binding: &HoverBinding,
+    line_index: &LineIndex,
+    position_encoding: PositionEncoding,
+) -> Result<String> {
+    let keyword = match binding.kind {
+        BindingKind::Const => "const",
+        BindingKind::Let => "let",
+        BindingKind::Var => "var",
+        BindingKind::Using => "using",
+        BindingKind::Function => "function",
+        BindingKind::Class => "class",
+        BindingKind::Parameter => "(parameter)",
+        BindingKind::TypeParameter => "(type parameter)",
+        BindingKind::Interface => "interface",
+        BindingKind::TypeAlias => "type",
+        BindingKind::Enum => "enum",
+        BindingKind::Namespace => "namespace",
+        BindingKind::Import => "import",
+        BindingKind::CatchParameter => "(catch parameter)",
+    };
+    let position = to_proto::position(
+        line_index,
+        binding.declaration_range.start(),
+        position_encoding,
+    )?;
+
+    Ok(format!(
+        "```ts\n{keyword} {}\n```\n\nDeclared at line {}, column {}",
+        binding.name,
+        position.line + 1,
+        position.character + 1
+    ))
+}
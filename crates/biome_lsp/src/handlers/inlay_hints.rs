@@ -0,0 +1,71 @@
+use crate::converters::{from_proto, to_proto};
+use crate::handlers::code_lens::score_label;
+use crate::session::Session;
+use anyhow::Result;
+use biome_rowan::TextRange;
+use biome_service::workspace::CognitiveComplexityParams;
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types as lsp;
+
+/// Returns the cognitive complexity score of the functions of a range of the document, when the
+/// `complexityHints` setting is enabled
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn inlay_hint(
+    session: &Session,
+    params: lsp::InlayHintParams,
+) -> Result<Option<Vec<lsp::InlayHint>>> {
+    if !session.shows_complexity_hints() {
+        return Ok(None);
+    }
+
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let range = TextRange::new(
+        from_proto::offset(&doc.line_index, params.range.start, position_encoding)?,
+        from_proto::offset(&doc.line_index, params.range.end, position_encoding)?,
+    );
+
+    let result = match session
+        .workspace
+        .cognitive_complexity(CognitiveComplexityParams { path: rome_path })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let tooltip = match result.max_allowed_complexity {
+        Some(max_allowed_complexity) => format!(
+            "The cognitive complexity score of the function. `noExcessiveCognitiveComplexity` \
+            reports the functions whose score is above {max_allowed_complexity}."
+        ),
+        None => String::from("The cognitive complexity score of the function."),
+    };
+
+    let mut inlay_hints = Vec::new();
+    for function in result.functions {
+        if !range.contains_range(function.range) {
+            continue;
+        }
+        inlay_hints.push(lsp::InlayHint {
+            position: to_proto::position(&doc.line_index, function.range.end(), position_encoding)?,
+            label: lsp::InlayHintLabel::String(format!(
+                "complexity: {}",
+                score_label(function.score)
+            )),
+            kind: None,
+            text_edits: None,
+            tooltip: Some(lsp::InlayHintTooltip::String(tooltip.clone())),
+            padding_left: Some(true),
+            padding_right: Some(true),
+            data: None,
+        });
+    }
+
+    Ok(Some(inlay_hints))
+}
@@ -0,0 +1,157 @@
+use crate::converters::{from_proto, to_proto};
+use crate::documents::Document;
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_service::module_resolution::relative_module_candidates;
+use biome_service::workspace::{
+    BindingReference, FindReferencesParams, GotoDefinitionParams, ReferenceKind,
+};
+use biome_service::WorkspaceError;
+use std::path::PathBuf;
+use tower_lsp::lsp_types::{self as lsp, DocumentHighlightKind};
+
+/// Returns where the binding under the cursor is declared. From the source of an import or an
+/// export, or from a binding declared by an import, it returns the imported module when it's a
+/// relative module that exists on disk.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: lsp::GotoDefinitionParams,
+) -> Result<Option<lsp::GotoDefinitionResponse>> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let result = match session.workspace.goto_definition(GotoDefinitionParams {
+        path: rome_path,
+        offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let module_url = result
+        .module_specifier
+        .as_deref()
+        .and_then(|specifier| resolve_relative_module(session, &url, specifier))
+        .and_then(|path| lsp::Url::from_file_path(path).ok());
+    if let Some(module_url) = module_url {
+        return Ok(Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location {
+            uri: module_url,
+            range: lsp::Range::default(),
+        })));
+    }
+
+    let Some(declaration_range) = result.declaration_range else {
+        return Ok(None);
+    };
+    let range = to_proto::range(&doc.line_index, declaration_range, position_encoding)?;
+    Ok(Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location {
+        uri: url,
+        range,
+    })))
+}
+
+/// Returns the references of the binding under the cursor, and its declaration when the client
+/// asks for it
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: lsp::ReferenceParams,
+) -> Result<Option<Vec<lsp::Location>>> {
+    let url = params.text_document_position.text_document.uri;
+    let (doc, references) = references_at(session, &url, params.text_document_position.position)?;
+    if references.is_empty() {
+        return Ok(None);
+    }
+
+    let position_encoding = session.position_encoding();
+    let locations = references
+        .into_iter()
+        .filter(|reference| {
+            params.context.include_declaration || reference.kind != ReferenceKind::Declaration
+        })
+        .map(|reference| {
+            Ok(lsp::Location {
+                uri: url.clone(),
+                range: to_proto::range(&doc.line_index, reference.range, position_encoding)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(locations))
+}
+
+/// Highlights the declaration and the references of the binding under the cursor. The
+/// declaration and the assignments are highlighted as writes.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_highlight(
+    session: &Session,
+    params: lsp::DocumentHighlightParams,
+) -> Result<Option<Vec<lsp::DocumentHighlight>>> {
+    let url = params.text_document_position_params.text_document.uri;
+    let (doc, references) =
+        references_at(session, &url, params.text_document_position_params.position)?;
+    if references.is_empty() {
+        return Ok(None);
+    }
+
+    let position_encoding = session.position_encoding();
+    let highlights = references
+        .into_iter()
+        .map(|reference| {
+            Ok(lsp::DocumentHighlight {
+                range: to_proto::range(&doc.line_index, reference.range, position_encoding)?,
+                kind: Some(match reference.kind {
+                    ReferenceKind::Declaration | ReferenceKind::Write => {
+                        DocumentHighlightKind::WRITE
+                    }
+                    ReferenceKind::Read => DocumentHighlightKind::READ,
+                }),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(highlights))
+}
+
+/// Returns the document and the declaration and references of the binding at `position`
+fn references_at(
+    session: &Session,
+    url: &lsp::Url,
+    position: lsp::Position,
+) -> Result<(Document, Vec<BindingReference>)> {
+    let rome_path = session.file_path(url)?;
+    let doc = session.document(url)?;
+    let offset = from_proto::offset(&doc.line_index, position, session.position_encoding())
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let references = match session.workspace.find_references(FindReferencesParams {
+        path: rome_path,
+        offset,
+    }) {
+        Ok(result) => result.references,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            Vec::new()
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok((doc, references))
+}
+
+/// Returns the file of a module imported with a relative specifier, e.g. `./utils`, when it
+/// exists on disk
+fn resolve_relative_module(session: &Session, url: &lsp::Url, specifier: &str) -> Option<PathBuf> {
+    let document_path = url.to_file_path().ok()?;
+    relative_module_candidates(&document_path, specifier)?
+        .into_iter()
+        .find(|candidate| session.fs.path_is_file(candidate))
+}
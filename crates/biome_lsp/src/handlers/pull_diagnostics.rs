@@ -0,0 +1,230 @@
+//! The pull diagnostics of the version 3.17 of the protocol. `lsp-types` doesn't have their
+//! types yet, so they're declared here with the subset of the fields that the server uses.
+
+use crate::converters::line_index::LineIndex;
+use crate::session::{Session, SessionHandle};
+use anyhow::Result;
+use biome_fs::FileSystemExt;
+use biome_service::workspace::{FeatureName, GetFileContentParams, IsPathIgnoredParams};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+use tokio::task::spawn_blocking;
+use tower_lsp::lsp_types as lsp;
+use tracing::error;
+
+pub(crate) const DOCUMENT_DIAGNOSTIC_REQUEST: &str = "textDocument/diagnostic";
+pub(crate) const WORKSPACE_DIAGNOSTIC_REQUEST: &str = "workspace/diagnostic";
+
+/// The options of the dynamic registration of [DOCUMENT_DIAGNOSTIC_REQUEST]
+pub(crate) fn registration_options() -> Value {
+    json!({
+        "documentSelector": null,
+        "identifier": "biome",
+        // The diagnostics of a file don't depend on the other files
+        "interFileDependencies": false,
+        "workspaceDiagnostics": true,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DocumentDiagnosticParams {
+    pub(crate) text_document: lsp::TextDocumentIdentifier,
+    /// The result id of the report that the client has for the document
+    pub(crate) previous_result_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub(crate) enum DocumentDiagnosticReport {
+    /// All the diagnostics of the document
+    Full {
+        #[serde(rename = "resultId")]
+        result_id: String,
+        items: Vec<lsp::Diagnostic>,
+    },
+    /// The diagnostics didn't change since the report of the previous result id
+    Unchanged {
+        #[serde(rename = "resultId")]
+        result_id: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WorkspaceDiagnosticParams {
+    /// The result ids of the reports that the client has for the files of the workspace
+    #[serde(default)]
+    pub(crate) previous_result_ids: Vec<PreviousResultId>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct PreviousResultId {
+    pub(crate) uri: lsp::Url,
+    pub(crate) value: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct WorkspaceDiagnosticReport {
+    pub(crate) items: Vec<WorkspaceDocumentDiagnosticReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct WorkspaceDocumentDiagnosticReport {
+    pub(crate) uri: lsp::Url,
+    /// The version of the opened document, or `None` when the file isn't opened in the editor
+    pub(crate) version: Option<i32>,
+    #[serde(flatten)]
+    pub(crate) report: DocumentDiagnosticReport,
+}
+
+/// Asks the client to pull the diagnostics again, e.g. when the configuration changes
+pub(crate) enum WorkspaceDiagnosticRefresh {}
+
+impl lsp::request::Request for WorkspaceDiagnosticRefresh {
+    type Params = ();
+    type Result = ();
+    const METHOD: &'static str = "workspace/diagnostic/refresh";
+}
+
+/// Returns the diagnostics of an opened document, or an unchanged report when neither the
+/// document nor the settings changed since the previous report
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn document_diagnostic(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> Result<DocumentDiagnosticReport> {
+    session.start_pulling_diagnostics().await;
+
+    let url = params.text_document.uri;
+    let doc = session.document(&url)?;
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        path: session.file_path(&url)?,
+    })?;
+
+    let result_id = session.diagnostics_result_id(&content);
+    if params.previous_result_id.as_ref() == Some(&result_id) {
+        return Ok(DocumentDiagnosticReport::Unchanged { result_id });
+    }
+
+    let items = session.compute_diagnostics(&url, &doc.line_index)?;
+    Ok(DocumentDiagnosticReport::Full { result_id, items })
+}
+
+/// Returns the diagnostics of the files of the project. The files that didn't change since
+/// their previous report get an unchanged report, without being checked again.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn workspace_diagnostic(
+    session: &SessionHandle,
+    params: WorkspaceDiagnosticParams,
+) -> Result<WorkspaceDiagnosticReport> {
+    session.start_pulling_diagnostics().await;
+
+    let mut previous_result_ids: FxHashMap<_, _> = params
+        .previous_result_ids
+        .into_iter()
+        .map(|previous_result_id| (previous_result_id.uri, previous_result_id.value))
+        .collect();
+
+    let mut items = Vec::new();
+    for path in session.project_files().iter() {
+        let Ok(url) = lsp::Url::from_file_path(path) else {
+            continue;
+        };
+        let previous_result_id = previous_result_ids.remove(&url);
+
+        // The files are checked one by one in the background: when the client cancels the
+        // request, this future is dropped and the remaining files aren't checked
+        let task_session = session.clone();
+        let task_path = path.clone();
+        let report = spawn_blocking(move || {
+            file_report(&task_session, &task_path, &url, previous_result_id)
+        })
+        .await?;
+
+        match report {
+            Ok(Some(report)) => items.push(report),
+            Ok(None) => {}
+            Err(err) => error!("Couldn't check {}: {err}", path.display()),
+        }
+    }
+
+    Ok(WorkspaceDiagnosticReport { items })
+}
+
+/// Returns the report of a file of the project, or [None] if the linter ignores it. The opened
+/// documents are checked with their content in the editor, and the other files are opened in the
+/// workspace only to check them, see [Session::with_unopened_file]. The files that didn't change since they were last read aren't
+/// read again if their previous report is still valid.
+fn file_report(
+    session: &Session,
+    path: &Path,
+    url: &lsp::Url,
+    previous_result_id: Option<String>,
+) -> Result<Option<WorkspaceDocumentDiagnosticReport>> {
+    let rome_path = session.file_path(url)?;
+    let is_ignored = session.workspace.is_path_ignored(IsPathIgnoredParams {
+        rome_path: rome_path.clone(),
+        feature: FeatureName::Lint,
+    })?;
+    if is_ignored {
+        return Ok(None);
+    }
+
+    let doc = session.document(url).ok();
+    if doc.is_none() {
+        if let Some(result_id) = session.cached_diagnostics_result_id(path) {
+            if previous_result_id.as_ref() == Some(&result_id) {
+                return Ok(Some(WorkspaceDocumentDiagnosticReport {
+                    uri: url.clone(),
+                    version: None,
+                    report: DocumentDiagnosticReport::Unchanged { result_id },
+                }));
+            }
+        }
+    }
+
+    let (content, result_id) = if doc.is_some() {
+        let content = session.workspace.get_file_content(GetFileContentParams {
+            path: rome_path.clone(),
+        })?;
+        let result_id = session.diagnostics_result_id(&content);
+        (content, result_id)
+    } else {
+        let mut content = String::new();
+        session.fs.open(path)?.read_to_string(&mut content)?;
+        let result_id = session.file_diagnostics_result_id(path, &content);
+        (content, result_id)
+    };
+
+    let version = doc.as_ref().map(|doc| doc.version);
+    if previous_result_id.as_ref() == Some(&result_id) {
+        return Ok(Some(WorkspaceDocumentDiagnosticReport {
+            uri: url.clone(),
+            version,
+            report: DocumentDiagnosticReport::Unchanged { result_id },
+        }));
+    }
+
+    let items = match doc {
+        Some(doc) => session.compute_diagnostics(url, &doc.line_index)?,
+        None => {
+            let line_index = LineIndex::new(&content);
+            match session.with_unopened_file(url, content, || {
+                session.compute_diagnostics(url, &line_index)
+            })? {
+                Some(items) => items?,
+                // The client opened the document in the meantime
+                None => return file_report(session, path, url, previous_result_id),
+            }
+        }
+    };
+
+    Ok(Some(WorkspaceDocumentDiagnosticReport {
+        uri: url.clone(),
+        version,
+        report: DocumentDiagnosticReport::Full { result_id, items },
+    }))
+}
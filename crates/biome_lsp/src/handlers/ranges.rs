@@ -0,0 +1,168 @@
+use crate::converters::{from_proto, to_proto};
+use crate::session::Session;
+use anyhow::{Context, Result};
+use biome_service::workspace::{
+    FoldingRangeKind, FoldingRangesParams, LinkedEditingRangesParams, SelectionRangesParams,
+};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types as lsp;
+
+/// Returns the lines of the document that can be folded: the blocks, the literals, the JSX
+/// elements, the groups of imports, the comments and the regions
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_range(
+    session: &Session,
+    params: lsp::FoldingRangeParams,
+) -> Result<Option<Vec<lsp::FoldingRange>>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let ranges = match session
+        .workspace
+        .folding_ranges(FoldingRangesParams { path: rome_path })
+    {
+        Ok(result) => result.ranges,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut folding_ranges = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        let start_line =
+            to_proto::position(&doc.line_index, range.range.start(), position_encoding)?.line;
+        let end_line =
+            to_proto::position(&doc.line_index, range.range.end(), position_encoding)?.line;
+        // The line of the closing delimiter stays visible when the block is folded
+        let end_line = if range.kind == FoldingRangeKind::Block {
+            end_line.saturating_sub(1)
+        } else {
+            end_line
+        };
+        if end_line <= start_line {
+            continue;
+        }
+
+        folding_ranges.push(lsp::FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind: match range.kind {
+                FoldingRangeKind::Block => None,
+                FoldingRangeKind::Comment => Some(lsp::FoldingRangeKind::Comment),
+                FoldingRangeKind::Imports => Some(lsp::FoldingRangeKind::Imports),
+                FoldingRangeKind::Region => Some(lsp::FoldingRangeKind::Region),
+            },
+            collapsed_text: None,
+        });
+    }
+
+    // Different ranges can fold the same lines, e.g. nested blocks that start on the same line
+    folding_ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    folding_ranges.dedup_by_key(|range| (range.start_line, range.end_line));
+
+    Ok(Some(folding_ranges))
+}
+
+/// Returns, for each position, the ranges that the selection goes through when it's expanded:
+/// the token at the position, then the nodes that contain it up to the whole document
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn selection_range(
+    session: &Session,
+    params: lsp::SelectionRangeParams,
+) -> Result<Option<Vec<lsp::SelectionRange>>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let offsets = params
+        .positions
+        .iter()
+        .map(|position| {
+            from_proto::offset(&doc.line_index, *position, position_encoding).with_context(|| {
+                format!("failed to access position {position:?} in document {url}")
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let ranges = match session.workspace.selection_ranges(SelectionRangesParams {
+        path: rome_path,
+        offsets,
+    }) {
+        Ok(result) => result.ranges,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut selection_ranges = Vec::with_capacity(params.positions.len());
+    for (position, ranges) in params.positions.into_iter().zip(ranges) {
+        let mut selection_range = None;
+        for range in ranges.into_iter().rev() {
+            selection_range = Some(lsp::SelectionRange {
+                range: to_proto::range(&doc.line_index, range, position_encoding)?,
+                parent: selection_range.map(Box::new),
+            });
+        }
+        // The response has one selection range for each position, even when there is nothing
+        // to select at the position
+        selection_ranges.push(selection_range.unwrap_or(lsp::SelectionRange {
+            range: lsp::Range::new(position, position),
+            parent: None,
+        }));
+    }
+
+    Ok(Some(selection_ranges))
+}
+
+/// The names of the JSX tags, including the member names (`Foo.Bar`) and the namespaced names
+/// (`svg:rect`). Typing a character that doesn't match ends the linked editing.
+const JSX_TAG_NAME_PATTERN: &str = r"[\w$-]+(?:[.:][\w$-]+)*";
+
+/// Returns the names of the opening and the closing tags of the JSX element under the cursor,
+/// so renaming one of them renames the other
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn linked_editing_range(
+    session: &Session,
+    params: lsp::LinkedEditingRangeParams,
+) -> Result<Option<lsp::LinkedEditingRanges>> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let ranges = match session
+        .workspace
+        .linked_editing_ranges(LinkedEditingRangesParams {
+            path: rome_path,
+            offset,
+        }) {
+        Ok(result) => result.ranges,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if ranges.is_empty() {
+        return Ok(None);
+    }
+
+    let ranges = ranges
+        .into_iter()
+        .map(|range| to_proto::range(&doc.line_index, range, position_encoding))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(lsp::LinkedEditingRanges {
+        ranges,
+        word_pattern: Some(String::from(JSX_TAG_NAME_PATTERN)),
+    }))
+}
@@ -1,11 +1,63 @@
 use std::collections::HashMap;
+use std::path::Path;
 
-use crate::converters::from_proto;
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto};
 use crate::{session::Session, utils};
 use anyhow::{Context, Result};
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use biome_fs::{FileSystemExt, RomePath};
+use biome_service::workspace::{FindReferencesParams, Language, RenameImportsParams};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    self as lsp, PrepareRenameResponse, RenameParams, TextDocumentPositionParams, WorkspaceEdit,
+};
 use tracing::trace;
 
+/// Checks that the cursor is on a binding that can be renamed, and returns the range of its
+/// identifier
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, params.position, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access position {:?} in document {url}",
+                params.position
+            )
+        })?;
+
+    let references = match session.workspace.find_references(FindReferencesParams {
+        path: rome_path,
+        offset,
+    }) {
+        Ok(result) => result.references,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // The identifiers without binding, e.g. the globals, can't be renamed
+    let Some(reference) = references
+        .into_iter()
+        .find(|reference| reference.range.contains_inclusive(offset))
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(PrepareRenameResponse::Range(to_proto::range(
+        &doc.line_index,
+        reference.range,
+        position_encoding,
+    )?)))
+}
+
 #[tracing::instrument(level = "debug", skip(session), err)]
 pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
     let url = params.text_document_position.text_document.uri;
@@ -30,9 +82,9 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
     let result = session
         .workspace
         .rename(biome_service::workspace::RenameParams {
-            path: rome_path,
+            path: rome_path.clone(),
             symbol_at: cursor_range,
-            new_name: params.new_name,
+            new_name: params.new_name.clone(),
         })?;
 
     let mut changes = HashMap::new();
@@ -41,6 +93,15 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
         utils::text_edit(&doc.line_index, result.indels, position_encoding)?,
     );
 
+    if let Some(exported_name) = &result.exported_name {
+        changes.extend(rename_imports(
+            session,
+            &rome_path,
+            exported_name,
+            &params.new_name,
+        )?);
+    }
+
     let workspace_edit = WorkspaceEdit {
         changes: Some(changes),
         document_changes: None,
@@ -49,3 +110,86 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
 
     Ok(Some(workspace_edit))
 }
+
+/// Updates the imports and the re-exports of a renamed export in the other files of the project
+fn rename_imports(
+    session: &Session,
+    module_path: &RomePath,
+    exported_name: &str,
+    new_name: &str,
+) -> Result<Vec<(lsp::Url, Vec<lsp::TextEdit>)>> {
+    let position_encoding = session.position_encoding();
+
+    let mut changes = Vec::new();
+    for path in session.project_files().iter() {
+        if path.as_path() == module_path.as_path() || !imports_modules(path) {
+            continue;
+        }
+        let Ok(url) = lsp::Url::from_file_path(path) else {
+            continue;
+        };
+        let rome_path = session.file_path(&url)?;
+
+        let rename = || {
+            session.workspace.rename_imports(RenameImportsParams {
+                path: rome_path.clone(),
+                module_path: module_path.clone(),
+                exported_name: exported_name.to_string(),
+                new_name: new_name.to_string(),
+            })
+        };
+
+        // The documents opened in the editor are already in the workspace, with their unsaved
+        // changes. The other files are opened only for the rename.
+        let (line_index, result) = match session.document(&url) {
+            Ok(doc) => (doc.line_index, rename()),
+            Err(_) => {
+                let mut content = String::new();
+                let read = session
+                    .fs
+                    .open(path)
+                    .and_then(|mut file| file.read_to_string(&mut content));
+                if read.is_err() {
+                    continue;
+                }
+                if !content.contains(exported_name) {
+                    continue;
+                }
+                let line_index = LineIndex::new(&content);
+                match session.with_unopened_file(&url, content, rename)? {
+                    Some(result) => (line_index, result),
+                    // The client opened the document in the meantime
+                    None => (session.document(&url)?.line_index, rename()),
+                }
+            }
+        };
+
+        let result = match result {
+            Ok(result) => result,
+            Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+                continue
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if result.range.is_empty() {
+            continue;
+        }
+        changes.push((
+            url,
+            utils::text_edit(&line_index, result.indels, position_encoding)?,
+        ));
+    }
+
+    Ok(changes)
+}
+
+/// Returns `true` if the file is a JavaScript or a TypeScript file, which can import a module
+fn imports_modules(path: &Path) -> bool {
+    matches!(
+        Language::from_path(path),
+        Language::JavaScript
+            | Language::JavaScriptReact
+            | Language::TypeScript
+            | Language::TypeScriptReact
+    )
+}
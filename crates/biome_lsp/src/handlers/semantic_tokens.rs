@@ -0,0 +1,160 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto, PositionEncoding};
+use crate::session::Session;
+use anyhow::Result;
+use biome_rowan::TextRange;
+use biome_service::workspace::{
+    SemanticToken, SemanticTokenKind, SemanticTokenModifier, SemanticTokensParams,
+};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types as lsp;
+
+/// The types of the tokens, in the order of [SemanticTokenKind]
+const TOKEN_TYPES: [lsp::SemanticTokenType; 10] = [
+    lsp::SemanticTokenType::VARIABLE,
+    lsp::SemanticTokenType::PARAMETER,
+    lsp::SemanticTokenType::FUNCTION,
+    lsp::SemanticTokenType::CLASS,
+    lsp::SemanticTokenType::INTERFACE,
+    lsp::SemanticTokenType::TYPE,
+    lsp::SemanticTokenType::TYPE_PARAMETER,
+    lsp::SemanticTokenType::ENUM,
+    lsp::SemanticTokenType::ENUM_MEMBER,
+    lsp::SemanticTokenType::NAMESPACE,
+];
+
+/// The modifiers of the tokens, in the order of [SemanticTokenModifier]. The modifiers that
+/// aren't in the protocol can be styled by the themes and the settings of the editors.
+const TOKEN_MODIFIERS: [lsp::SemanticTokenModifier; 5] = [
+    lsp::SemanticTokenModifier::DECLARATION,
+    lsp::SemanticTokenModifier::READONLY,
+    lsp::SemanticTokenModifier::new("import"),
+    lsp::SemanticTokenModifier::new("global"),
+    lsp::SemanticTokenModifier::new("unused"),
+];
+
+/// The legend that the server sends with its capabilities, to decode the tokens
+pub(crate) fn legend() -> lsp::SemanticTokensLegend {
+    lsp::SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Returns the identifiers of the document classified with the semantic model
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full(
+    session: &Session,
+    params: lsp::SemanticTokensParams,
+) -> Result<Option<lsp::SemanticTokensResult>> {
+    let tokens = semantic_tokens(session, &params.text_document.uri, None)?;
+    Ok(tokens.map(lsp::SemanticTokensResult::Tokens))
+}
+
+/// Returns the identifiers of a range of the document, e.g. the visible lines, classified with
+/// the semantic model
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_range(
+    session: &Session,
+    params: lsp::SemanticTokensRangeParams,
+) -> Result<Option<lsp::SemanticTokensRangeResult>> {
+    let tokens = semantic_tokens(session, &params.text_document.uri, Some(params.range))?;
+    Ok(tokens.map(lsp::SemanticTokensRangeResult::Tokens))
+}
+
+fn semantic_tokens(
+    session: &Session,
+    url: &lsp::Url,
+    range: Option<lsp::Range>,
+) -> Result<Option<lsp::SemanticTokens>> {
+    let rome_path = session.file_path(url)?;
+    let doc = session.document(url)?;
+    let position_encoding = session.position_encoding();
+
+    let range = match range {
+        Some(range) => Some(TextRange::new(
+            from_proto::offset(&doc.line_index, range.start, position_encoding)?,
+            from_proto::offset(&doc.line_index, range.end, position_encoding)?,
+        )),
+        None => None,
+    };
+
+    let tokens = match session.workspace.semantic_tokens(SemanticTokensParams {
+        path: rome_path,
+        range,
+    }) {
+        Ok(result) => result.tokens,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(Some(lsp::SemanticTokens {
+        result_id: None,
+        data: encode_tokens(&doc.line_index, &tokens, position_encoding)?,
+    }))
+}
+
+/// Encodes the tokens relatively to each other, as the protocol requires: the line of a token
+/// is relative to the line of the previous token, and so is its start when they're on the same
+/// line
+fn encode_tokens(
+    line_index: &LineIndex,
+    tokens: &[SemanticToken],
+    position_encoding: PositionEncoding,
+) -> Result<Vec<lsp::SemanticToken>> {
+    let mut encoded_tokens = Vec::with_capacity(tokens.len());
+    let mut previous_start = lsp::Position::new(0, 0);
+    for token in tokens {
+        let lsp::Range { start, end } =
+            to_proto::range(line_index, token.range, position_encoding)?;
+        // The identifiers are on a single line, and the clients may not support multi-line tokens
+        if start.line != end.line {
+            continue;
+        }
+
+        let delta_line = start.line - previous_start.line;
+        let delta_start = if delta_line == 0 {
+            start.character - previous_start.character
+        } else {
+            start.character
+        };
+        encoded_tokens.push(lsp::SemanticToken {
+            delta_line,
+            delta_start,
+            length: end.character - start.character,
+            token_type: token_type(token.kind),
+            token_modifiers_bitset: token.modifiers.iter().fold(0, |bitset, modifier| {
+                bitset | 1 << token_modifier(*modifier)
+            }),
+        });
+        previous_start = start;
+    }
+    Ok(encoded_tokens)
+}
+
+fn token_type(kind: SemanticTokenKind) -> u32 {
+    match kind {
+        SemanticTokenKind::Variable => 0,
+        SemanticTokenKind::Parameter => 1,
+        SemanticTokenKind::Function => 2,
+        SemanticTokenKind::Class => 3,
+        SemanticTokenKind::Interface => 4,
+        SemanticTokenKind::Type => 5,
+        SemanticTokenKind::TypeParameter => 6,
+        SemanticTokenKind::Enum => 7,
+        SemanticTokenKind::EnumMember => 8,
+        SemanticTokenKind::Namespace => 9,
+    }
+}
+
+fn token_modifier(modifier: SemanticTokenModifier) -> u32 {
+    match modifier {
+        SemanticTokenModifier::Declaration => 0,
+        SemanticTokenModifier::Readonly => 1,
+        SemanticTokenModifier::Import => 2,
+        SemanticTokenModifier::Global => 3,
+        SemanticTokenModifier::Unused => 4,
+    }
+}
@@ -0,0 +1,238 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{to_proto, PositionEncoding};
+use crate::session::Session;
+use crate::symbol_index::IndexedSymbol;
+use anyhow::Result;
+use biome_fs::FileSystemExt;
+use biome_service::workspace::{
+    DocumentSymbol, DocumentSymbolsParams, GetFileContentParams, SymbolKind,
+};
+use biome_service::WorkspaceError;
+use std::path::Path;
+use std::sync::Arc;
+use tower_lsp::lsp_types as lsp;
+use tracing::error;
+
+/// The maximum number of symbols returned for a query of the workspace symbols
+const MAX_WORKSPACE_SYMBOLS: usize = 256;
+
+/// Returns the symbols declared in a document, for the outline of the editor
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_symbol(
+    session: &Session,
+    params: lsp::DocumentSymbolParams,
+) -> Result<Option<lsp::DocumentSymbolResponse>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let symbols = match session
+        .workspace
+        .document_symbols(DocumentSymbolsParams { path: rome_path })
+    {
+        Ok(result) => result.symbols,
+        Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let symbols = symbols
+        .into_iter()
+        .map(|symbol| to_lsp_symbol(&doc.line_index, symbol, position_encoding))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(lsp::DocumentSymbolResponse::Nested(symbols)))
+}
+
+/// Returns the symbols of the files of the project and of the opened documents whose name
+/// matches the query. The characters of the query must appear in the name in the same order,
+/// ignoring the case, e.g. `gtu` matches `getUser`.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn workspace_symbol(
+    session: &Session,
+    params: lsp::WorkspaceSymbolParams,
+) -> Result<Option<Vec<lsp::SymbolInformation>>> {
+    let mut paths = session.project_files().to_vec();
+    paths.extend(
+        session
+            .document_urls()
+            .iter()
+            .filter_map(|url| url.to_file_path().ok()),
+    );
+    paths.sort();
+    paths.dedup();
+    session.symbol_index.retain(&paths);
+
+    let query = params.query.to_lowercase();
+    let mut symbols = Vec::new();
+    for path in paths {
+        let Ok(url) = lsp::Url::from_file_path(&path) else {
+            continue;
+        };
+        let file_symbols = match file_symbols(session, &path, &url) {
+            Ok(file_symbols) => file_symbols,
+            Err(err) => {
+                error!("Couldn't index the symbols of {}: {err}", path.display());
+                continue;
+            }
+        };
+
+        for symbol in file_symbols.iter() {
+            if !matches_query(&symbol.name, &query) {
+                continue;
+            }
+            #[allow(deprecated)]
+            symbols.push(lsp::SymbolInformation {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                location: lsp::Location {
+                    uri: url.clone(),
+                    range: symbol.range,
+                },
+                container_name: symbol.container_name.clone(),
+            });
+            if symbols.len() == MAX_WORKSPACE_SYMBOLS {
+                return Ok(Some(symbols));
+            }
+        }
+    }
+
+    Ok(Some(symbols))
+}
+
+/// Returns the symbols of a file from the index of the session, and indexes them when the
+/// file isn't indexed, e.g. because it changed. The files that aren't opened in the editor are
+/// read and opened in the workspace only to compute their symbols.
+fn file_symbols(session: &Session, path: &Path, url: &lsp::Url) -> Result<Arc<Vec<IndexedSymbol>>> {
+    session.symbol_index.symbols(path, || {
+        let rome_path = session.file_path(url)?;
+        let document_symbols = || {
+            session.workspace.document_symbols(DocumentSymbolsParams {
+                path: rome_path.clone(),
+            })
+        };
+        let opened_content = || {
+            session.workspace.get_file_content(GetFileContentParams {
+                path: rome_path.clone(),
+            })
+        };
+
+        let (content, result) = if session.document(url).is_ok() {
+            (opened_content()?, document_symbols())
+        } else {
+            let mut content = String::new();
+            session.fs.open(path)?.read_to_string(&mut content)?;
+            match session.with_unopened_file(url, content.clone(), document_symbols)? {
+                Some(result) => (content, result),
+                // The client opened the document in the meantime
+                None => (opened_content()?, document_symbols()),
+            }
+        };
+
+        let symbols = match result {
+            Ok(result) => result.symbols,
+            Err(WorkspaceError::SourceFileNotSupported(_) | WorkspaceError::FileIgnored(_)) => {
+                Vec::new()
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let line_index = LineIndex::new(&content);
+        let mut indexed_symbols = Vec::new();
+        index_symbols(
+            &line_index,
+            symbols,
+            None,
+            session.position_encoding(),
+            &mut indexed_symbols,
+        )?;
+        Ok(indexed_symbols)
+    })
+}
+
+/// Flattens the symbols and their children in `indexed_symbols`
+fn index_symbols(
+    line_index: &LineIndex,
+    symbols: Vec<DocumentSymbol>,
+    container_name: Option<&str>,
+    position_encoding: PositionEncoding,
+    indexed_symbols: &mut Vec<IndexedSymbol>,
+) -> Result<()> {
+    for symbol in symbols {
+        indexed_symbols.push(IndexedSymbol {
+            name: symbol.name.clone(),
+            kind: symbol_kind(symbol.kind),
+            range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+            container_name: container_name.map(str::to_string),
+        });
+        index_symbols(
+            line_index,
+            symbol.children,
+            Some(&symbol.name),
+            position_encoding,
+            indexed_symbols,
+        )?;
+    }
+    Ok(())
+}
+
+fn matches_query(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .all(|query_char| name.any(|name_char| name_char == query_char))
+}
+
+#[allow(deprecated)]
+fn to_lsp_symbol(
+    line_index: &LineIndex,
+    symbol: DocumentSymbol,
+    position_encoding: PositionEncoding,
+) -> Result<lsp::DocumentSymbol> {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| to_lsp_symbol(line_index, child, position_encoding))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(lsp::DocumentSymbol {
+        name: symbol.name,
+        detail: None,
+        kind: symbol_kind(symbol.kind),
+        tags: None,
+        deprecated: None,
+        range: to_proto::range(line_index, symbol.range, position_encoding)?,
+        selection_range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+        children: (!children.is_empty()).then_some(children),
+    })
+}
+
+fn symbol_kind(kind: SymbolKind) -> lsp::SymbolKind {
+    match kind {
+        SymbolKind::Function => lsp::SymbolKind::FUNCTION,
+        SymbolKind::Class => lsp::SymbolKind::CLASS,
+        SymbolKind::Method => lsp::SymbolKind::METHOD,
+        SymbolKind::Property => lsp::SymbolKind::PROPERTY,
+        SymbolKind::Constructor => lsp::SymbolKind::CONSTRUCTOR,
+        SymbolKind::Interface => lsp::SymbolKind::INTERFACE,
+        SymbolKind::TypeAlias => lsp::SymbolKind::TYPE_PARAMETER,
+        SymbolKind::Enum => lsp::SymbolKind::ENUM,
+        SymbolKind::EnumMember => lsp::SymbolKind::ENUM_MEMBER,
+        SymbolKind::Namespace => lsp::SymbolKind::NAMESPACE,
+        SymbolKind::Variable => lsp::SymbolKind::VARIABLE,
+        SymbolKind::Constant => lsp::SymbolKind::CONSTANT,
+        // Like the CSS and the JSON language services of VS Code
+        SymbolKind::Rule => lsp::SymbolKind::CLASS,
+        SymbolKind::AtRule => lsp::SymbolKind::MODULE,
+        SymbolKind::Object => lsp::SymbolKind::MODULE,
+        SymbolKind::Array => lsp::SymbolKind::ARRAY,
+        SymbolKind::String => lsp::SymbolKind::STRING,
+        SymbolKind::Number => lsp::SymbolKind::NUMBER,
+        SymbolKind::Boolean => lsp::SymbolKind::BOOLEAN,
+        SymbolKind::Null => lsp::SymbolKind::NULL,
+    }
+}
@@ -1,7 +1,5 @@
 use anyhow::Result;
-use biome_service::workspace::{
-    ChangeFileParams, CloseFileParams, GetFileContentParams, Language, OpenFileParams,
-};
+use biome_service::workspace::{ChangeFileParams, GetFileContentParams, Language, OpenFileParams};
 use tower_lsp::lsp_types;
 use tracing::{error, field};
 
@@ -22,14 +20,17 @@ pub(crate) async fn did_open(
     let rome_path = session.file_path(&url)?;
     let doc = Document::new(version, &content);
 
-    session.workspace.open_file(OpenFileParams {
-        path: rome_path,
-        version,
-        content,
-        language_hint,
-    })?;
-
-    session.insert_document(url.clone(), doc);
+    session.open_document(
+        url.clone(),
+        doc,
+        OpenFileParams {
+            path: rome_path,
+            version,
+            content,
+            language_hint,
+        },
+    )?;
+    session.file_changed(&url);
 
     if let Err(err) = session.update_diagnostics(url).await {
         error!("Failed to update diagnostics: {}", err);
@@ -64,6 +65,7 @@ pub(crate) async fn did_change(
     tracing::trace!("new document: {:?}", text);
 
     session.insert_document(url.clone(), Document::new(version, &text));
+    session.file_changed(&url);
 
     session.workspace.change_file(ChangeFileParams {
         path: rome_path,
@@ -85,13 +87,10 @@ pub(crate) async fn did_close(
     params: lsp_types::DidCloseTextDocumentParams,
 ) -> Result<()> {
     let url = params.text_document.uri;
-    let rome_path = session.file_path(&url)?;
-
-    session
-        .workspace
-        .close_file(CloseFileParams { path: rome_path })?;
 
-    session.remove_document(&url);
+    session.close_document(&url)?;
+    // The content of the file is read from the disk again
+    session.file_changed(&url);
 
     let diagnostics = vec![];
     let version = None;
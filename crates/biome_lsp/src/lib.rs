@@ -7,6 +7,7 @@ mod handlers;
 mod requests;
 mod server;
 mod session;
+mod symbol_index;
 mod utils;
 
 pub use crate::extension_settings::WorkspaceSettings;
@@ -1,5 +1,9 @@
 use crate::capabilities::server_capabilities;
 use crate::diagnostics::{handle_lsp_error, LspError};
+use crate::handlers::pull_diagnostics::{
+    self, DocumentDiagnosticParams, DocumentDiagnosticReport, WorkspaceDiagnosticParams,
+    WorkspaceDiagnosticReport, DOCUMENT_DIAGNOSTIC_REQUEST, WORKSPACE_DIAGNOSTIC_REQUEST,
+};
 use crate::requests::syntax_tree::{SyntaxTreePayload, SYNTAX_TREE_REQUEST};
 use crate::session::{
     CapabilitySet, CapabilityStatus, ClientInformation, Session, SessionHandle, SessionKey,
@@ -67,6 +71,24 @@ impl LSPServer {
         requests::syntax_tree::syntax_tree(&self.session, &url).map_err(into_lsp_error)
     }
 
+    async fn document_diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReport> {
+        pull_diagnostics::document_diagnostic(&self.session, params)
+            .await
+            .map_err(into_lsp_error)
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> LspResult<WorkspaceDiagnosticReport> {
+        pull_diagnostics::workspace_diagnostic(&self.session, params)
+            .await
+            .map_err(into_lsp_error)
+    }
+
     #[tracing::instrument(skip(self), name = "biome/rage", level = "debug")]
     async fn rage(&self, params: RageParams) -> LspResult<RageResult> {
         let mut entries = vec![
@@ -148,6 +170,14 @@ impl LSPServer {
                                 base_path.display()
                             )),
                             kind: Some(WatchKind::all()),
+                        },
+                        // Keeps the list of the files of the project and their symbols up to date
+                        FileSystemWatcher {
+                            glob_pattern: GlobPattern::String(format!(
+                                "{}/**/*",
+                                base_path.display()
+                            )),
+                            kind: Some(WatchKind::all()),
                         }
                     ],
                 })))
@@ -188,22 +218,23 @@ impl LSPServer {
             },
         );
 
-        let rename = {
-            let config = self.session.extension_settings.read().ok();
-            config.and_then(|x| x.settings.rename).unwrap_or(false)
-        };
+        self.session.register_capabilities(capabilities).await;
 
-        capabilities.add_capability(
-            "biome_rename",
-            "textDocument/rename",
-            if rename {
-                CapabilityStatus::Enable(None)
-            } else {
+        // The clients that don't support the pull diagnostics may reject their registration, so
+        // it's sent on its own. The diagnostics are published until the client pulls them.
+        let mut pull_diagnostics_capabilities = CapabilitySet::default();
+        pull_diagnostics_capabilities.add_capability(
+            "biome_pull_diagnostics",
+            DOCUMENT_DIAGNOSTIC_REQUEST,
+            if self.session.is_linting_and_formatting_disabled() {
                 CapabilityStatus::Disable
+            } else {
+                CapabilityStatus::Enable(Some(pull_diagnostics::registration_options()))
             },
         );
-
-        self.session.register_capabilities(capabilities).await;
+        self.session
+            .register_capabilities(pull_diagnostics_capabilities)
+            .await;
     }
 
     async fn map_op_error<T>(
@@ -304,10 +335,18 @@ impl LanguageServer for LSPServer {
         self.session.load_extension_settings().await;
         self.setup_capabilities().await;
         self.session.update_all_diagnostics().await;
+        self.session.refresh_code_lenses_and_inlay_hints().await;
     }
 
     #[tracing::instrument(level = "trace", skip(self))]
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in &params.changes {
+            if change.typ != FileChangeType::CHANGED {
+                self.session.clear_project_files();
+            }
+            self.session.file_changed(&change.uri);
+        }
+
         let file_paths = params
             .changes
             .iter()
@@ -325,6 +364,7 @@ impl LanguageServer for LSPServer {
                                 self.session.load_workspace_settings().await;
                                 self.setup_capabilities().await;
                                 self.session.update_all_diagnostics().await;
+                                self.session.refresh_code_lenses_and_inlay_hints().await;
                                 // for now we are only interested to the configuration file,
                                 // so it's OK to exist the loop
                                 break;
@@ -398,21 +438,155 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::rename::prepare_rename(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
         biome_diagnostics::panic::catch_unwind(move || {
-            let rename_enabled = self
-                .session
-                .extension_settings
-                .read()
-                .ok()
-                .and_then(|config| config.settings.rename)
-                .unwrap_or(false);
-
-            if rename_enabled {
-                handlers::rename::rename(&self.session, params).map_err(into_lsp_error)
-            } else {
-                Ok(None)
-            }
+            handlers::rename::rename(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::hover::hover(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::goto_definition(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::references(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> LspResult<Option<Vec<DocumentHighlight>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::document_highlight(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::document_symbol(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LspResult<Option<Vec<SymbolInformation>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::workspace_symbol(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::folding_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LspResult<Option<Vec<SelectionRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::selection_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn linked_editing_range(
+        &self,
+        params: LinkedEditingRangeParams,
+    ) -> LspResult<Option<LinkedEditingRanges>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::linked_editing_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> LspResult<Option<SemanticTokensResult>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> LspResult<Option<SemanticTokensRangeResult>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_range(&self.session, params)
+                .map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> LspResult<Option<Vec<CodeLens>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::code_lens::code_lens(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> LspResult<Option<serde_json::Value>> {
+        handlers::code_lens::execute_command(&self.session, params)
+            .await
+            .map_err(into_lsp_error)
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LspResult<Option<Vec<InlayHint>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::inlay_hints::inlay_hint(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn completion(&self, params: CompletionParams) -> LspResult<Option<CompletionResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::completion::completion(&self.session, params).map_err(into_lsp_error)
         })
         .map_err(into_lsp_error)?
     }
@@ -562,6 +736,13 @@ impl ServerFactory {
 
         builder = builder.custom_method("biome/rage", LSPServer::rage);
 
+        builder =
+            builder.custom_method(DOCUMENT_DIAGNOSTIC_REQUEST, LSPServer::document_diagnostic);
+        builder = builder.custom_method(
+            WORKSPACE_DIAGNOSTIC_REQUEST,
+            LSPServer::workspace_diagnostic,
+        );
+
         workspace_method!(builder, file_features);
         workspace_method!(builder, project_features);
         workspace_method!(builder, is_path_ignored);
@@ -581,7 +762,20 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, rename_imports);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, search_pattern);
+        workspace_method!(builder, hover);
+        workspace_method!(builder, goto_definition);
+        workspace_method!(builder, find_references);
+        workspace_method!(builder, document_symbols);
+        workspace_method!(builder, folding_ranges);
+        workspace_method!(builder, selection_ranges);
+        workspace_method!(builder, semantic_tokens);
+        workspace_method!(builder, suppressions);
+        workspace_method!(builder, cognitive_complexity);
+        workspace_method!(builder, completions);
+        workspace_method!(builder, linked_editing_ranges);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
@@ -1,29 +1,34 @@
+use crate::converters::line_index::LineIndex;
 use crate::converters::{negotiated_encoding, PositionEncoding, WideEncoding};
 use crate::documents::Document;
 use crate::extension_settings::ExtensionSettings;
 use crate::extension_settings::CONFIGURATION_SECTION;
+use crate::handlers::pull_diagnostics::WorkspaceDiagnosticRefresh;
+use crate::symbol_index::SymbolIndex;
 use crate::utils;
 use anyhow::Result;
 use biome_analyze::RuleCategories;
 use biome_console::markup;
-use biome_diagnostics::PrintDescription;
-use biome_fs::{FileSystem, RomePath};
+use biome_diagnostics::{Error, PrintDescription};
+use biome_fs::{FileSystem, PathInterner, RomePath, TraversalContext};
 use biome_service::configuration::{load_configuration, LoadedConfiguration};
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, PullDiagnosticsParams, SupportsFeatureParams,
+    CloseFileParams, FeatureName, FeaturesBuilder, IsPathIgnoredParams, Language, OpenFileParams,
+    PullDiagnosticsParams, SupportsFeatureParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use biome_service::{ConfigurationBasePath, Workspace};
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHasher};
 use serde_json::Value;
-use std::path::PathBuf;
-use std::sync::atomic::AtomicU8;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8};
 use std::sync::Arc;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 use tokio::sync::Notify;
 use tokio::sync::OnceCell;
 use tower_lsp::lsp_types;
@@ -65,6 +70,26 @@ pub(crate) struct Session {
     pub(crate) fs: DynRef<'static, dyn FileSystem>,
 
     documents: RwLock<FxHashMap<lsp_types::Url, Document>>,
+    /// Held while a document of the client is opened or closed in the workspace, and while a file
+    /// that isn't opened by the client is opened in the workspace to process it, so they don't
+    /// interleave
+    documents_sync: Mutex<()>,
+
+    /// The symbols of the files of the project, for the workspace symbols
+    pub(crate) symbol_index: SymbolIndex,
+
+    /// The files of the project that aren't ignored, listed on the first request that needs
+    /// them. The list is cleared when files are created or deleted, or when the settings change.
+    project_files: RwLock<Option<Arc<Vec<PathBuf>>>>,
+
+    /// Whether the client pulls the diagnostics, instead of the server publishing them
+    pulls_diagnostics: AtomicBool,
+    /// Incremented when the settings change, so the result ids of the pulled diagnostics change
+    /// even when the content of the files doesn't
+    diagnostics_generation: AtomicU64,
+    /// The hash of the content on disk of the files whose diagnostics were pulled, so the files
+    /// that didn't change aren't read again. The hash of a file is removed when it changes.
+    content_hashes: RwLock<FxHashMap<PathBuf, u64>>,
 
     pub(crate) cancellation: Arc<Notify>,
 
@@ -147,6 +172,12 @@ impl Session {
             workspace,
             configuration_status: AtomicU8::new(ConfigurationStatus::Missing as u8),
             documents,
+            documents_sync: Mutex::default(),
+            symbol_index: SymbolIndex::default(),
+            project_files: RwLock::default(),
+            pulls_diagnostics: AtomicBool::new(false),
+            diagnostics_generation: AtomicU64::new(0),
+            content_hashes: RwLock::default(),
             extension_settings: config,
             fs,
             cancellation,
@@ -246,9 +277,61 @@ impl Session {
         self.documents.write().unwrap().insert(url, document);
     }
 
-    /// Remove the [`Document`] matching the provided [`lsp_types::Url`]
-    pub(crate) fn remove_document(&self, url: &lsp_types::Url) {
+    /// Opens the [`Document`] of the client in the workspace, and sets it for the provided
+    /// [`lsp_types::Url`]
+    pub(crate) fn open_document(
+        &self,
+        url: lsp_types::Url,
+        document: Document,
+        params: OpenFileParams,
+    ) -> Result<(), WorkspaceError> {
+        let _sync = self.documents_sync.lock().unwrap();
+        self.workspace.open_file(params)?;
+        self.insert_document(url, document);
+        Ok(())
+    }
+
+    /// Closes the [`Document`] matching the provided [`lsp_types::Url`] in the workspace, and
+    /// removes it
+    pub(crate) fn close_document(&self, url: &lsp_types::Url) -> Result<()> {
+        let _sync = self.documents_sync.lock().unwrap();
+        let path = self.file_path(url)?;
+        self.workspace.close_file(CloseFileParams { path })?;
         self.documents.write().unwrap().remove(url);
+        Ok(())
+    }
+
+    /// Opens the file at `url` in the workspace with `content` while `func` runs, for a file of
+    /// the project that isn't opened by the client.
+    ///
+    /// The client can't open or close the document while `func` runs. Returns [None] without
+    /// running `func` if the document is opened by the client, its content is already in the
+    /// workspace then.
+    pub(crate) fn with_unopened_file<T>(
+        &self,
+        url: &lsp_types::Url,
+        content: String,
+        func: impl FnOnce() -> T,
+    ) -> Result<Option<T>> {
+        let _sync = self.documents_sync.lock().unwrap();
+        if self.documents.read().unwrap().contains_key(url) {
+            return Ok(None);
+        }
+        let path = self.file_path(url)?;
+        self.workspace.open_file(OpenFileParams {
+            path: path.clone(),
+            content,
+            version: 0,
+            language_hint: Language::default(),
+        })?;
+        let result = func();
+        self.workspace.close_file(CloseFileParams { path })?;
+        Ok(Some(result))
+    }
+
+    /// Returns the URLs of the documents opened in the client
+    pub(crate) fn document_urls(&self) -> Vec<lsp_types::Url> {
+        self.documents.read().unwrap().keys().cloned().collect()
     }
 
     pub(crate) fn file_path(&self, url: &lsp_types::Url) -> Result<RomePath> {
@@ -279,8 +362,30 @@ impl Session {
     /// contents changes.
     #[tracing::instrument(level = "trace", skip_all, fields(url = display(&url), diagnostic_count), err)]
     pub(crate) async fn update_diagnostics(&self, url: lsp_types::Url) -> Result<()> {
-        let rome_path = self.file_path(&url)?;
+        // The client pulls the diagnostics when it needs them
+        if self.pulls_diagnostics.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
         let doc = self.document(&url)?;
+        let diagnostics = self.compute_diagnostics(&url, &doc.line_index)?;
+
+        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Computes the diagnostics of a file that is opened in the workspace
+    pub(crate) fn compute_diagnostics(
+        &self,
+        url: &lsp_types::Url,
+        line_index: &LineIndex,
+    ) -> Result<Vec<lsp_types::Diagnostic>> {
+        let rome_path = self.file_path(url)?;
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             feature: FeaturesBuilder::new()
                 .with_linter()
@@ -310,6 +415,8 @@ impl Session {
                 path: rome_path,
                 categories,
                 max_diagnostics: u64::MAX,
+                only: Vec::new(),
+                skip: Vec::new(),
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
@@ -318,12 +425,7 @@ impl Session {
                 .diagnostics
                 .into_iter()
                 .filter_map(|d| {
-                    match utils::diagnostic_to_lsp(
-                        d,
-                        &url,
-                        &doc.line_index,
-                        self.position_encoding(),
-                    ) {
+                    match utils::diagnostic_to_lsp(d, url, line_index, self.position_encoding()) {
                         Ok(diag) => Some(diag),
                         Err(err) => {
                             tracing::error!("failed to convert diagnostic to LSP: {err:?}");
@@ -338,17 +440,24 @@ impl Session {
             result
         };
 
-        tracing::Span::current().record("diagnostic_count", diagnostics.len());
-
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
-            .await;
-
-        Ok(())
+        Ok(diagnostics)
     }
 
     /// Updates diagnostics for every [`Document`] in this [`Session`]
     pub(crate) async fn update_all_diagnostics(&self) {
+        // The settings changed, so the reports of the pulled diagnostics are stale
+        self.diagnostics_generation.fetch_add(1, Ordering::Relaxed);
+        if self.pulls_diagnostics.load(Ordering::Relaxed) {
+            if let Err(err) = self
+                .client
+                .send_request::<WorkspaceDiagnosticRefresh>(())
+                .await
+            {
+                error!("Failed to refresh the diagnostics: {err}");
+            }
+            return;
+        }
+
         let mut futures: FuturesUnordered<_> = self
             .documents
             .read()
@@ -364,6 +473,78 @@ impl Session {
         }
     }
 
+    /// Stops publishing the diagnostics, because the client pulls them. The published diagnostics
+    /// are cleared, so the client doesn't show them twice.
+    pub(crate) async fn start_pulling_diagnostics(&self) {
+        if self.pulls_diagnostics.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        for url in self.document_urls() {
+            self.client.publish_diagnostics(url, Vec::new(), None).await;
+        }
+    }
+
+    /// Returns the result id of the pulled diagnostics of a file. It changes when the content of
+    /// the file or the settings change.
+    pub(crate) fn diagnostics_result_id(&self, content: &str) -> String {
+        self.result_id_of_content_hash(content_hash(content))
+    }
+
+    /// Returns the result id of the pulled diagnostics of the file at `path` and remembers the
+    /// hash of its content, which was read from the disk
+    pub(crate) fn file_diagnostics_result_id(&self, path: &Path, content: &str) -> String {
+        let content_hash = content_hash(content);
+        self.content_hashes
+            .write()
+            .unwrap()
+            .insert(path.to_path_buf(), content_hash);
+        self.result_id_of_content_hash(content_hash)
+    }
+
+    /// Returns the result id of the pulled diagnostics of the file at `path` if its content
+    /// didn't change since it was last read from the disk
+    pub(crate) fn cached_diagnostics_result_id(&self, path: &Path) -> Option<String> {
+        let content_hash = *self.content_hashes.read().unwrap().get(path)?;
+        Some(self.result_id_of_content_hash(content_hash))
+    }
+
+    fn result_id_of_content_hash(&self, content_hash: u64) -> String {
+        let mut hasher = FxHasher::default();
+        self.diagnostics_generation
+            .load(Ordering::Relaxed)
+            .hash(&mut hasher);
+        content_hash.hash(&mut hasher);
+        format!("{:x}", hasher.finish())
+    }
+
+    /// Asks the client to request the code lenses and the inlay hints again, e.g. when the
+    /// settings change, if the client supports it
+    pub(crate) async fn refresh_code_lenses_and_inlay_hints(&self) {
+        let workspace = self
+            .initialize_params
+            .get()
+            .and_then(|params| params.client_capabilities.workspace.as_ref());
+        let refreshes_code_lenses = workspace
+            .and_then(|workspace| workspace.code_lens.as_ref())
+            .and_then(|code_lens| code_lens.refresh_support)
+            .unwrap_or(false);
+        let refreshes_inlay_hints = workspace
+            .and_then(|workspace| workspace.inlay_hint.as_ref())
+            .and_then(|inlay_hint| inlay_hint.refresh_support)
+            .unwrap_or(false);
+
+        if refreshes_code_lenses {
+            if let Err(err) = self.client.code_lens_refresh().await {
+                error!("Failed to refresh the code lenses: {err}");
+            }
+        }
+        if refreshes_inlay_hints {
+            if let Err(err) = self.client.inlay_hint_refresh().await {
+                error!("Failed to refresh the inlay hints: {err}");
+            }
+        }
+    }
+
     /// True if the client supports dynamic registration of "workspace/didChangeConfiguration" requests
     pub(crate) fn can_register_did_change_configuration(&self) -> bool {
         self.initialize_params
@@ -390,6 +571,92 @@ impl Session {
         }
     }
 
+    /// Returns the files of the workspace, sorted by path, whose language is supported by the
+    /// workspace and that aren't ignored by the settings. It's empty when the workspace doesn't
+    /// have a base path.
+    pub(crate) fn project_files(&self) -> Arc<Vec<PathBuf>> {
+        if let Some(files) = self.project_files.read().unwrap().as_ref() {
+            return files.clone();
+        }
+        let files = Arc::new(self.list_project_files());
+        *self.project_files.write().unwrap() = Some(files.clone());
+        files
+    }
+
+    /// Lists the files of the project again on the next request that needs them
+    pub(crate) fn clear_project_files(&self) {
+        *self.project_files.write().unwrap() = None;
+    }
+
+    /// Forgets what was computed from the content of the file at `url`, because it changed
+    pub(crate) fn file_changed(&self, url: &lsp_types::Url) {
+        if let Ok(path) = url.to_file_path() {
+            self.symbol_index.remove(&path);
+            self.content_hashes.write().unwrap().remove(&path);
+        }
+    }
+
+    fn list_project_files(&self) -> Vec<PathBuf> {
+        struct ProjectFiles<'a> {
+            interner: PathInterner,
+            workspace: &'a dyn Workspace,
+            files: Mutex<Vec<PathBuf>>,
+        }
+
+        impl TraversalContext for ProjectFiles<'_> {
+            fn interner(&self) -> &PathInterner {
+                &self.interner
+            }
+
+            fn push_diagnostic(&self, _: Error) {
+                // The files that can't be read are left out
+            }
+
+            fn can_handle(&self, rome_path: &RomePath) -> bool {
+                // A path is left out when all the features ignore it, like in the CLI
+                ![
+                    FeatureName::Format,
+                    FeatureName::Lint,
+                    FeatureName::OrganizeImports,
+                ]
+                .into_iter()
+                .all(|feature| {
+                    self.workspace
+                        .is_path_ignored(IsPathIgnoredParams {
+                            rome_path: rome_path.clone(),
+                            feature,
+                        })
+                        .unwrap_or_default()
+                })
+            }
+
+            fn handle_file(&self, path: &Path) {
+                if Language::from_path(path) != Language::Unknown {
+                    if let Ok(mut files) = self.files.lock() {
+                        files.push(path.to_path_buf());
+                    }
+                }
+            }
+        }
+
+        let Some(base_path) = self.base_path() else {
+            return Vec::new();
+        };
+        let (interner, _) = PathInterner::new();
+        let context = ProjectFiles {
+            interner,
+            workspace: self.workspace.as_ref(),
+            files: Mutex::default(),
+        };
+        self.fs.traversal(Box::new(|scope| {
+            scope.spawn(&context, base_path);
+        }));
+
+        let mut files = context.files.into_inner().unwrap_or_default();
+        files.sort();
+        files
+    }
+
     /// Returns a reference to the client information for this session
     pub(crate) fn client_information(&self) -> Option<&ClientInformation> {
         self.initialize_params.get()?.client_information.as_ref()
@@ -461,6 +728,9 @@ impl Session {
         };
 
         self.set_configuration_status(status);
+        // The ignored files and the symbols of the ignored files may have changed
+        self.clear_project_files();
+        self.symbol_index.clear();
     }
 
     /// Requests "workspace/configuration" from client and updates Session config
@@ -538,6 +808,13 @@ impl Session {
         }
     }
 
+    pub(crate) fn shows_complexity_hints(&self) -> bool {
+        self.extension_settings
+            .read()
+            .unwrap()
+            .shows_complexity_hints()
+    }
+
     pub fn position_encoding(&self) -> PositionEncoding {
         self.initialize_params
             .get()
@@ -545,3 +822,9 @@ impl Session {
             .unwrap_or(PositionEncoding::Wide(WideEncoding::Utf16))
     }
 }
+
+fn content_hash(content: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,58 @@
+use rustc_hash::FxHashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use tower_lsp::lsp_types as lsp;
+
+/// A symbol declared in a file of the project, e.g. a function or a CSS rule
+#[derive(Debug, Clone)]
+pub(crate) struct IndexedSymbol {
+    pub(crate) name: String,
+    pub(crate) kind: lsp::SymbolKind,
+    pub(crate) range: lsp::Range,
+    /// Name of the symbol that contains this symbol, e.g. the class of a method
+    pub(crate) container_name: Option<String>,
+}
+
+/// The symbols of the files of the project. The symbols of a file are computed on the first
+/// query, and again after the file is removed from the index because it changed.
+#[derive(Default)]
+pub(crate) struct SymbolIndex {
+    files: RwLock<FxHashMap<PathBuf, Arc<Vec<IndexedSymbol>>>>,
+}
+
+impl SymbolIndex {
+    /// Returns the symbols of the file at `path`. `compute` is called when the file isn't indexed.
+    pub(crate) fn symbols(
+        &self,
+        path: &Path,
+        compute: impl FnOnce() -> anyhow::Result<Vec<IndexedSymbol>>,
+    ) -> anyhow::Result<Arc<Vec<IndexedSymbol>>> {
+        if let Some(symbols) = self.files.read().unwrap().get(path) {
+            return Ok(symbols.clone());
+        }
+
+        let symbols = Arc::new(compute()?);
+        self.files
+            .write()
+            .unwrap()
+            .insert(path.to_path_buf(), symbols.clone());
+        Ok(symbols)
+    }
+
+    /// Removes the symbols of the file at `path`, e.g. when its content changes
+    pub(crate) fn remove(&self, path: &Path) {
+        self.files.write().unwrap().remove(path);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.files.write().unwrap().clear();
+    }
+
+    /// Removes the files that aren't in `paths` anymore, e.g. the deleted files
+    pub(crate) fn retain(&self, paths: &[PathBuf]) {
+        self.files
+            .write()
+            .unwrap()
+            .retain(|path, _| paths.binary_search(path).is_ok());
+    }
+}
@@ -9,7 +9,7 @@ use biome_lsp::WorkspaceSettings;
 use biome_service::workspace::GetSyntaxTreeResult;
 use biome_service::workspace::{GetFileContentParams, GetSyntaxTreeParams};
 use biome_service::DynRef;
-use futures::channel::mpsc::{channel, Sender};
+use futures::channel::mpsc::{channel, Receiver, Sender};
 use futures::Sink;
 use futures::SinkExt;
 use futures::Stream;
@@ -295,17 +295,29 @@ const CHANNEL_BUFFER_SIZE: usize = 8;
 #[derive(Debug, PartialEq, Eq)]
 enum ServerNotification {
     PublishDiagnostics(PublishDiagnosticsParams),
+    ApplyEdit(lsp::ApplyWorkspaceEditParams),
 }
 
 /// Basic handler for requests and notifications coming from the server for tests
-async fn client_handler<I, O>(
+async fn client_handler<I, O>(stream: I, sink: O, notify: Sender<ServerNotification>) -> Result<()>
+where
+    // This function has to be generic as `RequestStream` and `ResponseSink`
+    // are not exported from `tower_lsp` and cannot be named in the signature
+    I: Stream<Item = Request> + Unpin,
+    O: Sink<Response> + Unpin,
+{
+    client_handler_with_settings(stream, sink, notify, WorkspaceSettings::default()).await
+}
+
+/// Handler for requests and notifications coming from the server for tests, which sends the
+/// given extension settings to the server
+async fn client_handler_with_settings<I, O>(
     mut stream: I,
     mut sink: O,
     mut notify: Sender<ServerNotification>,
+    settings: WorkspaceSettings,
 ) -> Result<()>
 where
-    // This function has to be generic as `RequestStream` and `ResponseSink`
-    // are not exported from `tower_lsp` and cannot be named in the signature
     I: Stream<Item = Request> + Unpin,
     O: Sink<Response> + Unpin,
 {
@@ -327,14 +339,30 @@ where
 
         let res = match req.method() {
             "workspace/configuration" => {
-                let settings = WorkspaceSettings {
-                    ..WorkspaceSettings::default()
-                };
                 let result =
                     to_value(slice::from_ref(&settings)).context("failed to serialize settings")?;
 
                 Response::from_ok(id.clone(), result)
             }
+            "workspace/applyEdit" => {
+                let params = req.params().expect("invalid request");
+                let params = from_value(params.clone()).expect("invalid params");
+                if notify
+                    .send(ServerNotification::ApplyEdit(params))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+
+                let result = to_value(lsp::ApplyWorkspaceEditResponse {
+                    applied: true,
+                    failure_reason: None,
+                    failed_change: None,
+                })
+                .context("failed to serialize the response")?;
+                Response::from_ok(id.clone(), result)
+            }
             _ => Response::from_error(id.clone(), jsonrpc::Error::method_not_found()),
         };
 
@@ -1943,6 +1971,1115 @@ async fn does_not_format_ignored_files() -> Result<()> {
     Ok(())
 }
 
+fn hover_params(line: u32, character: u32) -> lsp::HoverParams {
+    lsp::HoverParams {
+        text_document_position_params: lsp::TextDocumentPositionParams {
+            text_document: lsp::TextDocumentIdentifier {
+                uri: url!("document.js"),
+            },
+            position: Position { line, character },
+        },
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+        },
+    }
+}
+
+#[tokio::test]
+async fn hover_shows_rule_documentation_and_binding() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("const value = 1;\ndebugger;\nvalue;\n")
+        .await?;
+
+    let hover: Option<lsp::Hover> = server
+        .request("textDocument/hover", "hover_diagnostic", hover_params(1, 3))
+        .await?
+        .context("hover returned None")?;
+    let hover = hover.context("no information on the diagnostic")?;
+    let lsp::HoverContents::Markup(content) = hover.contents else {
+        bail!("the hover content isn't Markdown");
+    };
+    assert_eq!(content.kind, lsp::MarkupKind::Markdown);
+    assert!(
+        content.value.starts_with(
+            "**[lint/suspicious/noDebugger](https://biomejs.dev/linter/rules/no-debugger)**"
+        ),
+        "{}",
+        content.value
+    );
+    assert!(
+        content
+            .value
+            .contains("Source: [`no-debugger`](https://eslint.org/docs/latest/rules/no-debugger)"),
+        "{}",
+        content.value
+    );
+    assert!(!content.value.contains("expect_diagnostic"));
+    assert_eq!(
+        hover.range,
+        Some(Range {
+            start: Position {
+                line: 1,
+                character: 0
+            },
+            end: Position {
+                line: 1,
+                character: 9
+            },
+        })
+    );
+
+    let hover: Option<lsp::Hover> = server
+        .request("textDocument/hover", "hover_binding", hover_params(2, 2))
+        .await?
+        .context("hover returned None")?;
+    let hover = hover.context("no information on the binding")?;
+    assert_eq!(
+        hover.contents,
+        lsp::HoverContents::Markup(lsp::MarkupContent {
+            kind: lsp::MarkupKind::Markdown,
+            value: String::from("```ts\nconst value\n```\n\nDeclared at line 1, column 7"),
+        })
+    );
+    assert_eq!(
+        hover.range,
+        Some(Range {
+            start: Position {
+                line: 2,
+                character: 0
+            },
+            end: Position {
+                line: 2,
+                character: 5
+            },
+        })
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+fn text_document_position(line: u32, character: u32) -> lsp::TextDocumentPositionParams {
+    lsp::TextDocumentPositionParams {
+        text_document: lsp::TextDocumentIdentifier {
+            uri: url!("document.js"),
+        },
+        position: Position { line, character },
+    }
+}
+
+fn line_range(line: u32, start: u32, end: u32) -> Range {
+    Range {
+        start: Position {
+            line,
+            character: start,
+        },
+        end: Position {
+            line,
+            character: end,
+        },
+    }
+}
+
+#[tokio::test]
+async fn goto_definition_and_references_of_a_binding() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("utils.ts").to_file_path().unwrap(),
+        "export function add(a, b) { return a + b; }",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("import { add } from './utils';\nlet total = add(1, 2);\ntotal += 1;\n")
+        .await?;
+
+    let definition: Option<lsp::GotoDefinitionResponse> = server
+        .request(
+            "textDocument/definition",
+            "definition_import",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: text_document_position(1, 13),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("definition returned None")?;
+    assert_eq!(
+        definition,
+        Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location {
+            uri: url!("document.js"),
+            range: line_range(0, 9, 12),
+        }))
+    );
+
+    let definition: Option<lsp::GotoDefinitionResponse> = server
+        .request(
+            "textDocument/definition",
+            "definition_module",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: text_document_position(0, 23),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("definition returned None")?;
+    assert_eq!(
+        definition,
+        Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location {
+            uri: url!("utils.ts"),
+            range: Range::default(),
+        }))
+    );
+
+    let references: Option<Vec<lsp::Location>> = server
+        .request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position: text_document_position(2, 0),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+                context: lsp::ReferenceContext {
+                    include_declaration: false,
+                },
+            },
+        )
+        .await?
+        .context("references returned None")?;
+    assert_eq!(
+        references,
+        Some(vec![lsp::Location {
+            uri: url!("document.js"),
+            range: line_range(2, 0, 5),
+        }])
+    );
+
+    let highlights: Option<Vec<lsp::DocumentHighlight>> = server
+        .request(
+            "textDocument/documentHighlight",
+            "document_highlight",
+            lsp::DocumentHighlightParams {
+                text_document_position_params: text_document_position(1, 13),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("documentHighlight returned None")?;
+    assert_eq!(
+        highlights,
+        Some(vec![
+            lsp::DocumentHighlight {
+                range: line_range(0, 9, 12),
+                kind: Some(lsp::DocumentHighlightKind::WRITE),
+            },
+            lsp::DocumentHighlight {
+                range: line_range(1, 12, 15),
+                kind: Some(lsp::DocumentHighlightKind::READ),
+            },
+        ])
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_updates_the_imports_of_the_other_files() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("document.js").to_file_path().unwrap(),
+        "export function add(a, b) {}\nadd(1, 2);\n",
+    );
+    fs.insert(
+        url!("src/main.js").to_file_path().unwrap(),
+        "import { add } from '../document';\nadd(1, 2);\n",
+    );
+    fs.insert(
+        url!("src/other.js").to_file_path().unwrap(),
+        "import { add } from './document';\n",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("export function add(a, b) {}\nadd(1, 2);\nconsole.log();\n")
+        .await?;
+
+    let response: Option<lsp::PrepareRenameResponse> = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            text_document_position(1, 1),
+        )
+        .await?
+        .context("prepareRename returned None")?;
+    assert_eq!(
+        response,
+        Some(lsp::PrepareRenameResponse::Range(line_range(1, 0, 3)))
+    );
+
+    let response: Option<lsp::PrepareRenameResponse> = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename_global",
+            text_document_position(2, 1),
+        )
+        .await?
+        .context("prepareRename returned None")?;
+    assert_eq!(response, None);
+
+    let edit: Option<lsp::WorkspaceEdit> = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            lsp::RenameParams {
+                text_document_position: text_document_position(0, 17),
+                new_name: String::from("sum"),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+    let changes = edit
+        .and_then(|edit| edit.changes)
+        .context("the rename has no changes")?;
+
+    let mut urls: Vec<_> = changes.keys().map(lsp::Url::as_str).collect();
+    urls.sort_unstable();
+    assert_eq!(
+        urls,
+        [url!("document.js").as_str(), url!("src/main.js").as_str()]
+    );
+    assert_eq!(
+        changes[&url!("src/main.js")],
+        [lsp::TextEdit {
+            range: line_range(0, 9, 9),
+            new_text: String::from("sum as "),
+        }]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn document_and_workspace_symbols() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("src/format.ts").to_file_path().unwrap(),
+        "export function formatDate() {}\nexport class DateFormatter {\n  format() {}\n}\n",
+    );
+    fs.insert(
+        url!("src/styles.css").to_file_path().unwrap(),
+        ".form { display: grid; }\n",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("class Form {\n  submit() {}\n}\n")
+        .await?;
+
+    let response: Option<lsp::DocumentSymbolResponse> = server
+        .request(
+            "textDocument/documentSymbol",
+            "document_symbol",
+            lsp::DocumentSymbolParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("documentSymbol returned None")?;
+    let Some(lsp::DocumentSymbolResponse::Nested(symbols)) = response else {
+        bail!("expected nested document symbols, got {response:?}");
+    };
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].name, "Form");
+    assert_eq!(symbols[0].kind, lsp::SymbolKind::CLASS);
+    assert_eq!(
+        symbols[0].range,
+        Range {
+            start: Position::new(0, 0),
+            end: Position::new(2, 1),
+        }
+    );
+    assert_eq!(symbols[0].selection_range, line_range(0, 6, 10));
+    let children = symbols[0].children.as_deref().unwrap_or_default();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].name, "submit");
+    assert_eq!(children[0].kind, lsp::SymbolKind::METHOD);
+
+    let symbols: Option<Vec<lsp::SymbolInformation>> = server
+        .request(
+            "workspace/symbol",
+            "workspace_symbol",
+            lsp::WorkspaceSymbolParams {
+                query: String::from("FORM"),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("workspace/symbol returned None")?;
+    let symbols: Vec<_> = symbols
+        .unwrap_or_default()
+        .into_iter()
+        .map(|symbol| {
+            (
+                symbol.name,
+                symbol.location.uri.to_string(),
+                symbol.container_name,
+            )
+        })
+        .collect();
+    assert_eq!(
+        symbols,
+        [
+            (String::from("Form"), url!("document.js").to_string(), None),
+            (
+                String::from("formatDate"),
+                url!("src/format.ts").to_string(),
+                None
+            ),
+            (
+                String::from("DateFormatter"),
+                url!("src/format.ts").to_string(),
+                None
+            ),
+            (
+                String::from("format"),
+                url!("src/format.ts").to_string(),
+                Some(String::from("DateFormatter"))
+            ),
+            (
+                String::from(".form"),
+                url!("src/styles.css").to_string(),
+                None
+            ),
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_symbols_skip_ignored_files() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("biome.json").to_file_path().unwrap(),
+        r#"{ "files": { "ignore": ["dist"] } }"#,
+    );
+    fs.insert(
+        url!("src/format.ts").to_file_path().unwrap(),
+        "export function formatDate() {}\n",
+    );
+    fs.insert(
+        url!("dist/format.js").to_file_path().unwrap(),
+        "export function formatDate() {}\n",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let symbols: Option<Vec<lsp::SymbolInformation>> = server
+        .request(
+            "workspace/symbol",
+            "workspace_symbol",
+            lsp::WorkspaceSymbolParams {
+                query: String::from("formatDate"),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("workspace/symbol returned None")?;
+    let urls: Vec<_> = symbols
+        .unwrap_or_default()
+        .into_iter()
+        .map(|symbol| symbol.location.uri.to_string())
+        .collect();
+    assert_eq!(urls, [url!("src/format.ts").to_string()]);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn folding_and_selection_ranges() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            "import a from \"a\";\nimport b from \"b\";\n\nfunction f() {\n  return [\n    1,\n  ];\n}",
+        )
+        .await?;
+
+    let folding_ranges: Option<Vec<lsp::FoldingRange>> = server
+        .request(
+            "textDocument/foldingRange",
+            "folding_range",
+            lsp::FoldingRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("foldingRange returned None")?;
+    let folding_ranges: Vec<_> = folding_ranges
+        .unwrap_or_default()
+        .into_iter()
+        .map(|range| (range.start_line, range.end_line, range.kind))
+        .collect();
+    // The closing delimiters stay visible, and `()` isn't folded because it's on a single line
+    assert_eq!(
+        folding_ranges,
+        [
+            (0, 1, Some(lsp::FoldingRangeKind::Imports)),
+            (3, 6, None),
+            (4, 5, None),
+        ]
+    );
+
+    let selection_ranges: Option<Vec<lsp::SelectionRange>> = server
+        .request(
+            "textDocument/selectionRange",
+            "selection_range",
+            lsp::SelectionRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                positions: vec![Position::new(5, 4)],
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("selectionRange returned None")?;
+    let selection_ranges = selection_ranges.unwrap_or_default();
+    assert_eq!(selection_ranges.len(), 1);
+
+    let mut ranges = Vec::new();
+    let mut selection_range = Some(&selection_ranges[0]);
+    while let Some(current) = selection_range {
+        ranges.push(current.range);
+        selection_range = current.parent.as_deref();
+    }
+    assert_eq!(ranges.first(), Some(&line_range(5, 4, 5)));
+    assert_eq!(
+        ranges.last(),
+        Some(&Range {
+            start: Position::new(0, 0),
+            end: Position::new(7, 1),
+        })
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn semantic_tokens() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("const a = 1;\nfoo(a);\n").await?;
+
+    let token =
+        |delta_line, delta_start, length, token_type, token_modifiers_bitset| lsp::SemanticToken {
+            delta_line,
+            delta_start,
+            length,
+            token_type,
+            token_modifiers_bitset,
+        };
+    // `a` is a readonly variable, and `foo` is a global
+    let declaration = token(0, 6, 1, 0, 0b00011);
+    let global = token(1, 0, 3, 0, 0b01000);
+    let reference = token(0, 4, 1, 0, 0b00010);
+
+    let result: Option<lsp::SemanticTokensResult> = server
+        .request(
+            "textDocument/semanticTokens/full",
+            "semantic_tokens_full",
+            lsp::SemanticTokensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("semanticTokens/full returned None")?;
+    let Some(lsp::SemanticTokensResult::Tokens(tokens)) = result else {
+        bail!("expected semantic tokens, got {result:?}");
+    };
+    assert_eq!(tokens.data, [declaration, global, reference]);
+
+    let result: Option<lsp::SemanticTokensRangeResult> = server
+        .request(
+            "textDocument/semanticTokens/range",
+            "semantic_tokens_range",
+            lsp::SemanticTokensRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: line_range(1, 0, 7),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("semanticTokens/range returned None")?;
+    let Some(lsp::SemanticTokensRangeResult::Tokens(tokens)) = result else {
+        bail!("expected semantic tokens, got {result:?}");
+    };
+    assert_eq!(tokens.data, [global, reference]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_document_and_workspace_diagnostics() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("biome.json").to_file_path().unwrap(),
+        r#"{ "linter": { "ignore": ["src/ignored.js"] } }"#,
+    );
+    fs.insert(url!("src/debug.js").to_file_path().unwrap(), "debugger;\n");
+    // The files ignored by the linter aren't reported
+    fs.insert(
+        url!("src/ignored.js").to_file_path().unwrap(),
+        "debugger;\n",
+    );
+    fs.insert(
+        url!("src/clean.js").to_file_path().unwrap(),
+        "export const clean = true;\n",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("debugger;\n").await?;
+
+    async fn next_published_diagnostics(
+        receiver: &mut Receiver<ServerNotification>,
+    ) -> Vec<lsp::Diagnostic> {
+        let notification = tokio::select! {
+            msg = receiver.next() => msg,
+            _ = sleep(Duration::from_secs(1)) => {
+                panic!("timed out waiting for the server to send diagnostics")
+            }
+        };
+        match notification {
+            Some(ServerNotification::PublishDiagnostics(params)) => params.diagnostics,
+            notification => panic!("unexpected notification {notification:?}"),
+        }
+    }
+    // The diagnostics are published until the client pulls them
+    assert_eq!(next_published_diagnostics(&mut receiver).await.len(), 1);
+
+    let report: Option<serde_json::Value> = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            serde_json::json!({ "textDocument": { "uri": url!("document.js") } }),
+        )
+        .await?;
+    let report = report.context("textDocument/diagnostic returned None")?;
+    assert_eq!(report["kind"], "full");
+    assert_eq!(report["items"].as_array().map(Vec::len), Some(1));
+    assert_eq!(report["items"][0]["code"], "lint/suspicious/noDebugger");
+
+    // The published diagnostics are cleared, so the editor doesn't show them twice
+    assert_eq!(next_published_diagnostics(&mut receiver).await, []);
+
+    let result_id = report["resultId"].clone();
+    let report: Option<serde_json::Value> = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            serde_json::json!({
+                "textDocument": { "uri": url!("document.js") },
+                "previousResultId": result_id,
+            }),
+        )
+        .await?;
+    let report = report.context("textDocument/diagnostic returned None")?;
+    assert_eq!(report["kind"], "unchanged");
+    assert_eq!(report["resultId"], result_id);
+
+    let report: Option<serde_json::Value> = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            serde_json::json!({ "previousResultIds": [] }),
+        )
+        .await?;
+    let report = report.context("workspace/diagnostic returned None")?;
+    let items = report["items"].as_array().cloned().unwrap_or_default();
+    let summary: Vec<_> = items
+        .iter()
+        .map(|item| {
+            (
+                item["uri"].as_str().unwrap_or_default().to_string(),
+                item["kind"].clone(),
+                item["version"].clone(),
+                item["items"].as_array().map(Vec::len),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (
+                url!("biome.json").to_string(),
+                serde_json::json!("full"),
+                serde_json::Value::Null,
+                Some(0)
+            ),
+            (
+                url!("src/clean.js").to_string(),
+                serde_json::json!("full"),
+                serde_json::Value::Null,
+                Some(0)
+            ),
+            (
+                url!("src/debug.js").to_string(),
+                serde_json::json!("full"),
+                serde_json::Value::Null,
+                Some(1)
+            ),
+        ]
+    );
+
+    // The files that didn't change since their previous report aren't checked again
+    let previous_result_ids: Vec<_> = items
+        .iter()
+        .map(|item| serde_json::json!({ "uri": item["uri"], "value": item["resultId"] }))
+        .collect();
+    let report: Option<serde_json::Value> = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            serde_json::json!({ "previousResultIds": previous_result_ids }),
+        )
+        .await?;
+    let report = report.context("workspace/diagnostic returned None")?;
+    let kinds: Vec<_> = report["items"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .iter()
+        .map(|item| item["kind"].clone())
+        .collect();
+    assert_eq!(kinds, ["unchanged", "unchanged", "unchanged"]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn code_lenses_and_inlay_hints_of_suppressions_and_complexity() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    let config = r#"{
+        "linter": {
+            "rules": {
+                "complexity": {
+                    "noExcessiveCognitiveComplexity": {
+                        "level": "error",
+                        "options": { "maxAllowedComplexity": 1 }
+                    }
+                }
+            }
+        }
+    }"#;
+    fs.insert(url!("biome.json").to_file_path().unwrap(), config);
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let settings = WorkspaceSettings {
+        complexity_hints: Some(true),
+        ..WorkspaceSettings::default()
+    };
+    let reader = tokio::spawn(client_handler_with_settings(stream, sink, sender, settings));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            r#"// biome-ignore lint/suspicious/noDebugger: debugging
+debugger;
+// biome-ignore lint/suspicious/noDebugger: the debugger was removed
+function check(a, b) {
+    if (a) {
+        if (b) {
+            return 1;
+        }
+    }
+    return 0;
+}
+"#,
+        )
+        .await?;
+
+    let code_lenses: Option<Vec<lsp::CodeLens>> = server
+        .request(
+            "textDocument/codeLens",
+            "code_lens",
+            lsp::CodeLensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?;
+    let code_lenses = code_lenses.context("textDocument/codeLens returned None")?;
+
+    let titles = code_lenses
+        .iter()
+        .map(|code_lens| {
+            let command = code_lens.command.as_ref().unwrap();
+            (
+                code_lens.range.start.line,
+                command.title.as_str(),
+                command.command.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        [
+            (0, "Suppression still needed", ""),
+            (2, "Suppression no longer needed", ""),
+            (2, "Remove suppression", "biome.removeSuppression"),
+            (3, "Cognitive complexity 3 (max 1)", ""),
+        ]
+    );
+
+    let inlay_hints: Option<Vec<lsp::InlayHint>> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range::new(Position::new(0, 0), Position::new(11, 0)),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?;
+    let inlay_hints = inlay_hints.context("textDocument/inlayHint returned None")?;
+    assert_eq!(inlay_hints.len(), 1);
+    assert_eq!(inlay_hints[0].position, Position::new(3, 14));
+    assert!(matches!(
+        &inlay_hints[0].label,
+        lsp::InlayHintLabel::String(label) if label == "complexity: 3"
+    ));
+
+    let remove_suppression = code_lenses[2].command.clone().unwrap();
+    let _: Option<serde_json::Value> = server
+        .request(
+            "workspace/executeCommand",
+            "execute_command",
+            lsp::ExecuteCommandParams {
+                command: remove_suppression.command,
+                arguments: remove_suppression.arguments.unwrap_or_default(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?;
+
+    // The server also publishes the diagnostics of the opened document
+    let params = loop {
+        let notification = tokio::select! {
+            msg = receiver.next() => msg,
+            _ = sleep(Duration::from_secs(1)) => {
+                panic!("timed out waiting for the server to apply the edit")
+            }
+        };
+        match notification {
+            Some(ServerNotification::ApplyEdit(params)) => break params,
+            Some(ServerNotification::PublishDiagnostics(_)) => continue,
+            None => panic!("the server didn't apply the edit"),
+        }
+    };
+    // The line of the comment is removed
+    assert_eq!(
+        params.edit.document_changes,
+        Some(lsp::DocumentChanges::Edits(vec![lsp::TextDocumentEdit {
+            text_document: lsp::OptionalVersionedTextDocumentIdentifier {
+                uri: url!("document.js"),
+                version: Some(0),
+            },
+            edits: vec![lsp::OneOf::Left(TextEdit {
+                range: Range::new(Position::new(2, 0), Position::new(3, 0)),
+                new_text: String::new(),
+            })],
+        }]))
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn completion_of_the_configuration_file() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(
+            "{\n  \"formatter\": {\n    \"indentStyle\": \"t\"\n  }\n}",
+            url!("biome.json"),
+            "json",
+        )
+        .await?;
+
+    let completion: Option<lsp::CompletionResponse> = server
+        .request(
+            "textDocument/completion",
+            "completion",
+            lsp::CompletionParams {
+                text_document_position: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("biome.json"),
+                    },
+                    position: Position::new(2, 21),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+                context: None,
+            },
+        )
+        .await?
+        .context("completion returned None")?;
+    let Some(lsp::CompletionResponse::Array(items)) = completion else {
+        panic!("unexpected completion response {completion:?}");
+    };
+
+    let items: Vec<_> = items
+        .into_iter()
+        .map(|item| (item.label, item.kind, item.text_edit))
+        .collect();
+    let text_edit = |new_text: &str| {
+        Some(lsp::CompletionTextEdit::Edit(TextEdit {
+            range: line_range(2, 19, 22),
+            new_text: new_text.to_string(),
+        }))
+    };
+    assert_eq!(
+        items,
+        [
+            (
+                String::from("space"),
+                Some(lsp::CompletionItemKind::VALUE),
+                text_edit("\"space\"")
+            ),
+            (
+                String::from("tab"),
+                Some(lsp::CompletionItemKind::VALUE),
+                text_edit("\"tab\"")
+            ),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn linked_editing_range_of_jsx_tags() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(
+            "<Foo.Bar>\n  <span />\n</Foo.Bar>;",
+            url!("document.jsx"),
+            "javascriptreact",
+        )
+        .await?;
+
+    let linked_editing_ranges: Option<lsp::LinkedEditingRanges> = server
+        .request(
+            "textDocument/linkedEditingRange",
+            "linked_editing_range",
+            lsp::LinkedEditingRangeParams {
+                text_document_position_params: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("document.jsx"),
+                    },
+                    position: Position::new(0, 5),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("linkedEditingRange returned None")?;
+    let linked_editing_ranges = linked_editing_ranges.context("the tags aren't linked")?;
+    assert_eq!(
+        linked_editing_ranges.ranges,
+        [line_range(0, 1, 8), line_range(2, 2, 9)]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn server_shutdown() -> Result<()> {
     let factory = ServerFactory::default();
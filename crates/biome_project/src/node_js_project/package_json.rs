@@ -28,12 +28,41 @@ impl Manifest for PackageJson {
     }
 }
 
+impl PackageJson {
+    /// Returns the version range of the dependency `name`, from the dependencies, the
+    /// development dependencies or the optional dependencies of the package
+    pub fn dependency(&self, name: &str) -> Option<&VersionRange> {
+        self.dependencies
+            .get(name)
+            .or_else(|| self.dev_dependencies.get(name))
+            .or_else(|| self.optional_dependencies.get(name))
+    }
+}
+
 #[derive(Debug, Default, biome_deserialize_macros::Deserializable)]
-pub struct Dependencies(FxHashMap<String, Version>);
+pub struct Dependencies(FxHashMap<String, VersionRange>);
+
+impl Dependencies {
+    pub fn get(&self, name: &str) -> Option<&VersionRange> {
+        self.0.get(name)
+    }
+}
 
 #[derive(Debug)]
 pub struct Version(node_semver::Version);
 
+/// The version of a dependency, e.g. `^18.2.0`
+#[derive(Debug)]
+pub struct VersionRange(node_semver::Range);
+
+impl VersionRange {
+    /// Returns `true` if the range allows any of the versions of `range`, e.g. `^16.8.0` allows
+    /// some of the versions of `<17`. It returns `false` if `range` isn't a valid range.
+    pub fn allows_any(&self, range: &str) -> bool {
+        node_semver::Range::parse(range).is_ok_and(|range| self.0.allows_any(&range))
+    }
+}
+
 impl Deserializable for PackageJson {
     fn deserialize(
         value: &impl DeserializableValue,
@@ -118,17 +147,51 @@ impl Deserializable for Version {
         match value.text().parse() {
             Ok(version) => Some(Version(version)),
             Err(err) => {
-                let (start, end) = err.location();
-                let start_range = range.start();
-                let end_range = range.end();
-                let range = TextRange::new(
-                    start_range.add(TextSize::from(start as u32)),
-                    end_range.add(TextSize::from(end as u32)),
-                );
-                diagnostics
-                    .push(DeserializationDiagnostic::new(err.kind().to_string()).with_range(range));
+                diagnostics.push(to_deserialization_diagnostic(
+                    &err.kind().to_string(),
+                    &err,
+                    range,
+                ));
+                None
+            }
+        }
+    }
+}
+
+impl Deserializable for VersionRange {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let range = value.range();
+        let value = Text::deserialize(value, name, diagnostics)?;
+        match value.text().parse() {
+            Ok(version_range) => Some(VersionRange(version_range)),
+            Err(err) => {
+                // The error of an invalid range doesn't tell what failed
+                diagnostics.push(to_deserialization_diagnostic(
+                    "Failed to parse version.",
+                    &err,
+                    range,
+                ));
                 None
             }
         }
     }
 }
+
+fn to_deserialization_diagnostic(
+    message: &str,
+    err: &node_semver::SemverError,
+    range: TextRange,
+) -> DeserializationDiagnostic {
+    let (start, end) = err.location();
+    let start_range = range.start();
+    let end_range = range.end();
+    let range = TextRange::new(
+        start_range.add(TextSize::from(start as u32)),
+        end_range.add(TextSize::from(end as u32)),
+    );
+    DeserializationDiagnostic::new(message).with_range(range)
+}
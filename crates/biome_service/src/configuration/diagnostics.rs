@@ -33,6 +33,9 @@ pub enum ConfigurationDiagnostic {
 
     /// Thrown when there's something wrong with the files specified inside `"extends"`
     CantLoadExtendFile(CantLoadExtendFile),
+
+    /// Thrown when a file specified inside `"plugins"` can't be read
+    CantLoadPluginFile(CantLoadPluginFile),
 }
 
 impl From<SyntaxError> for ConfigurationDiagnostic {
@@ -98,6 +101,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.severity(),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.severity(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.severity(),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.severity(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.severity(),
         }
     }
@@ -109,6 +113,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.category(),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.category(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.category(),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.category(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.category(),
         }
     }
@@ -120,6 +125,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.tags(),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.tags(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.tags(),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.tags(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.tags(),
         }
     }
@@ -131,6 +137,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.location(),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.location(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.location(),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.location(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.location(),
         }
     }
@@ -142,6 +149,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.source(),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.source(),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.source(),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.source(),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.source(),
         }
     }
@@ -153,6 +161,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.message(fmt),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.message(fmt),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.message(fmt),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.message(fmt),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.message(fmt),
         }
     }
@@ -164,6 +173,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.description(fmt),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.description(fmt),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.description(fmt),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.description(fmt),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.description(fmt),
         }
     }
@@ -175,6 +185,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.advices(visitor),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.advices(visitor),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.advices(visitor),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.advices(visitor),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.advices(visitor),
         }
     }
@@ -186,6 +197,7 @@ impl Diagnostic for ConfigurationDiagnostic {
             ConfigurationDiagnostic::Deserialization(error) => error.verbose_advices(visitor),
             ConfigurationDiagnostic::InvalidIgnorePattern(error) => error.verbose_advices(visitor),
             ConfigurationDiagnostic::CantLoadExtendFile(error) => error.verbose_advices(visitor),
+            ConfigurationDiagnostic::CantLoadPluginFile(error) => error.verbose_advices(visitor),
             ConfigurationDiagnostic::InvalidConfiguration(error) => error.verbose_advices(visitor),
         }
     }
@@ -272,6 +284,34 @@ impl From<CantLoadExtendFile> for WorkspaceError {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+	category = "configuration",
+	severity = Error,
+)]
+pub struct CantLoadPluginFile {
+    #[location(resource)]
+    file_path: String,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
+
+impl CantLoadPluginFile {
+    pub fn new(file_path: impl Into<String>, message: impl Display) -> Self {
+        Self {
+            file_path: file_path.into(),
+            message: MessageAndDescription::from(markup! {{message}}.to_owned()),
+        }
+    }
+}
+
+impl From<CantLoadPluginFile> for WorkspaceError {
+    fn from(value: CantLoadPluginFile) -> Self {
+        WorkspaceError::Configuration(ConfigurationDiagnostic::CantLoadPluginFile(value))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
 	category = "configuration",
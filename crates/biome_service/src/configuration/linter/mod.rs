@@ -188,7 +188,8 @@ impl From<&RulePlainConfiguration> for Severity {
         match conf {
             RulePlainConfiguration::Warn => Severity::Warning,
             RulePlainConfiguration::Error => Severity::Error,
-            _ => unreachable!("the rule is turned off, it should not step in here"),
+            // A rule that is turned off only runs when it's selected explicitly, e.g. with `--only`
+            RulePlainConfiguration::Off => Severity::Warning,
         }
     }
 }
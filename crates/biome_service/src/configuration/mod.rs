@@ -11,15 +11,16 @@ pub mod json;
 pub mod linter;
 pub mod organize_imports;
 mod overrides;
+pub mod plugins;
 pub mod vcs;
 
-use crate::configuration::diagnostics::CantLoadExtendFile;
 pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
+use crate::configuration::diagnostics::{CantLoadExtendFile, CantLoadPluginFile};
 pub(crate) use crate::configuration::generated::push_to_analyzer_rules;
 use crate::configuration::organize_imports::{
     partial_organize_imports, OrganizeImports, PartialOrganizeImports,
 };
-use crate::configuration::overrides::Overrides;
+use crate::configuration::plugins::PluginFile;
 use crate::configuration::vcs::{
     partial_vcs_configuration, PartialVcsConfiguration, VcsConfiguration,
 };
@@ -56,7 +57,10 @@ pub use linter::{
     partial_linter_configuration, LinterConfiguration, PartialLinterConfiguration,
     RuleConfiguration, Rules,
 };
-pub use overrides::to_override_settings;
+pub use overrides::{
+    to_override_settings, OverrideFormatterConfiguration, OverridePattern, Overrides,
+};
+pub use plugins::{PatternConstraint, PatternRule, Plugin, Plugins};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::io::ErrorKind;
@@ -117,6 +121,10 @@ pub struct Configuration {
     /// A list of granular patterns that should be applied only to a sub set of files
     #[partial(bpaf(hide))]
     pub overrides: Overrides,
+
+    /// A list of paths to the files of plugins, that define custom lint rules with patterns.
+    #[partial(bpaf(hide))]
+    pub plugins: Plugins,
 }
 
 impl PartialConfiguration {
@@ -392,6 +400,9 @@ pub struct LoadedConfiguration {
     pub file_path: Option<PathBuf>,
     /// The Deserialized configuration
     pub configuration: PartialConfiguration,
+    /// The configurations that were merged to obtain [LoadedConfiguration::configuration], in
+    /// the order they were applied: the files listed in `extends`, then the configuration file
+    pub sources: Vec<(PathBuf, PartialConfiguration)>,
     /// All diagnostics that were emitted during parsing and deserialization
     pub diagnostics: Vec<Error>,
 }
@@ -467,16 +478,25 @@ impl LoadedConfiguration {
             deserialized,
         } = value;
         let (partial_configuration, mut diagnostics) = deserialized.consume();
+        let mut plugin_diagnostics = Vec::new();
+        let mut sources = Vec::new();
 
         Ok(Self {
             configuration: match partial_configuration {
                 Some(mut partial_configuration) => {
-                    partial_configuration.apply_extends(
+                    let own_configuration = partial_configuration.clone();
+                    sources = partial_configuration.apply_extends(
                         fs,
                         &configuration_file_path,
                         &configuration_directory_path,
                         &mut diagnostics,
                     )?;
+                    sources.push((configuration_file_path.clone(), own_configuration));
+                    partial_configuration.load_plugins(
+                        fs,
+                        &configuration_directory_path,
+                        &mut plugin_diagnostics,
+                    )?;
                     partial_configuration.migrate_deprecated_fields();
                     partial_configuration
                 }
@@ -487,7 +507,9 @@ impl LoadedConfiguration {
                 .map(|diagnostic| {
                     diagnostic.with_file_path(configuration_file_path.display().to_string())
                 })
+                .chain(plugin_diagnostics)
                 .collect(),
+            sources,
             directory_path: Some(configuration_directory_path),
             file_path: Some(configuration_file_path),
         })
@@ -500,6 +522,8 @@ impl PartialConfiguration {
     ///
     /// The `extends` configs are applied from left to right.
     ///
+    /// It returns the `extends` configs along with their path.
+    ///
     /// If a configuration can't be resolved from the file system, the operation will fail.
     fn apply_extends(
         &mut self,
@@ -507,20 +531,23 @@ impl PartialConfiguration {
         file_path: &Path,
         directory_path: &Path,
         diagnostics: &mut Vec<Error>,
-    ) -> Result<(), WorkspaceError> {
+    ) -> Result<Vec<(PathBuf, PartialConfiguration)>, WorkspaceError> {
         let deserialized = self.deserialize_extends(fs, directory_path)?;
-        let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
+        let (extended_configurations, errors): (Vec<_>, Vec<_>) = deserialized
             .into_iter()
-            .map(|d| d.consume())
-            .map(|(config, diagnostics)| (config.unwrap_or_default(), diagnostics))
+            .map(|(path, d)| {
+                let (config, diagnostics) = d.consume();
+                ((path, config.unwrap_or_default()), diagnostics)
+            })
             .unzip();
 
-        let extended_configuration = configurations.into_iter().reduce(
-            |mut previous_configuration, current_configuration| {
+        let extended_configuration = extended_configurations
+            .iter()
+            .map(|(_, configuration)| configuration.clone())
+            .reduce(|mut previous_configuration, current_configuration| {
                 previous_configuration.merge_with(current_configuration);
                 previous_configuration
-            },
-        );
+            });
         if let Some(mut extended_configuration) = extended_configuration {
             // We swap them to avoid having to clone `self.configuration` to merge it.
             std::mem::swap(self, &mut extended_configuration);
@@ -535,7 +562,7 @@ impl PartialConfiguration {
                 .collect::<Vec<_>>(),
         );
 
-        Ok(())
+        Ok(extended_configurations)
     }
 
     /// It attempts to deserialize all the configuration files that were specified in the `extends` property
@@ -543,7 +570,7 @@ impl PartialConfiguration {
         &mut self,
         fs: &DynRef<'_, dyn FileSystem>,
         directory_path: &Path,
-    ) -> Result<Vec<Deserialized<PartialConfiguration>>, WorkspaceError> {
+    ) -> Result<Vec<(PathBuf, Deserialized<PartialConfiguration>)>, WorkspaceError> {
         let Some(extends) = &self.extends else {
             return Ok(Vec::new());
         };
@@ -574,11 +601,63 @@ impl PartialConfiguration {
                 JsonParserOptions::default(),
                 "",
             );
-            deserialized_configurations.push(deserialized)
+            deserialized_configurations.push((config_path, deserialized))
         }
         Ok(deserialized_configurations)
     }
 
+    /// Reads the files of the plugins, so that their rules are part of the configuration.
+    ///
+    /// If a file can't be read from the file system, or if the plugin is compiled to WebAssembly,
+    /// the operation will fail.
+    fn load_plugins(
+        &mut self,
+        fs: &DynRef<'_, dyn FileSystem>,
+        directory_path: &Path,
+        diagnostics: &mut Vec<Error>,
+    ) -> Result<(), WorkspaceError> {
+        let Some(plugins) = self.plugins.as_mut() else {
+            return Ok(());
+        };
+
+        plugins.load(|path| {
+            let plugin_path = directory_path.join(path);
+            if plugin_path
+                .extension()
+                .is_some_and(|extension| extension == "wasm")
+            {
+                return Err(CantLoadPluginFile::new(
+                    plugin_path.display().to_string(),
+                    "WebAssembly plugins aren't supported: this build of Biome doesn't include a WebAssembly runtime.",
+                )
+                .into());
+            }
+            let mut file = fs
+                .open_with_options(plugin_path.as_path(), OpenOptions::default().read(true))
+                .map_err(|err| {
+                    CantLoadPluginFile::new(plugin_path.display().to_string(), err.to_string())
+                })?;
+            let mut content = String::new();
+            file.read_to_string(&mut content).map_err(|err| {
+                CantLoadPluginFile::new(plugin_path.display().to_string(), err.to_string())
+            })?;
+            let (plugin_file, errors) = deserialize_from_json_str::<PluginFile>(
+                content.as_str(),
+                JsonParserOptions::default(),
+                "",
+            )
+            .consume();
+            diagnostics.extend(
+                errors
+                    .into_iter()
+                    .map(|diagnostic| diagnostic.with_file_path(plugin_path.display().to_string())),
+            );
+            Ok(plugin_file
+                .map(|plugin_file| plugin_file.rules)
+                .unwrap_or_default())
+        })
+    }
+
     /// Checks for the presence of deprecated fields and updates the
     /// configuration to apply them to the new schema.
     fn migrate_deprecated_fields(&mut self) {
@@ -102,11 +102,13 @@ impl FromStr for OverridePattern {
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct OverrideFormatterConfiguration {
     // if `false`, it disables the feature. `true` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub enabled: Option<bool>,
 
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub format_with_errors: Option<bool>,
 
@@ -134,7 +136,8 @@ pub struct OverrideFormatterConfiguration {
     /// What's the max width of a line. Defaults to 80.
     #[serde(
         deserialize_with = "deserialize_line_width",
-        serialize_with = "serialize_line_width"
+        serialize_with = "serialize_line_width",
+        skip_serializing_if = "Option::is_none"
     )]
     #[bpaf(long("line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
@@ -0,0 +1,276 @@
+//! The configuration of the plugins, listed in the `plugins` field of `biome.json`.
+//!
+//! A plugin is a JSON file that defines lint rules with a pattern:
+//!
+//! ```json
+//! {
+//!     "rules": [
+//!         {
+//!             "name": "noDirectFetch",
+//!             "pattern": "fetch($$$args)",
+//!             "message": "Use apiClient instead of calling fetch directly.",
+//!             "rewrite": "apiClient.fetch($$$args)"
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! The files are read when the configuration is loaded, and their rules are stored in the configuration.
+
+use crate::configuration::linter::RulePlainConfiguration;
+use crate::settings::{PatternConstraintSettings, PatternRuleSettings, PluginSettings};
+use biome_console::markup;
+use biome_deserialize::{Deserializable, DeserializableValue, DeserializationDiagnostic};
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_diagnostics::Severity;
+use biome_rowan::TextRange;
+use bpaf::Bpaf;
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Plugins(#[bpaf(hide)] pub Vec<Plugin>);
+
+impl FromStr for Plugins {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl Plugins {
+    /// Replaces the paths of the plugins with the rules of their files, read with `read_rules`
+    pub(crate) fn load<E>(
+        &mut self,
+        mut read_rules: impl FnMut(&str) -> Result<Vec<PatternRule>, E>,
+    ) -> Result<(), E> {
+        for plugin in self.0.iter_mut() {
+            if let Plugin::Path(path) = plugin {
+                let rules = read_rules(path)?;
+                *plugin = Plugin::Pattern {
+                    path: std::mem::take(path),
+                    rules,
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A plugin: in `biome.json` it's the path of its file, relative to the configuration file
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Plugin {
+    /// The path of a file that wasn't read yet
+    Path(String),
+    /// A file that contains pattern rules
+    Pattern {
+        path: String,
+        rules: Vec<PatternRule>,
+    },
+}
+
+impl FromStr for Plugin {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::Path(s.to_string()))
+    }
+}
+
+impl Deserializable for Plugin {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        <String as Deserializable>::deserialize(value, name, diagnostics).map(Plugin::Path)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Plugin {
+    fn schema_name() -> String {
+        String::from("Plugin")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        // In the configuration file, a plugin is the path of its file
+        <String>::json_schema(gen)
+    }
+}
+
+/// The content of the file of a plugin
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PluginFile {
+    /// A field for the [JSON schema](https://json-schema.org/) specification
+    #[serde(rename = "$schema")]
+    pub schema: Option<String>,
+
+    /// The rules of the plugin
+    pub rules: Vec<PatternRule>,
+}
+
+/// A lint rule that reports the code that matches a pattern
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PatternRule {
+    /// The name of the rule, used to suppress it with `// biome-ignore lint/plugin(<name>)`
+    #[deserializable(required, validate = "validate_rule_name")]
+    pub name: String,
+
+    /// The code to report. `$name` matches any node and `$$$name` matches any number of
+    /// elements of a list.
+    #[deserializable(required, validate = "biome_deserialize::non_empty")]
+    pub pattern: String,
+
+    /// Constraints on the code matched by the metavariables of the pattern
+    #[serde(rename = "where", skip_serializing_if = "BTreeMap::is_empty")]
+    pub constraints: BTreeMap<String, PatternConstraint>,
+
+    /// The message of the diagnostic
+    #[deserializable(required, validate = "biome_deserialize::non_empty")]
+    pub message: String,
+
+    /// The severity of the diagnostic, `"error"` by default. `"off"` disables the rule.
+    pub severity: RulePlainConfiguration,
+
+    /// The code that replaces the reported code, it can contain the metavariables of the pattern
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrite: Option<String>,
+}
+
+impl Default for PatternRule {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            pattern: String::new(),
+            constraints: BTreeMap::new(),
+            message: String::new(),
+            severity: RulePlainConfiguration::Error,
+            rewrite: None,
+        }
+    }
+}
+
+/// A constraint on the source text of the code matched by a metavariable
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct PatternConstraint {
+    /// A regular expression that the code must match
+    #[deserializable(validate = "validate_regex")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matches: Option<String>,
+
+    /// A regular expression that the code must not match
+    #[deserializable(validate = "validate_regex")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_matches: Option<String>,
+}
+
+fn validate_rule_name<T: AsRef<str>>(
+    value: &T,
+    name: &str,
+    range: TextRange,
+    diagnostics: &mut Vec<DeserializationDiagnostic>,
+) -> bool {
+    let mut chars = value.as_ref().chars();
+    let is_valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric());
+    if !is_valid {
+        diagnostics.push(
+            DeserializationDiagnostic::new(markup! {
+                <Emphasis>{name}</Emphasis>" must contain only letters and digits, and start with a letter"
+            })
+            .with_range(range),
+        );
+    }
+    is_valid
+}
+
+fn validate_regex<T: AsRef<str>>(
+    value: &T,
+    name: &str,
+    range: TextRange,
+    diagnostics: &mut Vec<DeserializationDiagnostic>,
+) -> bool {
+    match Regex::new(value.as_ref()) {
+        Ok(_) => true,
+        Err(error) => {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup! {
+                    <Emphasis>{name}</Emphasis>" isn't a valid regular expression"
+                })
+                .with_range(range)
+                .with_note(error.to_string()),
+            );
+            false
+        }
+    }
+}
+
+pub fn to_plugin_settings(plugins: Plugins) -> PluginSettings {
+    let pattern_rules = plugins
+        .0
+        .into_iter()
+        .flat_map(|plugin| match plugin {
+            Plugin::Path(_) => Vec::new(),
+            Plugin::Pattern { rules, .. } => rules,
+        })
+        .filter_map(|rule| {
+            let severity = match rule.severity {
+                RulePlainConfiguration::Warn => Severity::Warning,
+                RulePlainConfiguration::Error => Severity::Error,
+                RulePlainConfiguration::Off => return None,
+            };
+            let constraints = rule
+                .constraints
+                .into_iter()
+                .map(|(metavariable, constraint)| {
+                    let constraint = PatternConstraintSettings {
+                        matches: constraint.matches.and_then(|regex| Regex::new(&regex).ok()),
+                        not_matches: constraint
+                            .not_matches
+                            .and_then(|regex| Regex::new(&regex).ok()),
+                    };
+                    (metavariable, constraint)
+                })
+                .collect();
+
+            Some(PatternRuleSettings {
+                name: intern_rule_name(rule.name),
+                pattern: rule.pattern,
+                constraints,
+                message: rule.message,
+                severity,
+                rewrite: rule.rewrite,
+            })
+        })
+        .collect();
+
+    PluginSettings { pattern_rules }
+}
+
+/// The analyzer identifies rules with static names: the names of the plugin rules are leaked
+/// once, so that loading the configuration many times doesn't leak more memory
+fn intern_rule_name(name: String) -> &'static str {
+    static NAMES: Mutex<Option<FxHashSet<&'static str>>> = Mutex::new(None);
+
+    let mut names = NAMES.lock().unwrap();
+    let names = names.get_or_insert_with(FxHashSet::default);
+    if let Some(interned) = names.get(name.as_str()) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.into_boxed_str());
+    names.insert(interned);
+    interned
+}
@@ -0,0 +1,325 @@
+//! Completions of the configuration file, computed from the JSON schema of the configuration so
+//! the editors don't need to fetch the schema published with the npm package.
+
+use crate::configuration::PartialConfiguration;
+use crate::workspace::{CompletionItem, CompletionItemKind, CompletionsResult};
+use crate::WorkspaceError;
+use biome_fs::{RomePath, BIOME_JSON, ROME_JSON};
+use biome_js_analyze::options::PossibleOptions;
+use biome_json_syntax::{
+    JsonMember, JsonMemberName, JsonObjectValue, JsonRoot, JsonStringValue, JsonSyntaxKind,
+    JsonSyntaxNode, JsonSyntaxToken, T,
+};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange, TextSize, TokenAtOffset};
+use lazy_static::lazy_static;
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+use serde_json::Value;
+
+lazy_static! {
+    static ref CONFIGURATION_SCHEMA: RootSchema =
+        SchemaGenerator::default().into_root_schema_for::<PartialConfiguration>();
+}
+
+/// A step from a value of the configuration to one of its children
+#[derive(Debug, Eq, PartialEq)]
+enum PathSegment {
+    /// The value of a member of an object
+    Key(String),
+    /// An item of an array
+    Item,
+}
+
+pub(crate) fn completions(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    offset: TextSize,
+) -> Result<CompletionsResult, WorkspaceError> {
+    // Only the configuration file has a schema to complete
+    if !rome_path.ends_with(ROME_JSON) && !rome_path.ends_with(BIOME_JSON) {
+        return Ok(CompletionsResult::default());
+    }
+
+    let root: JsonRoot = parse.tree();
+    Ok(configuration_completions(&root, offset))
+}
+
+/// Returns the keys or the values that the configuration accepts at `offset`
+fn configuration_completions(root: &JsonRoot, offset: TextSize) -> CompletionsResult {
+    let Some(token) = token_before_cursor(root, offset) else {
+        return CompletionsResult::default();
+    };
+    let Some(parent) = token.parent() else {
+        return CompletionsResult::default();
+    };
+    let token_range = token.text_trimmed_range();
+
+    if token.kind() == JsonSyntaxKind::JSON_STRING_LITERAL {
+        let is_unterminated =
+            token.text_trimmed().len() < 2 || !token.text_trimmed().ends_with('"');
+        if offset <= token_range.start() || (offset >= token_range.end() && !is_unterminated) {
+            return CompletionsResult::default();
+        }
+        // The text after the cursor doesn't belong to an unterminated string
+        let range = if is_unterminated {
+            TextRange::new(token_range.start(), offset)
+        } else {
+            token_range
+        };
+
+        let items = if let Some(name) = JsonMemberName::cast_ref(&parent) {
+            name.syntax()
+                .parent()
+                .and_then(JsonMember::cast)
+                .map(|member| key_items(&member))
+                .unwrap_or_default()
+        } else if JsonStringValue::can_cast(parent.kind()) {
+            value_items(&schema_path(&parent), true)
+        } else {
+            Vec::new()
+        };
+        return CompletionsResult { range, items };
+    }
+
+    let range = TextRange::empty(offset);
+    let items = match token.kind() {
+        T!['{'] => JsonObjectValue::cast(parent)
+            .map(|object| object_key_items(&object, None, false))
+            .unwrap_or_default(),
+        T![,] if parent.kind() == JsonSyntaxKind::JSON_MEMBER_LIST => parent
+            .parent()
+            .and_then(JsonObjectValue::cast)
+            .map(|object| object_key_items(&object, None, false))
+            .unwrap_or_default(),
+        T![,] if parent.kind() == JsonSyntaxKind::JSON_ARRAY_ELEMENT_LIST => {
+            value_items(&schema_path(&parent), false)
+        }
+        T!['['] => {
+            let mut path = schema_path(&parent);
+            path.push(PathSegment::Item);
+            value_items(&path, false)
+        }
+        T![:] => value_items(&schema_path(&parent), false),
+        _ => Vec::new(),
+    };
+
+    CompletionsResult { range, items }
+}
+
+/// Returns the token that ends before the cursor or that contains it
+fn token_before_cursor(root: &JsonRoot, offset: TextSize) -> Option<JsonSyntaxToken> {
+    let token = match root.syntax().token_at_offset(offset) {
+        TokenAtOffset::None => return None,
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, _) => left,
+    };
+    // The cursor is in the whitespace before the token
+    if offset <= token.text_trimmed_range().start() {
+        token.prev_token()
+    } else {
+        Some(token)
+    }
+}
+
+/// Returns the keys that can replace the name of `member`
+fn key_items(member: &JsonMember) -> Vec<CompletionItem> {
+    let Some(object) = member.syntax().ancestors().find_map(JsonObjectValue::cast) else {
+        return Vec::new();
+    };
+    object_key_items(&object, Some(member), member.colon_token().is_ok())
+}
+
+/// Returns the keys accepted by `object` that aren't used by its members, except `edited`
+fn object_key_items(
+    object: &JsonObjectValue,
+    edited: Option<&JsonMember>,
+    has_colon: bool,
+) -> Vec<CompletionItem> {
+    let existing_keys: Vec<String> = object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .filter(|member| Some(member) != edited)
+        .filter_map(|member| Some(member.name().ok()?.inner_string_text().ok()?.to_string()))
+        .collect();
+
+    let mut items: Vec<_> = schemas_at(&schema_path(object.syntax()))
+        .into_iter()
+        .filter_map(|schema| schema.object.as_ref())
+        .flat_map(|object| &object.properties)
+        .filter(|(key, _)| !existing_keys.contains(key))
+        .map(|(key, schema)| CompletionItem {
+            label: key.clone(),
+            kind: CompletionItemKind::Property,
+            text: if has_colon {
+                format!("\"{key}\"")
+            } else {
+                format!("\"{key}\": ")
+            },
+            documentation: description(schema),
+        })
+        .collect();
+    sort_items(&mut items);
+    items
+}
+
+/// Returns the values of the enums and the booleans accepted at `path`
+fn value_items(path: &[PathSegment], only_strings: bool) -> Vec<CompletionItem> {
+    let mut items = Vec::new();
+    for schema in schemas_at(path) {
+        if let Some(values) = &schema.enum_values {
+            // The members of the enums with descriptions are schemas with a single value
+            let documentation = if values.len() == 1 {
+                schema
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.description.clone())
+            } else {
+                None
+            };
+            for value in values {
+                if only_strings && !value.is_string() {
+                    continue;
+                }
+                items.push(value_item(value, documentation.clone()));
+            }
+        }
+
+        let is_boolean = schema
+            .instance_type
+            .as_ref()
+            .is_some_and(|instance_type| instance_type.contains(&InstanceType::Boolean));
+        if is_boolean && !only_strings {
+            items.push(value_item(&Value::Bool(true), None));
+            items.push(value_item(&Value::Bool(false), None));
+        }
+    }
+    sort_items(&mut items);
+    items
+}
+
+fn value_item(value: &Value, documentation: Option<String>) -> CompletionItem {
+    CompletionItem {
+        label: value
+            .as_str()
+            .map_or_else(|| value.to_string(), str::to_string),
+        kind: CompletionItemKind::Value,
+        text: value.to_string(),
+        documentation,
+    }
+}
+
+fn sort_items(items: &mut Vec<CompletionItem>) {
+    items.sort_by(|left, right| left.label.cmp(&right.label));
+    items.dedup_by(|left, right| left.label == right.label);
+}
+
+/// Returns the keys and the items that lead from the root of the configuration to `node`
+fn schema_path(node: &JsonSyntaxNode) -> Vec<PathSegment> {
+    let mut path: Vec<_> = node
+        .ancestors()
+        .filter_map(|node| {
+            if node.kind() == JsonSyntaxKind::JSON_ARRAY_ELEMENT_LIST {
+                Some(PathSegment::Item)
+            } else {
+                let member = JsonMember::cast(node)?;
+                let name = member.name().ok()?.inner_string_text().ok()?;
+                Some(PathSegment::Key(name.to_string()))
+            }
+        })
+        .collect();
+    path.reverse();
+    path
+}
+
+/// Returns the schemas that the value at `path` must match
+fn schemas_at(path: &[PathSegment]) -> Vec<&'static SchemaObject> {
+    let mut schemas = Vec::new();
+    resolve_object(&CONFIGURATION_SCHEMA.schema, &mut schemas);
+
+    for (index, segment) in path.iter().enumerate() {
+        let mut children = Vec::new();
+        for schema in schemas {
+            let child = match segment {
+                PathSegment::Key(key) if key == "options" && is_rule_with_options(schema) => {
+                    // The schema of the configuration accepts the options of all the rules
+                    match index.checked_sub(1).and_then(|index| path.get(index)) {
+                        Some(PathSegment::Key(rule_name)) => rule_options_schema(rule_name),
+                        _ => None,
+                    }
+                }
+                PathSegment::Key(key) => schema
+                    .object
+                    .as_ref()
+                    .and_then(|object| object.properties.get(key)),
+                PathSegment::Item => match schema.array.as_ref().and_then(|a| a.items.as_ref()) {
+                    Some(SingleOrVec::Single(item)) => Some(item.as_ref()),
+                    _ => None,
+                },
+            };
+            if let Some(child) = child {
+                resolve(child, &mut children);
+            }
+        }
+        schemas = children;
+    }
+
+    schemas
+}
+
+/// Collects the schemas that a value matching `schema` matches, following the references and
+/// the combinations of schemas
+fn resolve(schema: &'static Schema, schemas: &mut Vec<&'static SchemaObject>) {
+    if let Schema::Object(object) = schema {
+        resolve_object(object, schemas);
+    }
+}
+
+fn resolve_object(object: &'static SchemaObject, schemas: &mut Vec<&'static SchemaObject>) {
+    if let Some(reference) = &object.reference {
+        if let Some(definition) = definition(reference) {
+            resolve(definition, schemas);
+        }
+        return;
+    }
+    if let Some(subschemas) = &object.subschemas {
+        let combined = [&subschemas.all_of, &subschemas.any_of, &subschemas.one_of];
+        for schema in combined.into_iter().flatten().flatten() {
+            resolve(schema, schemas);
+        }
+    }
+    schemas.push(object);
+}
+
+fn definition(reference: &str) -> Option<&'static Schema> {
+    let name = reference.strip_prefix("#/definitions/")?;
+    CONFIGURATION_SCHEMA.definitions.get(name)
+}
+
+/// Whether `schema` is the configuration of a rule with a level and options
+fn is_rule_with_options(schema: &SchemaObject) -> bool {
+    schema
+        .object
+        .as_ref()
+        .is_some_and(|object| object.properties.contains_key("level"))
+}
+
+/// Returns the schema of the options of a rule, which is one of the definitions of the
+/// configuration
+fn rule_options_schema(rule_name: &str) -> Option<&'static Schema> {
+    match PossibleOptions::rule_options_schema(rule_name, &mut SchemaGenerator::default())? {
+        Schema::Object(SchemaObject {
+            reference: Some(reference),
+            ..
+        }) => definition(&reference),
+        _ => None,
+    }
+}
+
+fn description(schema: &Schema) -> Option<String> {
+    match schema {
+        Schema::Object(object) => object.metadata.as_ref()?.description.clone(),
+        Schema::Bool(_) => None,
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ExtensionHandler, Mime};
+use super::{syntax_ranges, ExtensionHandler, Mime};
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
 };
@@ -7,18 +7,23 @@ use crate::settings::{
     FormatSettings, Language, LanguageListSettings, LanguageSettings, OverrideSettings,
     SettingsHandle,
 };
-use crate::workspace::GetSyntaxTreeResult;
+use crate::workspace::{
+    DocumentSymbol, DocumentSymbolsResult, FoldingRangesResult, GetSyntaxTreeResult,
+    SelectionRangesResult, SymbolKind,
+};
 use crate::WorkspaceError;
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::{can_format_css_yet, format_node};
 use biome_css_parser::CssParserOptions;
-use biome_css_syntax::{CssFileSource, CssLanguage, CssRoot, CssSyntaxNode};
+use biome_css_syntax::{
+    CssAtRule, CssFileSource, CssLanguage, CssQualifiedRule, CssRoot, CssSyntaxNode, T,
+};
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::RomePath;
 use biome_parser::AnyParse;
-use biome_rowan::{FileSource, NodeCache};
+use biome_rowan::{match_ast, AstNode, Direction, FileSource, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -109,8 +114,21 @@ impl ExtensionHandler for CssFileHandler {
                 lint: None,
                 code_actions: None,
                 rename: None,
+                rename_imports: None,
                 fix_all: None,
                 organize_imports: None,
+                search: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+                semantic_tokens: None,
+                suppressions: None,
+                cognitive_complexity: None,
+                completions: None,
+                linked_editing_ranges: None,
             },
             // TODO(faulty): Once the CSS formatter is sufficiently stable, we
             // will unhide its capabilities from services. But in the meantime,
@@ -255,3 +273,81 @@ fn format_on_type(
     let printed = biome_css_formatter::format_sub_tree(options, &root_node)?;
     Ok(printed)
 }
+
+fn document_symbols(parse: AnyParse) -> Result<DocumentSymbolsResult, WorkspaceError> {
+    let root: CssRoot = parse.tree();
+    Ok(DocumentSymbolsResult {
+        symbols: css_symbols(root.syntax()),
+    })
+}
+
+/// Returns the rules and the at-rules in the descendants of `node`. The rules nested in a rule or
+/// in an at-rule, e.g. in `@media`, are its children.
+fn css_symbols(node: &CssSyntaxNode) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for child in node.children() {
+        match css_symbol(&child) {
+            Some(symbol) => symbols.push(symbol),
+            None => symbols.extend(css_symbols(&child)),
+        }
+    }
+    symbols
+}
+
+fn css_symbol(node: &CssSyntaxNode) -> Option<DocumentSymbol> {
+    let (kind, name, selection_range) = match_ast! {
+        match node {
+            CssQualifiedRule(rule) => {
+                let prelude = rule.prelude();
+                let name = collapse_whitespace(&prelude.syntax().text_trimmed().to_string());
+                (SymbolKind::Rule, name, prelude.range())
+            },
+            CssAtRule(rule) => {
+                // The name of an at-rule is the text before its block, e.g. `@media print`
+                let range = rule.range();
+                let end = rule
+                    .syntax()
+                    .descendants_tokens(Direction::Next)
+                    .find(|token| matches!(token.kind(), T!['{'] | T![;]))
+                    .map_or(range.end(), |token| token.text_trimmed_range().start());
+                let text = rule.syntax().text_trimmed().to_string();
+                let prelude = text[..usize::from(end - range.start())].trim_end();
+                let selection_range = TextRange::at(range.start(), TextSize::of(prelude));
+                (SymbolKind::AtRule, collapse_whitespace(prelude), selection_range)
+            },
+            _ => return None,
+        }
+    };
+
+    Some(DocumentSymbol {
+        name,
+        kind,
+        range: node.text_trimmed_range(),
+        selection_range,
+        children: css_symbols(node),
+    })
+}
+
+fn folding_ranges(parse: AnyParse) -> Result<FoldingRangesResult, WorkspaceError> {
+    let root: CssRoot = parse.tree();
+    Ok(FoldingRangesResult {
+        ranges: syntax_ranges::folding_ranges(root.syntax()),
+    })
+}
+
+fn selection_ranges(
+    parse: AnyParse,
+    offsets: &[TextSize],
+) -> Result<SelectionRangesResult, WorkspaceError> {
+    let root: CssRoot = parse.tree();
+    let ranges = offsets
+        .iter()
+        .map(|offset| syntax_ranges::selection_ranges(root.syntax(), *offset))
+        .collect();
+    Ok(SelectionRangesResult { ranges })
+}
+
+/// Replaces the line breaks and the indentation of a selector or a prelude with single spaces
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
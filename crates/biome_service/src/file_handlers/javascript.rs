@@ -1,12 +1,22 @@
 use super::{
-    AnalyzerCapabilities, DebugCapabilities, ExtensionHandler, FormatterCapabilities, LintParams,
-    LintResults, Mime, ParserCapabilities,
+    syntax_ranges, AnalyzerCapabilities, DebugCapabilities, ExtensionHandler,
+    FormatterCapabilities, LintParams, LintResults, Mime, ParserCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
 use crate::file_handlers::{is_diagnostic_error, FixAllParams, Language as LanguageId};
+use crate::module_resolution;
+use crate::plugins::PatternRulePlugin;
+use crate::search::StructuralPattern;
 use crate::settings::OverrideSettings;
-use crate::workspace::OrganizeImportsResult;
+use crate::workspace::{
+    BindingKind, BindingReference, CognitiveComplexityResult, DocumentSymbol,
+    DocumentSymbolsResult, FindReferencesResult, FoldingRange, FoldingRangeKind,
+    FoldingRangesResult, FunctionComplexity, GotoDefinitionResult, HoverBinding, HoverResult,
+    LinkedEditingRangesResult, OrganizeImportsResult, ReferenceKind, SearchPatternResult,
+    SelectionRangesResult, SemanticToken, SemanticTokenKind, SemanticTokenModifier,
+    SemanticTokensResult, Suppression, SuppressionsResult, SymbolKind,
+};
 use crate::{
     settings::{FormatSettings, Language, LanguageListSettings, LanguageSettings, SettingsHandle},
     workspace::{
@@ -16,17 +26,21 @@ use crate::{
     Rules, WorkspaceError,
 };
 use biome_analyze::{
-    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
-    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, AnalyzerPlugin, ControlFlow,
+    GroupCategory, Never, QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter,
+    RuleGroup, RuleKey, PLUGIN_GROUP,
 };
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::RomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+use biome_js_analyze::utils::rename::{
+    AnyJsRenamableDeclaration, RenamableNode, RenameError, RenameSymbolExtensions,
+};
 use biome_js_analyze::{
-    analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, RuleError,
+    analyze, analyze_with_inspect_matcher, visit_registry, ComplexityOptions, ControlFlowGraph,
+    RuleError,
 };
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
@@ -34,12 +48,32 @@ use biome_js_formatter::context::{
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_semantic::{semantic_model, Binding, SemanticModel, SemanticModelOptions};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
+use biome_js_syntax::suppression::parse_suppression_comment;
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    inner_string_text, AnyJsImportClause, AnyJsModuleItem, AnyJsNamedImportSpecifier, AnyJsRoot,
+    JsClassDeclaration, JsClassExportDefaultDeclaration, JsConstructorClassMember, JsExport,
+    JsExportNamedFromClause, JsExportNamedShorthandSpecifier, JsExpressionStatement, JsFileSource,
+    JsFunctionDeclaration, JsFunctionExportDefaultDeclaration, JsGetterClassMember,
+    JsIdentifierAssignment, JsIdentifierExpression, JsImport, JsLanguage, JsLiteralExportName,
+    JsMethodClassMember, JsModuleSource, JsPropertyClassMember, JsReferenceIdentifier,
+    JsSetterClassMember, JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsVariableDeclarationClause, JsVariableKind, JsxElement, JsxReferenceIdentifier, TextRange,
+    TextSize, TokenAtOffset, TsConstructorSignatureClassMember, TsDeclareFunctionDeclaration,
+    TsDeclareFunctionExportDefaultDeclaration, TsEnumDeclaration, TsEnumMember,
+    TsGetterSignatureClassMember, TsGetterSignatureTypeMember, TsInterfaceDeclaration,
+    TsMethodSignatureClassMember, TsMethodSignatureTypeMember, TsModuleDeclaration,
+    TsPropertySignatureClassMember, TsPropertySignatureTypeMember, TsSetterSignatureClassMember,
+    TsSetterSignatureTypeMember, TsTypeAliasDeclaration,
 };
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, FileSource, NodeCache};
+use biome_rowan::{
+    match_ast, AstNode, AstSeparatedList, BatchMutationExt, Direction, FileSource, NodeCache,
+    SyntaxResult,
+};
+use biome_text_edit::TextEdit;
+use biome_unicode_table::is_js_ident;
 use std::borrow::Cow;
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -163,7 +197,20 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                rename_imports: Some(rename_imports),
                 organize_imports: Some(organize_imports),
+                search: Some(search),
+                hover: Some(hover),
+                goto_definition: Some(goto_definition),
+                find_references: Some(find_references),
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+                semantic_tokens: Some(semantic_tokens),
+                suppressions: Some(suppressions),
+                cognitive_complexity: Some(cognitive_complexity),
+                completions: None,
+                linked_editing_ranges: Some(linked_editing_ranges),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -251,6 +298,7 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
             }
         },
         &options,
+        Vec::new(),
         JsFileSource::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
@@ -304,10 +352,12 @@ fn lint(params: LintParams) -> LintResults {
             let has_lint = params.filter.categories.contains(RuleCategories::LINT);
 
             info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(
+            let (_, analyze_diagnostics) = analyze_with_inspect_matcher(
                 &tree,
                 params.filter,
+                |_| {},
                 &analyzer_options,
+                pattern_rule_plugins(&params.settings, file_source),
                 file_source,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
@@ -322,9 +372,13 @@ fn lint(params: LintParams) -> LintResults {
 
                         // We do now check if the severity of the diagnostics should be changed.
                         // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        // The rules of the plugins define their own severity.
                         let severity = diagnostic
                             .category()
-                            .filter(|category| category.name().starts_with("lint/"))
+                            .filter(|category| {
+                                category.name().starts_with("lint/")
+                                    && *category != category!("lint/plugin")
+                            })
                             .map(|category| {
                                 params
                                     .rules
@@ -425,6 +479,7 @@ fn code_actions(
         visit_registry(&mut visitor);
 
         enabled_rules.extend(visitor.enabled_rules);
+        enabled_rules.push(RuleFilter::Group(PLUGIN_GROUP));
     }
 
     let mut filter = if !enabled_rules.is_empty() {
@@ -445,19 +500,28 @@ fn code_actions(
         return PullActionsResult { actions: vec![] };
     };
 
-    analyze(&tree, filter, &analyzer_options, source_type, |signal| {
-        actions.extend(signal.actions().into_code_action_iter().map(|item| {
-            CodeAction {
-                category: item.category.clone(),
-                rule_name: item
-                    .rule_name
-                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                suggestion: item.suggestion,
-            }
-        }));
+    let plugins = pattern_rule_plugins(&settings, source_type);
+    analyze_with_inspect_matcher(
+        &tree,
+        filter,
+        |_| {},
+        &analyzer_options,
+        plugins,
+        source_type,
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -488,45 +552,54 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let mut errors: u16 = 0;
     let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules) {
-                    errors += 1;
+        let plugins = pattern_rule_plugins(&settings, file_source);
+        let (action, _) = analyze_with_inspect_matcher(
+            &tree,
+            filter,
+            |_| {},
+            &analyzer_options,
+            plugins,
+            file_source,
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
-                        }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                    match fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
                     }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
@@ -662,8 +735,21 @@ fn rename(
     {
         let original_name = node.text_trimmed();
         let range = node.text_range();
-        match node.try_into() {
+        match AnyJsRenamableDeclaration::try_from(node) {
             Ok(node) => {
+                if !is_valid_binding_name(&new_name) {
+                    return Err(WorkspaceError::RenameError(RenameError::InvalidName(
+                        new_name,
+                    )));
+                }
+                let exported_name = node
+                    .binding(&model)
+                    .and_then(AnyJsIdentifierBinding::cast)
+                    .filter(|binding| is_exported_under_its_name(binding, &model))
+                    .and_then(|binding| {
+                        Some(binding.name_token().ok()?.text_trimmed().to_string())
+                    });
+
                 let mut batch = root.begin();
                 let result = batch.rename_any_renamable_node(&model, node, &new_name);
                 if !result {
@@ -674,7 +760,11 @@ fn rename(
                     }))
                 } else {
                     let (range, indels) = batch.as_text_edits().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        exported_name,
+                    })
                 }
             }
             Err(err) => Err(WorkspaceError::RenameError(err)),
@@ -686,6 +776,150 @@ fn rename(
     }
 }
 
+/// The words that can't be the name of a binding in strict mode, in addition to the keywords
+const STRICT_MODE_RESERVED_WORDS: &[&str] = &[
+    "arguments",
+    "await",
+    "eval",
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+];
+
+/// Returns `true` if `name` is an identifier that can be the name of a binding in any file, e.g.
+/// it isn't `class` or `yield`
+fn is_valid_binding_name(name: &str) -> bool {
+    is_js_ident(name)
+        && !JsSyntaxKind::from_keyword(name).is_some_and(|kind| kind.is_non_contextual_keyword())
+        && !STRICT_MODE_RESERVED_WORDS.contains(&name)
+}
+
+/// Returns `true` if the binding is exported under its own name, e.g. `export const a` or
+/// `export { a }`: renaming the binding renames the export
+fn is_exported_under_its_name(binding: &AnyJsIdentifierBinding, model: &SemanticModel) -> bool {
+    let is_declared_by_export = binding.declaration().is_some_and(|declaration| {
+        let is_default_export = matches!(
+            declaration,
+            AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_)
+                | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+                | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_)
+        ) || declaration.syntax().parent().is_some_and(|parent| {
+            parent.kind() == JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+        });
+        !is_default_export && declaration.export().is_some()
+    });
+
+    is_declared_by_export
+        || model.as_binding(binding).all_references().any(|reference| {
+            reference
+                .syntax()
+                .parent()
+                .is_some_and(|parent| JsExportNamedShorthandSpecifier::can_cast(parent.kind()))
+        })
+}
+
+fn rename_imports(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    module_path: &RomePath,
+    exported_name: &str,
+    new_name: &str,
+) -> Result<RenameResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let resolves_to_module = |source: SyntaxResult<JsModuleSource>| {
+        source
+            .and_then(|source| source.inner_string_text())
+            .is_ok_and(|specifier| {
+                module_resolution::resolves_to(rome_path, specifier.text(), module_path)
+            })
+    };
+    let is_exported_name = |name: &JsLiteralExportName| {
+        name.value()
+            .is_ok_and(|token| inner_string_text(&token) == exported_name)
+    };
+    // The local names are kept, so that the rename doesn't conflict with the bindings of the file
+    let aliased_name = format!("{new_name} as {exported_name}");
+
+    let mut replacements = Vec::new();
+    for node in root.syntax().descendants() {
+        if let Some(import) = JsImport::cast_ref(&node) {
+            let Ok(clause) = import.import_clause() else {
+                continue;
+            };
+            if !resolves_to_module(clause.source()) {
+                continue;
+            }
+            for specifier in clause
+                .syntax()
+                .descendants()
+                .filter_map(AnyJsNamedImportSpecifier::cast)
+            {
+                match specifier {
+                    AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                        if let Ok(local_name) = specifier.local_name() {
+                            if local_name.syntax().text_trimmed() == exported_name {
+                                replacements.push((
+                                    local_name.syntax().text_trimmed_range(),
+                                    aliased_name.clone(),
+                                ));
+                            }
+                        }
+                    }
+                    AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                        if let Ok(name) = specifier.name() {
+                            if is_exported_name(&name) {
+                                replacements.push((
+                                    name.syntax().text_trimmed_range(),
+                                    new_name.to_string(),
+                                ));
+                            }
+                        }
+                    }
+                    AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
+                }
+            }
+        } else if let Some(clause) = JsExportNamedFromClause::cast_ref(&node) {
+            if !resolves_to_module(clause.source()) {
+                continue;
+            }
+            for specifier in clause.specifiers().iter().flatten() {
+                let Ok(source_name) = specifier.source_name() else {
+                    continue;
+                };
+                if is_exported_name(&source_name) {
+                    let replacement = if specifier.export_as().is_some() {
+                        new_name.to_string()
+                    } else {
+                        aliased_name.clone()
+                    };
+                    replacements.push((source_name.syntax().text_trimmed_range(), replacement));
+                }
+            }
+        }
+    }
+
+    let old = root.syntax().to_string();
+    let mut new = old.clone();
+    let mut range: Option<TextRange> = None;
+    // Replaces from the end, so that the ranges of the other replacements stay valid
+    for (replaced_range, replacement) in replacements.into_iter().rev() {
+        new.replace_range(std::ops::Range::<usize>::from(replaced_range), &replacement);
+        range = Some(range.map_or(replaced_range, |range| range.cover(replaced_range)));
+    }
+
+    Ok(RenameResult {
+        range: range.unwrap_or_default(),
+        indels: TextEdit::from_unicode_words(&old, &new),
+        exported_name: None,
+    })
+}
+
 fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
@@ -736,6 +970,723 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
     }
 }
 
+fn search(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    pattern: &str,
+) -> Result<SearchPatternResult, WorkspaceError> {
+    let source_type = JsFileSource::try_from(rome_path.as_path()).unwrap_or_default();
+    let pattern_parse = biome_js_parser::parse(pattern, source_type, JsParserOptions::default());
+    let Some(pattern_root) = search_pattern_root(&pattern_parse) else {
+        return Ok(SearchPatternResult {
+            matches: Vec::new(),
+            valid_pattern: false,
+        });
+    };
+
+    let pattern = StructuralPattern::new(pattern_root, metavariable_text);
+    Ok(SearchPatternResult {
+        matches: pattern.find_matches(&parse.syntax::<JsLanguage>()),
+        valid_pattern: true,
+    })
+}
+
+/// Returns the node to search: the pattern must contain a single statement. When the statement is
+/// an expression without semicolon, the expression is searched, so that it can be found anywhere.
+fn search_pattern_root(parse: &biome_js_parser::Parse<AnyJsRoot>) -> Option<JsSyntaxNode> {
+    if parse.has_errors() {
+        return None;
+    }
+    let statements = match parse.tree() {
+        AnyJsRoot::JsModule(module) => module.items().into_syntax(),
+        AnyJsRoot::JsScript(script) => script.statements().into_syntax(),
+        AnyJsRoot::JsExpressionSnipped(snipped) => {
+            return Some(snipped.expression().ok()?.into_syntax())
+        }
+    };
+    let mut statements = statements.children();
+    let statement = statements.next()?;
+    if statements.next().is_some() {
+        return None;
+    }
+    match JsExpressionStatement::cast_ref(&statement) {
+        Some(expression_statement) if expression_statement.semicolon_token().is_none() => {
+            Some(expression_statement.expression().ok()?.into_syntax())
+        }
+        _ => Some(statement),
+    }
+}
+
+fn hover(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    offset: TextSize,
+) -> Result<HoverResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some((token, binding)) = binding_at_offset(&root, &model, offset) else {
+        return Ok(HoverResult::default());
+    };
+    let binding = binding.tree();
+
+    let binding = binding_kind(&binding).and_then(|kind| {
+        Some(HoverBinding {
+            name: binding.name_token().ok()?.text_trimmed().to_string(),
+            kind,
+            declaration_range: binding.syntax().text_trimmed_range(),
+        })
+    });
+
+    Ok(HoverResult {
+        range: Some(token.text_trimmed_range()),
+        binding,
+        rule_name: None,
+    })
+}
+
+fn goto_definition(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    offset: TextSize,
+) -> Result<GotoDefinitionResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    if let Some(source) = root
+        .syntax()
+        .token_at_offset(offset)
+        .find_map(|token| JsModuleSource::cast(token.parent()?))
+    {
+        return Ok(GotoDefinitionResult {
+            declaration_range: None,
+            module_specifier: module_specifier(&source),
+        });
+    }
+
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some((token, binding)) = binding_at_offset(&root, &model, offset) else {
+        return Ok(GotoDefinitionResult::default());
+    };
+    let binding = binding.tree();
+    let import_clause = binding
+        .declaration()
+        .filter(|declaration| {
+            matches!(
+                declaration,
+                AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+                    | AnyJsBindingDeclaration::JsNamedImportSpecifier(_)
+                    | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+                    | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_)
+            )
+        })
+        .and_then(|declaration| {
+            let clause = declaration
+                .syntax()
+                .ancestors()
+                .find_map(AnyJsImportClause::cast)?;
+            Some((declaration, clause))
+        });
+
+    let Some((specifier, clause)) = import_clause else {
+        return Ok(GotoDefinitionResult {
+            declaration_range: Some(binding.syntax().text_trimmed_range()),
+            module_specifier: None,
+        });
+    };
+
+    // From the binding declared by the import, the definition is in the imported module
+    let is_declaration = token.parent().as_ref() == Some(binding.syntax());
+    Ok(GotoDefinitionResult {
+        declaration_range: Some(specifier.syntax().text_trimmed_range()),
+        module_specifier: is_declaration
+            .then(|| clause.source().ok())
+            .flatten()
+            .and_then(|source| module_specifier(&source)),
+    })
+}
+
+fn find_references(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    offset: TextSize,
+) -> Result<FindReferencesResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some((_, binding)) = binding_at_offset(&root, &model, offset) else {
+        return Ok(FindReferencesResult::default());
+    };
+
+    let mut references = vec![BindingReference {
+        range: binding.syntax().text_trimmed_range(),
+        kind: ReferenceKind::Declaration,
+    }];
+    references.extend(binding.all_references().map(|reference| BindingReference {
+        range: reference.syntax().text_trimmed_range(),
+        kind: if reference.is_write() {
+            ReferenceKind::Write
+        } else {
+            ReferenceKind::Read
+        },
+    }));
+    // A hoisted binding can be referenced before its declaration
+    references.sort_by_key(|reference| reference.range.start());
+
+    Ok(FindReferencesResult { references })
+}
+
+/// Returns the identifier at `offset` and the binding that it declares or refers to
+fn binding_at_offset(
+    root: &AnyJsRoot,
+    model: &SemanticModel,
+    offset: TextSize,
+) -> Option<(JsSyntaxToken, Binding)> {
+    // The cursor can be between two tokens, e.g. at the end of an identifier
+    root.syntax().token_at_offset(offset).find_map(|token| {
+        let node = token.parent()?;
+        let binding = if let Some(binding) = AnyJsIdentifierBinding::cast_ref(&node) {
+            model.as_binding(&binding)
+        } else if let Some(reference) = JsReferenceIdentifier::cast_ref(&node) {
+            model.binding(&reference)?
+        } else if let Some(assignment) = JsIdentifierAssignment::cast_ref(&node) {
+            model.binding(&assignment)?
+        } else {
+            model.binding(&JsxReferenceIdentifier::cast(node)?)?
+        };
+        Some((token, binding))
+    })
+}
+
+/// Returns the text of the source of an import or an export, without the quotes
+fn module_specifier(source: &JsModuleSource) -> Option<String> {
+    Some(source.inner_string_text().ok()?.text().to_string())
+}
+
+/// Returns how the binding is declared, e.g. `const` or `function`
+fn binding_kind(binding: &AnyJsIdentifierBinding) -> Option<BindingKind> {
+    let kind = match binding.declaration()? {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            match declarator.declaration()?.variable_kind().ok()? {
+                JsVariableKind::Const => BindingKind::Const,
+                JsVariableKind::Let => BindingKind::Let,
+                JsVariableKind::Var => BindingKind::Var,
+                JsVariableKind::Using => BindingKind::Using,
+            }
+        }
+        AnyJsBindingDeclaration::JsArrowFunctionExpression(_)
+        | AnyJsBindingDeclaration::JsFormalParameter(_)
+        | AnyJsBindingDeclaration::JsRestParameter(_)
+        | AnyJsBindingDeclaration::JsBogusParameter(_)
+        | AnyJsBindingDeclaration::TsIndexSignatureParameter(_)
+        | AnyJsBindingDeclaration::TsPropertyParameter(_) => BindingKind::Parameter,
+        AnyJsBindingDeclaration::TsInferType(_)
+        | AnyJsBindingDeclaration::TsMappedType(_)
+        | AnyJsBindingDeclaration::TsTypeParameter(_) => BindingKind::TypeParameter,
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExpression(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_) => {
+            BindingKind::Function
+        }
+        AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExpression(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_) => BindingKind::Class,
+        AnyJsBindingDeclaration::TsInterfaceDeclaration(_) => BindingKind::Interface,
+        AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => BindingKind::TypeAlias,
+        AnyJsBindingDeclaration::TsEnumDeclaration(_) => BindingKind::Enum,
+        AnyJsBindingDeclaration::TsModuleDeclaration(_) => BindingKind::Namespace,
+        AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsBogusNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_)
+        | AnyJsBindingDeclaration::TsImportEqualsDeclaration(_) => BindingKind::Import,
+        AnyJsBindingDeclaration::JsCatchDeclaration(_) => BindingKind::CatchParameter,
+    };
+    Some(kind)
+}
+
+fn semantic_tokens(
+    parse: AnyParse,
+    range: Option<TextRange>,
+) -> Result<SemanticTokensResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let mut tokens = Vec::new();
+
+    for binding in model.all_bindings() {
+        let identifier = binding.tree();
+        let binding_kind = binding_kind(&identifier);
+        let (kind, modifiers) = semantic_token_kind(binding_kind);
+
+        let mut declaration_modifiers = modifiers.clone();
+        declaration_modifiers.push(SemanticTokenModifier::Declaration);
+        // Like `noUnusedVariables`, a binding whose name starts with `_` isn't reported as unused
+        let is_unused = binding.all_references().next().is_none()
+            && !model.is_exported(&identifier)
+            && !identifier
+                .name_token()
+                .is_ok_and(|name| name.text_trimmed().starts_with('_'));
+        if is_unused {
+            declaration_modifiers.push(SemanticTokenModifier::Unused);
+        }
+        tokens.push(SemanticToken {
+            range: identifier.range(),
+            kind,
+            modifiers: declaration_modifiers,
+        });
+
+        for reference in binding.all_references() {
+            tokens.push(SemanticToken {
+                range: reference.syntax().text_trimmed_range(),
+                kind,
+                modifiers: modifiers.clone(),
+            });
+            if binding_kind == Some(BindingKind::Enum) {
+                if let Some(member_range) = enum_member_range(reference.syntax()) {
+                    tokens.push(SemanticToken {
+                        range: member_range,
+                        kind: SemanticTokenKind::EnumMember,
+                        modifiers: vec![SemanticTokenModifier::Readonly],
+                    });
+                }
+            }
+        }
+    }
+
+    for reference in model.all_unresolved_references() {
+        let node = reference.syntax();
+        // e.g. `Promise` in `Promise<void>`
+        let kind = if node
+            .parent()
+            .is_some_and(|parent| parent.kind() == JsSyntaxKind::TS_REFERENCE_TYPE)
+        {
+            SemanticTokenKind::Type
+        } else {
+            SemanticTokenKind::Variable
+        };
+        tokens.push(SemanticToken {
+            range: node.text_trimmed_range(),
+            kind,
+            modifiers: vec![SemanticTokenModifier::Global],
+        });
+    }
+
+    // The members of the enums aren't bindings of the semantic model
+    tokens.extend(
+        root.syntax()
+            .descendants()
+            .filter_map(TsEnumMember::cast)
+            .filter_map(|member| {
+                Some(SemanticToken {
+                    range: member.name().ok()?.range(),
+                    kind: SemanticTokenKind::EnumMember,
+                    modifiers: vec![
+                        SemanticTokenModifier::Declaration,
+                        SemanticTokenModifier::Readonly,
+                    ],
+                })
+            }),
+    );
+
+    if let Some(range) = range {
+        tokens.retain(|token| {
+            token
+                .range
+                .intersect(range)
+                .is_some_and(|intersection| !intersection.is_empty())
+        });
+    }
+    tokens.sort_by_key(|token| token.range.start());
+    tokens.dedup_by_key(|token| token.range.start());
+
+    Ok(SemanticTokensResult { tokens })
+}
+
+/// Returns the kind of the tokens of a binding, and the modifiers of its declaration and of its
+/// references
+fn semantic_token_kind(
+    binding_kind: Option<BindingKind>,
+) -> (SemanticTokenKind, Vec<SemanticTokenModifier>) {
+    let Some(binding_kind) = binding_kind else {
+        return (SemanticTokenKind::Variable, Vec::new());
+    };
+    let kind = match binding_kind {
+        BindingKind::Const | BindingKind::Let | BindingKind::Var | BindingKind::Using => {
+            SemanticTokenKind::Variable
+        }
+        BindingKind::CatchParameter | BindingKind::Parameter => SemanticTokenKind::Parameter,
+        BindingKind::Function => SemanticTokenKind::Function,
+        BindingKind::Class => SemanticTokenKind::Class,
+        BindingKind::TypeParameter => SemanticTokenKind::TypeParameter,
+        BindingKind::Interface => SemanticTokenKind::Interface,
+        BindingKind::TypeAlias => SemanticTokenKind::Type,
+        BindingKind::Enum => SemanticTokenKind::Enum,
+        BindingKind::Namespace => SemanticTokenKind::Namespace,
+        // The kind of the imported binding is declared in the other module
+        BindingKind::Import => SemanticTokenKind::Variable,
+    };
+    let modifiers = match binding_kind {
+        BindingKind::Const | BindingKind::Using => vec![SemanticTokenModifier::Readonly],
+        BindingKind::Import => vec![
+            SemanticTokenModifier::Import,
+            SemanticTokenModifier::Readonly,
+        ],
+        _ => Vec::new(),
+    };
+    (kind, modifiers)
+}
+
+/// Returns the range of `Red` in `Color.Red` when `reference` is the reference to `Color`
+fn enum_member_range(reference: &JsSyntaxNode) -> Option<TextRange> {
+    let expression = JsIdentifierExpression::cast(reference.parent()?)?;
+    let member_expression = JsStaticMemberExpression::cast(expression.syntax().parent()?)?;
+    let object = member_expression.object().ok()?;
+    if object.syntax() != expression.syntax() {
+        return None;
+    }
+    Some(member_expression.member().ok()?.range())
+}
+
+fn document_symbols(parse: AnyParse) -> Result<DocumentSymbolsResult, WorkspaceError> {
+    let tree: AnyJsRoot = parse.tree();
+    Ok(DocumentSymbolsResult {
+        symbols: js_symbols(tree.syntax()),
+    })
+}
+
+/// Returns the symbols declared in the descendants of `node`. The symbols declared inside a
+/// symbol, e.g. the methods of a class, are its children.
+fn js_symbols(node: &JsSyntaxNode) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for child in node.children() {
+        match js_declared_symbols(&child) {
+            Some(declared_symbols) => symbols.extend(declared_symbols),
+            None => symbols.extend(js_symbols(&child)),
+        }
+    }
+    symbols
+}
+
+/// Returns the symbols that `node` declares, or [None] when it doesn't declare a symbol
+fn js_declared_symbols(node: &JsSyntaxNode) -> Option<Vec<DocumentSymbol>> {
+    let (kind, name) = match_ast! {
+        match node {
+            JsFunctionDeclaration(declaration) => {
+                (SymbolKind::Function, Some(declaration.id().ok()?.into_syntax()))
+            },
+            JsFunctionExportDefaultDeclaration(declaration) => {
+                (SymbolKind::Function, declaration.id().map(AstNode::into_syntax))
+            },
+            TsDeclareFunctionDeclaration(declaration) => {
+                (SymbolKind::Function, Some(declaration.id().ok()?.into_syntax()))
+            },
+            TsDeclareFunctionExportDefaultDeclaration(declaration) => {
+                (SymbolKind::Function, declaration.id().map(AstNode::into_syntax))
+            },
+            JsClassDeclaration(declaration) => {
+                (SymbolKind::Class, Some(declaration.id().ok()?.into_syntax()))
+            },
+            JsClassExportDefaultDeclaration(declaration) => {
+                (SymbolKind::Class, declaration.id().map(AstNode::into_syntax))
+            },
+            JsMethodClassMember(member) => {
+                (SymbolKind::Method, Some(member.name().ok()?.into_syntax()))
+            },
+            TsMethodSignatureClassMember(member) => {
+                (SymbolKind::Method, Some(member.name().ok()?.into_syntax()))
+            },
+            TsMethodSignatureTypeMember(member) => {
+                (SymbolKind::Method, Some(member.name().ok()?.into_syntax()))
+            },
+            JsPropertyClassMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            TsPropertySignatureClassMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            TsPropertySignatureTypeMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            JsGetterClassMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            JsSetterClassMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            TsGetterSignatureClassMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            TsSetterSignatureClassMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            TsGetterSignatureTypeMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            TsSetterSignatureTypeMember(member) => {
+                (SymbolKind::Property, Some(member.name().ok()?.into_syntax()))
+            },
+            JsConstructorClassMember(member) => {
+                (SymbolKind::Constructor, Some(member.name().ok()?.into_syntax()))
+            },
+            TsConstructorSignatureClassMember(member) => {
+                (SymbolKind::Constructor, Some(member.name().ok()?.into_syntax()))
+            },
+            TsInterfaceDeclaration(declaration) => {
+                (SymbolKind::Interface, Some(declaration.id().ok()?.into_syntax()))
+            },
+            TsTypeAliasDeclaration(declaration) => {
+                (SymbolKind::TypeAlias, Some(declaration.binding_identifier().ok()?.into_syntax()))
+            },
+            TsEnumDeclaration(declaration) => {
+                (SymbolKind::Enum, Some(declaration.id().ok()?.into_syntax()))
+            },
+            TsEnumMember(member) => {
+                (SymbolKind::EnumMember, Some(member.name().ok()?.into_syntax()))
+            },
+            TsModuleDeclaration(declaration) => {
+                (SymbolKind::Namespace, Some(declaration.name().ok()?.into_syntax()))
+            },
+            JsVariableDeclarationClause(clause) => {
+                return exported_variable_symbols(&clause);
+            },
+            _ => return None,
+        }
+    };
+
+    // The anonymous default exports are named after the export
+    let (name, selection_range) = match name {
+        Some(name) => (name.text_trimmed().to_string(), name.text_trimmed_range()),
+        None => {
+            let token = node.first_token()?;
+            ("default".to_string(), token.text_trimmed_range())
+        }
+    };
+    Some(vec![DocumentSymbol {
+        name,
+        kind,
+        range: node.text_trimmed_range(),
+        selection_range,
+        children: js_symbols(node),
+    }])
+}
+
+/// Returns the variables declared by `export const a = 1, b = 2;`. The variables that aren't
+/// exported aren't symbols: they are usually the implementation details of the module.
+fn exported_variable_symbols(clause: &JsVariableDeclarationClause) -> Option<Vec<DocumentSymbol>> {
+    clause.parent::<JsExport>()?;
+    let declaration = clause.declaration().ok()?;
+    let kind = match declaration.variable_kind().ok()? {
+        JsVariableKind::Const | JsVariableKind::Using => SymbolKind::Constant,
+        JsVariableKind::Let | JsVariableKind::Var => SymbolKind::Variable,
+    };
+
+    let symbols = declaration
+        .declarators()
+        .iter()
+        .filter_map(|declarator| {
+            let declarator = declarator.ok()?;
+            let id = declarator.id().ok()?;
+            let id = id
+                .as_any_js_binding()?
+                .as_js_identifier_binding()?
+                .name_token()
+                .ok()?;
+            Some(DocumentSymbol {
+                name: id.text_trimmed().to_string(),
+                kind,
+                range: declarator.range(),
+                selection_range: id.text_trimmed_range(),
+                children: js_symbols(declarator.syntax()),
+            })
+        })
+        .collect();
+    Some(symbols)
+}
+
+fn folding_ranges(parse: AnyParse) -> Result<FoldingRangesResult, WorkspaceError> {
+    let tree: AnyJsRoot = parse.tree();
+    let mut ranges = syntax_ranges::folding_ranges(tree.syntax());
+
+    // The JSX elements are folded from their opening tag to their closing tag
+    ranges.extend(
+        tree.syntax()
+            .descendants()
+            .filter_map(JsxElement::cast)
+            .map(|element| FoldingRange {
+                range: element.range(),
+                kind: FoldingRangeKind::Block,
+            }),
+    );
+
+    if let AnyJsRoot::JsModule(module) = &tree {
+        // The ranges of the groups of consecutive imports, with the number of imports in each
+        let mut import_groups: Vec<(TextRange, usize)> = Vec::new();
+        let mut follows_import = false;
+        for item in module.items() {
+            let is_import = matches!(item, AnyJsModuleItem::JsImport(_));
+            if is_import {
+                let range = item.range();
+                match import_groups.last_mut() {
+                    Some((group, count)) if follows_import => {
+                        *group = group.cover(range);
+                        *count += 1;
+                    }
+                    _ => import_groups.push((range, 1)),
+                }
+            }
+            follows_import = is_import;
+        }
+        ranges.extend(
+            import_groups
+                .into_iter()
+                .filter(|(_, count)| *count > 1)
+                .map(|(range, _)| FoldingRange {
+                    range,
+                    kind: FoldingRangeKind::Imports,
+                }),
+        );
+    }
+
+    ranges.sort_by_key(|range| range.range.start());
+    Ok(FoldingRangesResult { ranges })
+}
+
+fn selection_ranges(
+    parse: AnyParse,
+    offsets: &[TextSize],
+) -> Result<SelectionRangesResult, WorkspaceError> {
+    let tree: AnyJsRoot = parse.tree();
+    let ranges = offsets
+        .iter()
+        .map(|offset| syntax_ranges::selection_ranges(tree.syntax(), *offset))
+        .collect();
+    Ok(SelectionRangesResult { ranges })
+}
+
+/// Returns the names of the opening and the closing tags of the JSX element when the cursor is
+/// in one of them, e.g. `Foo.Bar` in `<Foo.Bar>` and `</Foo.Bar>`, so they're edited together
+fn linked_editing_ranges(
+    parse: AnyParse,
+    offset: TextSize,
+) -> Result<LinkedEditingRangesResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    // The cursor can be between two tokens, e.g. at the end of the name
+    let ranges = root
+        .syntax()
+        .token_at_offset(offset)
+        .find_map(|token| {
+            let tag = token.ancestors().find(|node| {
+                matches!(
+                    node.kind(),
+                    JsSyntaxKind::JSX_OPENING_ELEMENT | JsSyntaxKind::JSX_CLOSING_ELEMENT
+                )
+            })?;
+            let element = tag.parent().and_then(JsxElement::cast)?;
+            let opening_name = element.opening_element().ok()?.name().ok()?;
+            let closing_name = element.closing_element().ok()?.name().ok()?;
+
+            let ranges = vec![opening_name.range(), closing_name.range()];
+            if !ranges.iter().any(|range| range.contains_inclusive(offset)) {
+                return None;
+            }
+            // The names that already differ aren't linked, the edits would keep them different
+            let is_matching =
+                opening_name.syntax().text_trimmed() == closing_name.syntax().text_trimmed();
+            is_matching.then_some(ranges)
+        })
+        .unwrap_or_default();
+
+    Ok(LinkedEditingRangesResult { ranges })
+}
+
+fn suppressions(parse: AnyParse) -> Result<SuppressionsResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let mut suppressions = Vec::new();
+    for token in root.syntax().descendants_tokens(Direction::Next) {
+        let pieces = token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces());
+        for piece in pieces.filter(|piece| piece.is_comments()) {
+            let is_suppression =
+                parse_suppression_comment(piece.text()).any(|suppression| suppression.is_ok());
+            if is_suppression {
+                suppressions.push(Suppression {
+                    range: piece.text_range(),
+                    // The workspace checks which comments are used by running the linter
+                    unused: false,
+                });
+            }
+        }
+    }
+
+    Ok(SuppressionsResult { suppressions })
+}
+
+fn cognitive_complexity(
+    path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+) -> Result<CognitiveComplexityResult, WorkspaceError> {
+    let root: AnyJsRoot = parse.tree();
+    let functions = biome_js_analyze::cognitive_complexity(root.syntax())
+        .into_iter()
+        .map(|function| FunctionComplexity {
+            range: function.range,
+            score: function.score,
+        })
+        .collect();
+
+    let settings = settings.as_ref();
+    let rule = RuleFilter::Rule("complexity", "noExcessiveCognitiveComplexity");
+    let is_enabled = settings
+        .as_rules(path.as_path())
+        .is_some_and(|rules| rules.as_enabled_rules().contains(&rule));
+    let max_allowed_complexity = is_enabled.then(|| {
+        to_analyzer_rules(settings, path.as_path())
+            .get_rule_options::<ComplexityOptions>(&RuleKey::new(
+                "complexity",
+                "noExcessiveCognitiveComplexity",
+            ))
+            .cloned()
+            .unwrap_or_default()
+            .max_allowed_complexity
+            .get()
+    });
+
+    Ok(CognitiveComplexityResult {
+        functions,
+        max_allowed_complexity,
+    })
+}
+
+/// Returns the rules of the plugins, with their patterns parsed for the files of `source_type`.
+/// The rules with a pattern that isn't a valid snippet of code are ignored.
+fn pattern_rule_plugins<'a>(
+    settings: &'a SettingsHandle,
+    source_type: JsFileSource,
+) -> Vec<Box<dyn AnalyzerPlugin<JsLanguage> + 'a>> {
+    settings
+        .as_ref()
+        .plugins
+        .pattern_rules
+        .iter()
+        .filter_map(|rule| {
+            let parse_snippet = Box::new(move |snippet: &str| {
+                let parse =
+                    biome_js_parser::parse(snippet, source_type, JsParserOptions::default());
+                search_pattern_root(&parse)
+            });
+            let plugin = PatternRulePlugin::new(rule, parse_snippet, metavariable_text)?;
+            Some(Box::new(plugin) as Box<dyn AnalyzerPlugin<JsLanguage>>)
+        })
+        .collect()
+}
+
+fn metavariable_text(token: &JsSyntaxToken) -> Option<&str> {
+    matches!(token.kind(), JsSyntaxKind::IDENT | JsSyntaxKind::JSX_IDENT)
+        .then(|| token.text_trimmed())
+}
+
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
     let settings = settings.as_ref();
     let configuration = AnalyzerConfiguration {
@@ -1,16 +1,21 @@
-use super::{ExtensionHandler, Mime};
+#[cfg(feature = "schema")]
+use super::configuration_completions::completions;
+use super::{syntax_ranges, ExtensionHandler, Mime};
 use crate::configuration::{to_analyzer_rules, PartialConfiguration};
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
     LintResults, ParserCapabilities,
 };
 use crate::file_handlers::{DebugCapabilities, Language as LanguageId};
+use crate::search::StructuralPattern;
 use crate::settings::{
     FormatSettings, Language, LanguageListSettings, LanguageSettings, OverrideSettings,
     SettingsHandle,
 };
 use crate::workspace::{
-    FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+    DocumentSymbol, DocumentSymbolsResult, FixFileResult, FoldingRangesResult, GetSyntaxTreeResult,
+    HoverResult, OrganizeImportsResult, PullActionsResult, SearchPatternResult,
+    SelectionRangesResult, SymbolKind,
 };
 use crate::{Rules, WorkspaceError};
 use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories};
@@ -22,9 +27,12 @@ use biome_json_analyze::analyze;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_syntax::{
+    AnyJsonValue, JsonFileSource, JsonLanguage, JsonMember, JsonMemberName, JsonRoot,
+    JsonSyntaxKind, JsonSyntaxNode, JsonSyntaxToken,
+};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, FileSource, NodeCache};
+use biome_rowan::{AstNode, AstSeparatedList, FileSource, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 use std::path::{Path, PathBuf};
 
@@ -122,8 +130,24 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                rename_imports: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
+                search: Some(search),
+                hover: Some(hover),
+                goto_definition: None,
+                find_references: None,
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(selection_ranges),
+                semantic_tokens: None,
+                suppressions: None,
+                cognitive_complexity: None,
+                #[cfg(feature = "schema")]
+                completions: Some(completions),
+                #[cfg(not(feature = "schema"))]
+                completions: None,
+                linked_editing_ranges: None,
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -389,6 +413,184 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
     })
 }
 
+/// In JSON, the metavariables are written inside strings, for example `"$value"`
+fn search(
+    _rome_path: &RomePath,
+    parse: AnyParse,
+    pattern: &str,
+) -> Result<SearchPatternResult, WorkspaceError> {
+    let pattern_parse = biome_json_parser::parse_json(
+        pattern,
+        JsonParserOptions {
+            allow_comments: true,
+            allow_trailing_commas: true,
+        },
+    );
+    let pattern_root = if pattern_parse.has_errors() {
+        None
+    } else {
+        pattern_parse.tree().value().ok()
+    };
+    let Some(pattern_root) = pattern_root else {
+        return Ok(SearchPatternResult {
+            matches: Vec::new(),
+            valid_pattern: false,
+        });
+    };
+
+    let pattern = StructuralPattern::new(pattern_root.into_syntax(), metavariable_text);
+    Ok(SearchPatternResult {
+        matches: pattern.find_matches(&parse.syntax::<JsonLanguage>()),
+        valid_pattern: true,
+    })
+}
+
+fn hover(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    offset: TextSize,
+) -> Result<HoverResult, WorkspaceError> {
+    // Only the names of the rules in the configuration file have information to show
+    if !rome_path.ends_with(ROME_JSON) && !rome_path.ends_with(BIOME_JSON) {
+        return Ok(HoverResult::default());
+    }
+
+    let root: JsonRoot = parse.tree();
+    let result = root
+        .syntax()
+        .token_at_offset(offset)
+        .find_map(|token| {
+            let name = token.parent().and_then(JsonMemberName::cast)?;
+            let rule_name = configuration_rule_name(&name)?;
+            Some(HoverResult {
+                range: Some(token.text_trimmed_range()),
+                binding: None,
+                rule_name: Some(rule_name),
+            })
+        })
+        .unwrap_or_default();
+
+    Ok(result)
+}
+
+/// Returns the group and the name of the rule when `name` is the name of a member of
+/// `linter.rules.<group>`, e.g. `noDebugger` in `linter.rules.suspicious.noDebugger`.
+/// The rules of the overrides are also matched.
+fn configuration_rule_name(name: &JsonMemberName) -> Option<(String, String)> {
+    // The names of the members that contain the name, from the innermost one
+    let mut member_names = name
+        .syntax()
+        .ancestors()
+        .filter_map(JsonMember::cast)
+        .filter_map(|member| member.name().ok()?.inner_string_text().ok());
+    let rule_name = member_names.next()?;
+    let group_name = member_names.next()?;
+    if member_names.next()?.text() != "rules" || member_names.next()?.text() != "linter" {
+        return None;
+    }
+
+    let (group_name, rule_name) = Rules::default()
+        .matches_diagnostic_code(Some(group_name.text()), Some(rule_name.text()))?;
+    Some((group_name.to_string(), rule_name.to_string()))
+}
+
+fn document_symbols(parse: AnyParse) -> Result<DocumentSymbolsResult, WorkspaceError> {
+    let root: JsonRoot = parse.tree();
+    let symbols = root
+        .value()
+        .map(|value| json_symbols(&value))
+        .unwrap_or_default();
+    Ok(DocumentSymbolsResult { symbols })
+}
+
+/// Returns the members of an object, and the objects and the arrays of an array named after
+/// their index, with their own members as children
+fn json_symbols(value: &AnyJsonValue) -> Vec<DocumentSymbol> {
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => object
+            .json_member_list()
+            .iter()
+            .filter_map(|member| {
+                let member = member.ok()?;
+                let name = member.name().ok()?;
+                let value = member.value().ok()?;
+                Some(DocumentSymbol {
+                    name: name.inner_string_text().ok()?.to_string(),
+                    kind: json_symbol_kind(&value)?,
+                    range: member.range(),
+                    selection_range: name.range(),
+                    children: json_symbols(&value),
+                })
+            })
+            .collect(),
+        AnyJsonValue::JsonArrayValue(array) => array
+            .elements()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, element)| {
+                let element = element.ok()?;
+                if !matches!(
+                    element,
+                    AnyJsonValue::JsonObjectValue(_) | AnyJsonValue::JsonArrayValue(_)
+                ) {
+                    return None;
+                }
+                Some(DocumentSymbol {
+                    name: index.to_string(),
+                    kind: json_symbol_kind(&element)?,
+                    range: element.range(),
+                    selection_range: element.range(),
+                    children: json_symbols(&element),
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn json_symbol_kind(value: &AnyJsonValue) -> Option<SymbolKind> {
+    let kind = match value {
+        AnyJsonValue::JsonArrayValue(_) => SymbolKind::Array,
+        AnyJsonValue::JsonBooleanValue(_) => SymbolKind::Boolean,
+        AnyJsonValue::JsonNullValue(_) => SymbolKind::Null,
+        AnyJsonValue::JsonNumberValue(_) => SymbolKind::Number,
+        AnyJsonValue::JsonObjectValue(_) => SymbolKind::Object,
+        AnyJsonValue::JsonStringValue(_) => SymbolKind::String,
+        AnyJsonValue::JsonBogusValue(_) => return None,
+    };
+    Some(kind)
+}
+
+fn folding_ranges(parse: AnyParse) -> Result<FoldingRangesResult, WorkspaceError> {
+    let root: JsonRoot = parse.tree();
+    Ok(FoldingRangesResult {
+        ranges: syntax_ranges::folding_ranges(root.syntax()),
+    })
+}
+
+fn selection_ranges(
+    parse: AnyParse,
+    offsets: &[TextSize],
+) -> Result<SelectionRangesResult, WorkspaceError> {
+    let root: JsonRoot = parse.tree();
+    let ranges = offsets
+        .iter()
+        .map(|offset| syntax_ranges::selection_ranges(root.syntax(), *offset))
+        .collect();
+    Ok(SelectionRangesResult { ranges })
+}
+
+fn metavariable_text(token: &JsonSyntaxToken) -> Option<&str> {
+    if token.kind() == JsonSyntaxKind::JSON_STRING_LITERAL {
+        token
+            .text_trimmed()
+            .strip_prefix('"')
+            .and_then(|text| text.strip_suffix('"'))
+    } else {
+        None
+    }
+}
+
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
     let configuration = AnalyzerConfiguration {
         rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
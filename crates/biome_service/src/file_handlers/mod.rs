@@ -2,7 +2,12 @@ use self::{
     css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
     unknown::UnknownFileHandler,
 };
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+use crate::workspace::{
+    CognitiveComplexityResult, CompletionsResult, DocumentSymbolsResult, FindReferencesResult,
+    FixFileMode, FoldingRangesResult, GotoDefinitionResult, HoverResult, LinkedEditingRangesResult,
+    OrganizeImportsResult, SearchPatternResult, SelectionRangesResult, SemanticTokensResult,
+    SuppressionsResult,
+};
 use crate::{
     settings::SettingsHandle,
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
@@ -11,7 +16,7 @@ use crate::{
 use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic};
 use biome_console::fmt::Formatter;
 use biome_console::markup;
-use biome_diagnostics::{Diagnostic, Severity};
+use biome_diagnostics::{category, Diagnostic, Severity};
 use biome_formatter::Printed;
 use biome_fs::RomePath;
 use biome_js_syntax::{JsFileSource, TextRange, TextSize};
@@ -21,9 +26,12 @@ pub use javascript::JsFormatterSettings;
 use std::ffi::OsStr;
 use std::path::Path;
 
+#[cfg(feature = "schema")]
+mod configuration_completions;
 mod css;
 mod javascript;
 mod json;
+mod syntax_ranges;
 mod unknown;
 
 /// Supported languages by Biome
@@ -295,7 +303,26 @@ type CodeActions =
     fn(AnyParse, TextRange, Option<&Rules>, SettingsHandle, &RomePath) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
+type RenameImports =
+    fn(&RomePath, AnyParse, &RomePath, &str, &str) -> Result<RenameResult, WorkspaceError>;
 type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
+type Search = fn(&RomePath, AnyParse, &str) -> Result<SearchPatternResult, WorkspaceError>;
+type Hover = fn(&RomePath, AnyParse, TextSize) -> Result<HoverResult, WorkspaceError>;
+type GotoDefinition =
+    fn(&RomePath, AnyParse, TextSize) -> Result<GotoDefinitionResult, WorkspaceError>;
+type FindReferences =
+    fn(&RomePath, AnyParse, TextSize) -> Result<FindReferencesResult, WorkspaceError>;
+type DocumentSymbols = fn(AnyParse) -> Result<DocumentSymbolsResult, WorkspaceError>;
+type FoldingRanges = fn(AnyParse) -> Result<FoldingRangesResult, WorkspaceError>;
+type SelectionRanges = fn(AnyParse, &[TextSize]) -> Result<SelectionRangesResult, WorkspaceError>;
+type SemanticTokens =
+    fn(AnyParse, Option<TextRange>) -> Result<SemanticTokensResult, WorkspaceError>;
+type Suppressions = fn(AnyParse) -> Result<SuppressionsResult, WorkspaceError>;
+type CognitiveComplexity =
+    fn(&RomePath, AnyParse, SettingsHandle) -> Result<CognitiveComplexityResult, WorkspaceError>;
+type Completions = fn(&RomePath, AnyParse, TextSize) -> Result<CompletionsResult, WorkspaceError>;
+type LinkedEditingRanges =
+    fn(AnyParse, TextSize) -> Result<LinkedEditingRangesResult, WorkspaceError>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -307,8 +334,34 @@ pub struct AnalyzerCapabilities {
     pub(crate) fix_all: Option<FixAll>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It renames the imports of an export of another file
+    pub(crate) rename_imports: Option<RenameImports>,
     /// It organize imports
     pub(crate) organize_imports: Option<OrganizeImports>,
+    /// It searches the code that matches a structural pattern
+    pub(crate) search: Option<Search>,
+    /// It returns the information about the code under the cursor
+    pub(crate) hover: Option<Hover>,
+    /// It returns where the binding or the module under the cursor is declared
+    pub(crate) goto_definition: Option<GotoDefinition>,
+    /// It returns the declaration and the references of the binding under the cursor
+    pub(crate) find_references: Option<FindReferences>,
+    /// It returns the symbols declared in a file
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// It returns the ranges of the code that can be folded
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It returns the ranges that the selection goes through when it's expanded
+    pub(crate) selection_ranges: Option<SelectionRanges>,
+    /// It returns the identifiers classified with the semantic model
+    pub(crate) semantic_tokens: Option<SemanticTokens>,
+    /// It returns the suppression comments of a file
+    pub(crate) suppressions: Option<Suppressions>,
+    /// It returns the cognitive complexity score of the functions of a file
+    pub(crate) cognitive_complexity: Option<CognitiveComplexity>,
+    /// It returns the completions at the cursor
+    pub(crate) completions: Option<Completions>,
+    /// It returns the ranges that are edited together with the range under the cursor
+    pub(crate) linked_editing_ranges: Option<LinkedEditingRanges>,
 }
 
 type Format = fn(&RomePath, AnyParse, SettingsHandle) -> Result<Printed, WorkspaceError>;
@@ -358,7 +411,6 @@ pub(crate) trait ExtensionHandler {
 pub(crate) struct Features {
     js: JsFileHandler,
     json: JsonFileHandler,
-    #[allow(unused)]
     css: CssFileHandler,
     unknown: UnknownFileHandler,
 }
@@ -385,8 +437,21 @@ impl Features {
             | Language::TypeScript
             | Language::TypeScriptReact => self.js.capabilities(),
             Language::Json | Language::Jsonc => self.json.capabilities(),
-            // TODO: change this when we are ready to handle CSS files
-            Language::Css => self.unknown.capabilities(),
+            // TODO: change this when we are ready to handle CSS files. Only the symbols and the
+            // ranges of the editors are ready for now.
+            Language::Css => {
+                let css = self.css.capabilities();
+                Capabilities {
+                    parser: css.parser,
+                    analyzer: AnalyzerCapabilities {
+                        document_symbols: css.analyzer.document_symbols,
+                        folding_ranges: css.analyzer.folding_ranges,
+                        selection_ranges: css.analyzer.selection_ranges,
+                        ..AnalyzerCapabilities::default()
+                    },
+                    ..self.unknown.capabilities()
+                }
+            }
             Language::Unknown => self.unknown.capabilities(),
         }
     }
@@ -399,9 +464,12 @@ pub(crate) fn is_diagnostic_error(
     diagnostic: &'_ AnalyzerDiagnostic,
     rules: Option<&'_ Rules>,
 ) -> bool {
+    // The rules of the plugins define their own severity
     let severity = diagnostic
         .category()
-        .filter(|category| category.name().starts_with("lint/"))
+        .filter(|category| {
+            category.name().starts_with("lint/") && *category != category!("lint/plugin")
+        })
         .map(|category| {
             rules
                 .and_then(|rules| rules.get_severity_from_code(category))
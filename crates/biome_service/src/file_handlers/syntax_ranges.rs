@@ -0,0 +1,181 @@
+//! The ranges of the syntax trees that the editors use to fold the code and to expand the
+//! selection. They only depend on the tokens and the trivia, so they are shared by all the
+//! languages.
+
+use crate::workspace::{FoldingRange, FoldingRangeKind};
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, TextRange, TextSize, TokenAtOffset,
+};
+
+/// Returns the ranges between the pairs of delimiters, e.g. `{` and `}`, the ranges of the
+/// comments and the ranges of the regions delimited by `#region` and `#endregion` comments.
+/// The ranges are sorted by their start.
+pub(crate) fn folding_ranges<L: Language>(root: &SyntaxNode<L>) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+    // The closing delimiter of each opened delimiter, and where the opened delimiter starts
+    let mut delimiters = Vec::new();
+    let mut region_starts = Vec::new();
+
+    for token in root.descendants_tokens(Direction::Next) {
+        comment_ranges(&token, &mut region_starts, &mut ranges);
+
+        let range = token.text_trimmed_range();
+        match token.text_trimmed() {
+            "{" | "${" => delimiters.push(("}", range.start())),
+            "[" => delimiters.push(("]", range.start())),
+            "(" => delimiters.push((")", range.start())),
+            closing @ ("}" | "]" | ")") => {
+                // The delimiters that aren't closed, e.g. in code with syntax errors, are skipped
+                let Some(index) = delimiters
+                    .iter()
+                    .rposition(|(expected, _)| *expected == closing)
+                else {
+                    continue;
+                };
+                let (_, start) = delimiters[index];
+                delimiters.truncate(index);
+                ranges.push(FoldingRange {
+                    range: TextRange::new(start, range.end()),
+                    kind: FoldingRangeKind::Block,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    ranges.sort_by_key(|range| range.range.start());
+    ranges
+}
+
+/// Adds the ranges of the comments of `token` to `ranges`: the multi-line comments, the
+/// consecutive single-line comments, and the regions that end in these comments
+fn comment_ranges<L: Language>(
+    token: &SyntaxToken<L>,
+    region_starts: &mut Vec<TextSize>,
+    ranges: &mut Vec<FoldingRange>,
+) {
+    fn end_consecutive_comments(
+        consecutive_comments: &mut Option<TextRange>,
+        ranges: &mut Vec<FoldingRange>,
+    ) {
+        if let Some(range) = consecutive_comments.take() {
+            ranges.push(FoldingRange {
+                range,
+                kind: FoldingRangeKind::Comment,
+            });
+        }
+    }
+
+    let mut consecutive_comments: Option<TextRange> = None;
+    let mut newlines = 0;
+
+    let pieces = token
+        .leading_trivia()
+        .pieces()
+        .chain(token.trailing_trivia().pieces());
+    for piece in pieces {
+        let kind = piece.kind();
+        if kind.is_whitespace() {
+            continue;
+        }
+        if kind.is_newline() {
+            newlines += 1;
+            // An empty line separates the comments
+            if newlines > 1 {
+                end_consecutive_comments(&mut consecutive_comments, ranges);
+            }
+            continue;
+        }
+        newlines = 0;
+        if !kind.is_comment() {
+            end_consecutive_comments(&mut consecutive_comments, ranges);
+            continue;
+        }
+
+        let range = piece.text_range();
+        match region_marker(piece.text()) {
+            Some(RegionMarker::Start) => {
+                end_consecutive_comments(&mut consecutive_comments, ranges);
+                region_starts.push(range.start());
+            }
+            Some(RegionMarker::End) => {
+                end_consecutive_comments(&mut consecutive_comments, ranges);
+                if let Some(start) = region_starts.pop() {
+                    ranges.push(FoldingRange {
+                        range: TextRange::new(start, range.end()),
+                        kind: FoldingRangeKind::Region,
+                    });
+                }
+            }
+            None if kind.is_multiline_comment() => {
+                end_consecutive_comments(&mut consecutive_comments, ranges);
+                ranges.push(FoldingRange {
+                    range,
+                    kind: FoldingRangeKind::Comment,
+                });
+            }
+            None => {
+                consecutive_comments =
+                    Some(consecutive_comments.map_or(range, |comments| comments.cover(range)));
+            }
+        }
+    }
+
+    end_consecutive_comments(&mut consecutive_comments, ranges);
+}
+
+enum RegionMarker {
+    Start,
+    End,
+}
+
+/// Returns the marker of a comment like `// #region Helpers` or `/* #endregion */`
+fn region_marker(comment: &str) -> Option<RegionMarker> {
+    let text = comment
+        .strip_prefix("//")
+        .or_else(|| comment.strip_prefix("/*"))?
+        .trim_start();
+    if text.starts_with("#region") {
+        Some(RegionMarker::Start)
+    } else if text.starts_with("#endregion") {
+        Some(RegionMarker::End)
+    } else {
+        None
+    }
+}
+
+/// Returns the range of the token at `offset` and the ranges of the nodes that contain it, from
+/// the innermost node. A range that is equal to the previous one is skipped.
+pub(crate) fn selection_ranges<L: Language>(
+    root: &SyntaxNode<L>,
+    offset: TextSize,
+) -> Vec<TextRange> {
+    let token = match root.token_at_offset(offset) {
+        TokenAtOffset::None => return Vec::new(),
+        TokenAtOffset::Single(token) => token,
+        // Between `name` and `(`, the name is selected
+        TokenAtOffset::Between(left, right) => {
+            if is_word(&right) || !is_word(&left) {
+                right
+            } else {
+                left
+            }
+        }
+    };
+
+    let mut ranges: Vec<TextRange> = Vec::new();
+    let ancestor_ranges = token.ancestors().map(|node| node.text_trimmed_range());
+    for range in std::iter::once(token.text_trimmed_range()).chain(ancestor_ranges) {
+        if ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    }
+    ranges
+}
+
+/// Returns `true` if the token is a name, a keyword or a literal, and not a punctuation
+fn is_word<L: Language>(token: &SyntaxToken<L>) -> bool {
+    token
+        .text_trimmed()
+        .starts_with(|c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '#' | '"' | '\''))
+}
@@ -6,9 +6,12 @@ use std::ops::{Deref, DerefMut};
 pub mod configuration;
 pub mod documentation;
 pub mod file_handlers;
+mod plugins;
 pub mod project_handlers;
+mod search;
 
 pub mod matcher;
+pub mod module_resolution;
 pub mod settings;
 pub mod workspace;
 
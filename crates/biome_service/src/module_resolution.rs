@@ -0,0 +1,120 @@
+//! Resolution of the relative specifiers of the JavaScript modules, e.g. `./utils` in
+//! `import { join } from "./utils"`. The resolution is lexical: it returns the paths that a
+//! specifier can point to, and the caller checks which one exists.
+
+use std::ffi::OsString;
+use std::path::{Component, Path, PathBuf};
+
+/// The extensions to try, in order, when the specifier of a relative module doesn't point to a file
+const MODULE_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts", "d.ts", "js", "jsx", "mjs", "cjs"];
+
+/// The TypeScript extensions of the files that a specifier with a JavaScript extension can import,
+/// e.g. `./utils.js` imports `./utils.ts`
+const TYPESCRIPT_EXTENSIONS: &[(&str, &[&str])] = &[
+    ("js", &["ts", "tsx"]),
+    ("jsx", &["tsx"]),
+    ("mjs", &["mts"]),
+    ("cjs", &["cts"]),
+];
+
+/// Returns the paths of the files that a relative specifier imported from `importer` can resolve
+/// to, in order of priority. Like the bundlers, it tries the specifier as is, then its TypeScript
+/// source, then with the usual extensions, then as a folder with an `index` file.
+///
+/// Returns [None] when the specifier isn't relative, e.g. `react`.
+pub fn relative_module_candidates(importer: &Path, specifier: &str) -> Option<Vec<PathBuf>> {
+    if !(specifier.starts_with("./") || specifier.starts_with("../")) {
+        return None;
+    }
+    let path = normalize_path(&importer.parent()?.join(specifier));
+
+    let typescript_sources = path
+        .extension()
+        .and_then(|extension| {
+            TYPESCRIPT_EXTENSIONS
+                .iter()
+                .find(|(javascript, _)| extension == *javascript)
+        })
+        .map_or(&[][..], |(_, typescript)| typescript)
+        .iter()
+        .map(|extension| path.with_extension(extension));
+    let with_extensions = MODULE_EXTENSIONS.iter().map(|extension| {
+        let mut file_name = OsString::from(path.as_os_str());
+        file_name.push(".");
+        file_name.push(extension);
+        PathBuf::from(file_name)
+    });
+    let index_files = MODULE_EXTENSIONS
+        .iter()
+        .map(|extension| path.join(format!("index.{extension}")));
+
+    Some(
+        std::iter::once(path.clone())
+            .chain(typescript_sources)
+            .chain(with_extensions)
+            .chain(index_files)
+            .collect(),
+    )
+}
+
+/// Returns `true` if the relative specifier imported from `importer` can resolve to `module`
+pub fn resolves_to(importer: &Path, specifier: &str, module: &Path) -> bool {
+    let module = normalize_path(module);
+    relative_module_candidates(importer, specifier)
+        .is_some_and(|candidates| candidates.contains(&module))
+}
+
+/// Removes the `.` and `..` components of a path, without accessing the file system
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_relative_specifiers() {
+        let importer = Path::new("/project/src/app.js");
+        assert!(resolves_to(
+            importer,
+            "./utils",
+            Path::new("/project/src/utils.ts")
+        ));
+        assert!(resolves_to(
+            importer,
+            "../lib/index.js",
+            Path::new("/project/lib/index.js")
+        ));
+        assert!(resolves_to(
+            importer,
+            "./utils.js",
+            Path::new("/project/src/utils.ts")
+        ));
+        assert!(resolves_to(
+            importer,
+            "../lib",
+            Path::new("/project/lib/index.mjs")
+        ));
+        assert!(!resolves_to(
+            importer,
+            "./utils",
+            Path::new("/project/utils.ts")
+        ));
+        assert!(!resolves_to(
+            importer,
+            "utils",
+            Path::new("/project/src/utils.ts")
+        ));
+    }
+}
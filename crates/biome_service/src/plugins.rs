@@ -0,0 +1,123 @@
+//! The lint rules defined by the plugins listed in the configuration.
+//!
+//! A pattern rule reports the nodes that match its [StructuralPattern], when the code matched by
+//! the metavariables satisfies the constraints of the rule. When the rule has a rewrite, its
+//! metavariables are replaced with the matched code, and the result replaces the reported node.
+
+use crate::search::{Metavariable, MetavariableText, PatternCapture, StructuralPattern};
+use crate::settings::PatternRuleSettings;
+use biome_analyze::{
+    ActionCategory, AnalyzerAction, AnalyzerPlugin, DiagnosticSignal, PluginSignal, RuleDiagnostic,
+    PLUGIN_GROUP,
+};
+use biome_console::markup;
+use biome_diagnostics::{category, Applicability, DiagnosticExt, Error};
+use biome_rowan::{BatchMutation, Language, SyntaxNode};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+/// Parses a snippet of code, and returns the node it contains
+pub(crate) type ParseSnippet<'a, L> = Box<dyn Fn(&str) -> Option<SyntaxNode<L>> + 'a>;
+
+/// A rule of a plugin, that reports the code that matches a pattern
+pub(crate) struct PatternRulePlugin<'a, L: Language> {
+    settings: &'a PatternRuleSettings,
+    pattern: StructuralPattern<L>,
+    parse_snippet: ParseSnippet<'a, L>,
+}
+
+impl<'a, L: Language> PatternRulePlugin<'a, L> {
+    /// Creates the rule, or returns `None` when `parse_snippet` can't parse its pattern
+    pub(crate) fn new(
+        settings: &'a PatternRuleSettings,
+        parse_snippet: ParseSnippet<'a, L>,
+        metavariable_text: MetavariableText<L>,
+    ) -> Option<Self> {
+        let pattern_root = parse_snippet(&settings.pattern)?;
+        Some(Self {
+            settings,
+            pattern: StructuralPattern::new(pattern_root, metavariable_text),
+            parse_snippet,
+        })
+    }
+
+    /// Returns `true` when the code matched by the metavariables satisfies all the constraints
+    fn is_satisfied_by(&self, capture: &PatternCapture<L>) -> bool {
+        self.settings
+            .constraints
+            .iter()
+            .all(|(metavariable, constraint)| {
+                let name = metavariable.trim_start_matches('$');
+                constraint.is_satisfied_by(&capture.text(name).unwrap_or_default())
+            })
+    }
+
+    /// Returns the node that replaces the matched node, with the metavariables of the rewrite
+    /// replaced with the code they matched
+    fn rewrite(&self, capture: &PatternCapture<L>) -> Option<SyntaxNode<L>> {
+        lazy_static! {
+            static ref METAVARIABLE: Regex =
+                Regex::new(r"\$(\$\$)?[A-Za-z_][A-Za-z0-9_]*").unwrap();
+        }
+
+        let rewrite = self.settings.rewrite.as_deref()?;
+        let code = METAVARIABLE.replace_all(rewrite, |captures: &Captures| {
+            let text = &captures[0];
+            match Metavariable::parse(text) {
+                Some(Metavariable::Single(Some(name)) | Metavariable::Multiple(Some(name))) => {
+                    capture.text(&name).unwrap_or_else(|| text.to_string())
+                }
+                _ => text.to_string(),
+            }
+        });
+        (self.parse_snippet)(&code).map(SyntaxNode::detach)
+    }
+}
+
+impl<'a, L: Language + 'static> AnalyzerPlugin<L> for PatternRulePlugin<'a, L> {
+    fn name(&self) -> &'static str {
+        self.settings.name
+    }
+
+    fn evaluate(&self, root: &SyntaxNode<L>) -> Vec<PluginSignal<L>> {
+        self.pattern
+            .find_captures(root)
+            .into_iter()
+            .filter(|capture| self.is_satisfied_by(capture))
+            .map(|capture| {
+                let name = self.settings.name;
+                let text_range = capture.node.text_trimmed_range();
+                let message = self.settings.message.clone();
+                let severity = self.settings.severity;
+                let replacement = self.rewrite(&capture);
+                let node = capture.node;
+                let root = root.clone();
+
+                let signal = DiagnosticSignal::new(move || -> Error {
+                    RuleDiagnostic::new(category!("lint/plugin"), text_range, message.as_str())
+                        .note(markup! {
+                            "This code matches the pattern of the rule "<Emphasis>{name}</Emphasis>"."
+                        })
+                        .with_severity(severity)
+                })
+                .with_action(move || {
+                    let replacement = replacement.clone()?;
+                    let mut mutation = BatchMutation::new(root.clone());
+                    mutation.replace_element(node.clone().into(), replacement.into());
+                    Some(AnalyzerAction {
+                        rule_name: Some((PLUGIN_GROUP, name)),
+                        category: ActionCategory::QuickFix,
+                        applicability: Applicability::MaybeIncorrect,
+                        message: markup! { "Apply the rewrite of the rule." }.to_owned(),
+                        mutation,
+                    })
+                });
+
+                PluginSignal {
+                    text_range,
+                    signal: Box::new(signal),
+                }
+            })
+            .collect()
+    }
+}
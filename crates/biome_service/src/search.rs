@@ -0,0 +1,284 @@
+//! Structural search of code.
+//!
+//! A pattern is a snippet of code, parsed with the parser of the language of the searched file.
+//! The searched tree matches the pattern when it has the same shape: the nodes must be of the same
+//! kind and the tokens must have the same text. Trivia, hence whitespaces and comments, are ignored.
+//!
+//! A pattern can contain metavariables, written as identifiers that start with `$`:
+//! - `$name` matches any node, and `$_` is its anonymous version;
+//! - `$$$name` matches zero or more elements of a list, and `$$$` is its anonymous version.
+//!
+//! When the same named metavariable appears more than once, all its occurrences must match the same code.
+
+use biome_rowan::{
+    Direction, Language, NodeOrToken, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, TextRange,
+};
+
+/// A metavariable found in a pattern
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum Metavariable {
+    /// `$name`, or `$_` when it's anonymous
+    Single(Option<String>),
+    /// `$$$name`, or `$$$` when it's anonymous
+    Multiple(Option<String>),
+}
+
+impl Metavariable {
+    /// Parses the text of an identifier as a metavariable
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        if let Some(name) = text.strip_prefix("$$$") {
+            if name.is_empty() {
+                Some(Self::Multiple(None))
+            } else {
+                is_metavariable_name(name).then(|| Self::Multiple(Some(name.to_string())))
+            }
+        } else if let Some(name) = text.strip_prefix('$') {
+            if name == "_" {
+                Some(Self::Single(None))
+            } else {
+                is_metavariable_name(name).then(|| Self::Single(Some(name.to_string())))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+fn is_metavariable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns the text of a token that can be a metavariable, `None` otherwise.
+///
+/// Each language decides which tokens can hold a metavariable.
+pub(crate) type MetavariableText<L> = fn(&SyntaxToken<L>) -> Option<&str>;
+
+/// A pattern parsed from a snippet of code
+pub(crate) struct StructuralPattern<L: Language> {
+    root: SyntaxNode<L>,
+    metavariable_text: MetavariableText<L>,
+}
+
+type Bindings<L> = Vec<(String, Vec<SyntaxElement<L>>)>;
+
+impl<L: Language> StructuralPattern<L> {
+    pub(crate) fn new(root: SyntaxNode<L>, metavariable_text: MetavariableText<L>) -> Self {
+        Self {
+            root,
+            metavariable_text,
+        }
+    }
+
+    /// Returns the ranges of all the nodes of `root` that match the pattern, in document order.
+    ///
+    /// Nested matches are returned too.
+    pub(crate) fn find_matches(&self, root: &SyntaxNode<L>) -> Vec<TextRange> {
+        self.find_captures(root)
+            .into_iter()
+            .map(|capture| capture.node.text_trimmed_range())
+            .collect()
+    }
+
+    /// Returns all the nodes of `root` that match the pattern, with the code matched by the
+    /// named metavariables, in document order.
+    pub(crate) fn find_captures(&self, root: &SyntaxNode<L>) -> Vec<PatternCapture<L>> {
+        let pattern = SyntaxElement::Node(self.root.clone());
+        let mut captures: Vec<PatternCapture<L>> = Vec::new();
+        for node in root.descendants() {
+            let range = node.text_trimmed_range();
+            // A node that wraps a single node has the same range: report the match only once
+            if range.is_empty()
+                || captures
+                    .last()
+                    .is_some_and(|capture| capture.node.text_trimmed_range() == range)
+            {
+                continue;
+            }
+            let mut bindings = Bindings::new();
+            if self.match_element(&pattern, &SyntaxElement::Node(node.clone()), &mut bindings) {
+                captures.push(PatternCapture { node, bindings });
+            }
+        }
+        captures
+    }
+
+    /// Returns the metavariable held by `element`, if any.
+    ///
+    /// Only the elements that contain a single token can be metavariables. Lists are never
+    /// metavariables, so that `f($x)` matches only the calls with a single argument.
+    fn metavariable(&self, element: &SyntaxElement<L>) -> Option<Metavariable> {
+        let token = match element {
+            NodeOrToken::Node(node) => {
+                if node.kind().is_list() {
+                    return None;
+                }
+                let first = node.first_token()?;
+                if Some(&first) != node.last_token().as_ref() {
+                    return None;
+                }
+                first
+            }
+            NodeOrToken::Token(token) => token.clone(),
+        };
+        Metavariable::parse((self.metavariable_text)(&token)?)
+    }
+
+    fn match_element(
+        &self,
+        pattern: &SyntaxElement<L>,
+        target: &SyntaxElement<L>,
+        bindings: &mut Bindings<L>,
+    ) -> bool {
+        if let Some(Metavariable::Single(name) | Metavariable::Multiple(name)) =
+            self.metavariable(pattern)
+        {
+            return bind(bindings, name, std::slice::from_ref(target));
+        }
+
+        if pattern.kind() != target.kind() {
+            return false;
+        }
+
+        match (pattern, target) {
+            (NodeOrToken::Token(pattern), NodeOrToken::Token(target)) => {
+                pattern.text_trimmed() == target.text_trimmed()
+            }
+            (NodeOrToken::Node(pattern), NodeOrToken::Node(target)) => {
+                self.match_sequence(&children(pattern), &children(target), bindings)
+            }
+            _ => false,
+        }
+    }
+
+    /// Matches the children of a pattern node against the children of a node. A `$$$` metavariable
+    /// can match any number of children, so the matching backtracks until all the children match.
+    fn match_sequence(
+        &self,
+        patterns: &[SyntaxElement<L>],
+        targets: &[SyntaxElement<L>],
+        bindings: &mut Bindings<L>,
+    ) -> bool {
+        let Some((pattern, patterns)) = patterns.split_first() else {
+            return targets.is_empty();
+        };
+
+        if let Some(Metavariable::Multiple(name)) = self.metavariable(pattern) {
+            for count in 0..=targets.len() {
+                let mut attempt = bindings.clone();
+                if bind(&mut attempt, name.clone(), &targets[..count])
+                    && self.match_sequence(patterns, &targets[count..], &mut attempt)
+                {
+                    *bindings = attempt;
+                    return true;
+                }
+            }
+            return false;
+        }
+
+        match targets.split_first() {
+            Some((target, targets)) => {
+                self.match_element(pattern, target, bindings)
+                    && self.match_sequence(patterns, targets, bindings)
+            }
+            None => false,
+        }
+    }
+}
+
+/// A node that matches a pattern
+pub(crate) struct PatternCapture<L: Language> {
+    /// The node that matches the pattern
+    pub(crate) node: SyntaxNode<L>,
+    bindings: Bindings<L>,
+}
+
+impl<L: Language> PatternCapture<L> {
+    /// Returns the source text of the code matched by the metavariable `name`, or `None` when
+    /// the pattern doesn't contain this metavariable
+    pub(crate) fn text(&self, name: &str) -> Option<String> {
+        let (_, elements) = self
+            .bindings
+            .iter()
+            .find(|(bound_name, _)| bound_name == name)?;
+        let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+            return Some(String::new());
+        };
+        let range = first.text_trimmed_range().cover(last.text_trimmed_range());
+        let text = self.node.text();
+        Some(
+            text.slice(range - self.node.text_range().start())
+                .to_string(),
+        )
+    }
+}
+
+/// Returns the children of `node` that take part in the matching. The separators of the lists
+/// are ignored, so that trailing commas don't matter.
+fn children<L: Language>(node: &SyntaxNode<L>) -> Vec<SyntaxElement<L>> {
+    if node.kind().is_list() {
+        node.children().map(SyntaxElement::Node).collect()
+    } else {
+        node.children_with_tokens().collect()
+    }
+}
+
+/// Binds `name` to `elements`. When the name was already bound, the elements must have the same
+/// tokens as the bound ones.
+fn bind<L: Language>(
+    bindings: &mut Bindings<L>,
+    name: Option<String>,
+    elements: &[SyntaxElement<L>],
+) -> bool {
+    let Some(name) = name else {
+        return true;
+    };
+    if let Some((_, bound)) = bindings.iter().find(|(bound_name, _)| *bound_name == name) {
+        tokens(bound).eq(tokens(elements))
+    } else {
+        bindings.push((name, elements.to_vec()));
+        true
+    }
+}
+
+/// Returns the trimmed text of the tokens of `elements`
+fn tokens<L: Language>(elements: &[SyntaxElement<L>]) -> impl Iterator<Item = String> + '_ {
+    elements.iter().flat_map(|element| {
+        let tokens: Vec<SyntaxToken<L>> = match element {
+            NodeOrToken::Node(node) => node.descendants_tokens(Direction::Next).collect(),
+            NodeOrToken::Token(token) => vec![token.clone()],
+        };
+        tokens
+            .into_iter()
+            .map(|token| token.text_trimmed().to_string())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::Metavariable;
+
+    #[test]
+    fn parse_metavariables() {
+        assert_eq!(
+            Metavariable::parse("$name"),
+            Some(Metavariable::Single(Some("name".to_string())))
+        );
+        assert_eq!(Metavariable::parse("$_"), Some(Metavariable::Single(None)));
+        assert_eq!(
+            Metavariable::parse("$$$args"),
+            Some(Metavariable::Multiple(Some("args".to_string())))
+        );
+        assert_eq!(
+            Metavariable::parse("$$$"),
+            Some(Metavariable::Multiple(None))
+        );
+        assert_eq!(Metavariable::parse("$"), None);
+        assert_eq!(Metavariable::parse("$$name"), None);
+        assert_eq!(Metavariable::parse("$1"), None);
+        assert_eq!(Metavariable::parse("name"), None);
+    }
+}
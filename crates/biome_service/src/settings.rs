@@ -2,6 +2,7 @@ use crate::configuration::diagnostics::InvalidIgnorePattern;
 use crate::configuration::formatter::to_format_settings;
 use crate::configuration::linter::to_linter_settings;
 use crate::configuration::organize_imports::{to_organize_imports_settings, OrganizeImports};
+use crate::configuration::plugins::to_plugin_settings;
 use crate::configuration::{
     push_to_analyzer_rules, to_override_settings, CssConfiguration, FormatterConfiguration,
     JavascriptConfiguration, JsonConfiguration, LinterConfiguration, PartialConfiguration,
@@ -14,7 +15,7 @@ use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
 use biome_deserialize::{Merge, StringSet};
-use biome_diagnostics::Category;
+use biome_diagnostics::{Category, Severity};
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
 use biome_fs::RomePath;
 use biome_js_analyze::metadata;
@@ -26,6 +27,7 @@ use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use indexmap::IndexSet;
+use regex::Regex;
 use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::{
@@ -48,6 +50,8 @@ pub struct WorkspaceSettings {
     pub organize_imports: OrganizeImportsSettings,
     /// overrides
     pub override_settings: OverrideSettings,
+    /// Rules defined by the plugins
+    pub plugins: PluginSettings,
 }
 
 impl WorkspaceSettings {
@@ -74,6 +78,19 @@ impl WorkspaceSettings {
         enabled == Some(&false)
     }
 
+    /// Resolves the formatter options of the language `L` for the file at `path`
+    pub fn format_options<L>(&self, path: &RomePath) -> L::FormatOptions
+    where
+        L: Language,
+    {
+        L::resolve_format_options(
+            &self.formatter,
+            &self.override_settings,
+            &L::lookup_settings(&self.languages).formatter,
+            path,
+        )
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -137,6 +154,10 @@ impl WorkspaceSettings {
             self.languages.css = CssConfiguration::from(css).into();
         }
 
+        if let Some(plugins) = configuration.plugins {
+            self.plugins = to_plugin_settings(plugins);
+        }
+
         // NOTE: keep this last. Computing the overrides require reading the settings computed by the parent settings.
         if let Some(overrides) = configuration.overrides {
             self.override_settings =
@@ -293,6 +314,52 @@ impl Default for OrganizeImportsSettings {
     }
 }
 
+/// Settings of the plugins for the entire workspace
+#[derive(Debug, Default)]
+pub struct PluginSettings {
+    /// The rules defined with a pattern
+    pub pattern_rules: Vec<PatternRuleSettings>,
+}
+
+/// A rule that reports the code that matches a pattern
+#[derive(Debug)]
+pub struct PatternRuleSettings {
+    /// The name of the rule
+    pub name: &'static str,
+    /// The code to report
+    pub pattern: String,
+    /// Constraints on the code matched by the metavariables of the pattern
+    pub constraints: Vec<(String, PatternConstraintSettings)>,
+    /// The message of the diagnostic
+    pub message: String,
+    /// The severity of the diagnostic
+    pub severity: Severity,
+    /// The code that replaces the reported code
+    pub rewrite: Option<String>,
+}
+
+/// A constraint on the source text of the code matched by a metavariable
+#[derive(Debug)]
+pub struct PatternConstraintSettings {
+    /// The code must match this regular expression
+    pub matches: Option<Regex>,
+    /// The code must not match this regular expression
+    pub not_matches: Option<Regex>,
+}
+
+impl PatternConstraintSettings {
+    /// Returns `true` if `text` satisfies the constraint
+    pub fn is_satisfied_by(&self, text: &str) -> bool {
+        self.matches
+            .as_ref()
+            .map_or(true, |regex| regex.is_match(text))
+            && self
+                .not_matches
+                .as_ref()
+                .map_or(true, |regex| !regex.is_match(text))
+    }
+}
+
 /// Linter settings for the entire workspace
 #[derive(Debug)]
 pub struct OverrideOrganizeImportsSettings {
@@ -504,12 +571,7 @@ impl<'a> SettingsHandle<'a> {
     where
         L: Language,
     {
-        L::resolve_format_options(
-            &self.inner.formatter,
-            &self.inner.override_settings,
-            &L::lookup_settings(&self.inner.languages).formatter,
-            path,
-        )
+        self.inner.format_options::<L>(path)
     }
 }
 
@@ -53,18 +53,21 @@
 
 use crate::file_handlers::Capabilities;
 use crate::{Deserialize, Serialize, WorkspaceError};
-use biome_analyze::ActionCategory;
 pub use biome_analyze::RuleCategories;
+use biome_analyze::{ActionCategory, RuleFilter};
 use biome_console::{markup, Markup, MarkupBuf};
 use biome_css_formatter::can_format_css_yet;
 use biome_diagnostics::CodeSuggestion;
 use biome_formatter::Printed;
 use biome_fs::RomePath;
+use biome_js_analyze::metadata;
 use biome_js_syntax::{TextRange, TextSize};
 use biome_text_edit::TextEdit;
 use std::collections::HashMap;
 use std::ffi::OsStr;
+use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
 use tracing::debug;
 
@@ -472,6 +475,80 @@ pub struct PullDiagnosticsParams {
     pub path: RomePath,
     pub categories: RuleCategories,
     pub max_diagnostics: u64,
+    /// When not empty, only these rules run, even when they are turned off in the configuration
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// These rules don't run
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
+}
+
+/// A group of rules, or a single rule, written `<group>` or `<group>/<rule>`,
+/// e.g. `style` or `suspicious/noDebugger`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RuleSelector {
+    Group(&'static str),
+    Rule(&'static str, &'static str),
+}
+
+impl FromStr for RuleSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let registry = metadata();
+        match s.split_once('/') {
+            None => registry
+                .find_group(s)
+                .map(|group| RuleSelector::Group(group.name()))
+                .ok_or_else(|| format!("The group {s} doesn't exist")),
+            Some((group, rule)) => registry
+                .find_rule(group, rule)
+                .map(|rule| RuleSelector::Rule(rule.group(), rule.rule_name()))
+                .ok_or_else(|| format!("The rule {s} doesn't exist")),
+        }
+    }
+}
+
+impl Display for RuleSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleSelector::Group(group) => write!(f, "{group}"),
+            RuleSelector::Rule(group, rule) => write!(f, "{group}/{rule}"),
+        }
+    }
+}
+
+impl From<RuleSelector> for RuleFilter<'static> {
+    fn from(selector: RuleSelector) -> Self {
+        match selector {
+            RuleSelector::Group(group) => RuleFilter::Group(group),
+            RuleSelector::Rule(group, rule) => RuleFilter::Rule(group, rule),
+        }
+    }
+}
+
+impl serde::Serialize for RuleSelector {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RuleSelector {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for RuleSelector {
+    fn schema_name() -> String {
+        String::from("RuleSelector")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <String>::json_schema(gen)
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -539,6 +616,12 @@ pub struct FixFileParams {
     pub path: RomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// When not empty, only the fixes of these rules are applied, even when they are turned off in the configuration
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// The fixes of these rules aren't applied
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -580,6 +663,21 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// Name of the export of the renamed binding, when the rename changes it. The files that
+    /// import this name from the renamed file must be updated with [Workspace::rename_imports]
+    pub exported_name: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RenameImportsParams {
+    /// The file that imports the renamed export
+    pub path: RomePath,
+    /// The file that exports the renamed binding
+    pub module_path: RomePath,
+    /// Name of the export before the rename
+    pub exported_name: String,
+    pub new_name: String,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
@@ -623,6 +721,392 @@ pub struct OrganizeImportsResult {
     pub code: String,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchPatternParams {
+    pub path: RomePath,
+    /// The code to search, where `$name` matches any node and `$$$name` matches
+    /// any number of elements of a list
+    pub pattern: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SearchPatternResult {
+    /// Ranges of the code that match the pattern
+    pub matches: Vec<TextRange>,
+    /// Whether the pattern could be parsed with the parser of the language of the file.
+    /// A pattern that can't be parsed doesn't match anything.
+    pub valid_pattern: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HoverParams {
+    pub path: RomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HoverResult {
+    /// Range of the node under the cursor
+    pub range: Option<TextRange>,
+    /// The binding that the identifier under the cursor declares or refers to
+    pub binding: Option<HoverBinding>,
+    /// Name of the rule group and rule under the cursor, e.g. a rule of the configuration file
+    pub rule_name: Option<(String, String)>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HoverBinding {
+    /// Name of the binding
+    pub name: String,
+    /// How the binding is declared
+    pub kind: BindingKind,
+    /// Range of the identifier that declares the binding
+    pub declaration_range: TextRange,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BindingKind {
+    Const,
+    Let,
+    Var,
+    Using,
+    Function,
+    Class,
+    Parameter,
+    TypeParameter,
+    Interface,
+    TypeAlias,
+    Enum,
+    Namespace,
+    Import,
+    CatchParameter,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GotoDefinitionParams {
+    pub path: RomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GotoDefinitionResult {
+    /// Range of the declaration of the binding under the cursor. For an imported binding, it's
+    /// the range of the import specifier
+    pub declaration_range: Option<TextRange>,
+    /// Specifier of the module under the cursor, e.g. `./utils`, when the cursor is on the source
+    /// of an import or an export, or on the binding declared by an import
+    pub module_specifier: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FindReferencesParams {
+    pub path: RomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FindReferencesResult {
+    /// The declaration and the references of the binding under the cursor, in the order they
+    /// appear in the file
+    pub references: Vec<BindingReference>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BindingReference {
+    /// Range of the identifier
+    pub range: TextRange,
+    pub kind: ReferenceKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReferenceKind {
+    /// The identifier declares the binding
+    Declaration,
+    /// The identifier reads the value of the binding
+    Read,
+    /// The identifier assigns a value to the binding
+    Write,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbolsParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbolsResult {
+    /// The symbols declared at the top level of the file, in the order they appear in the file
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbol {
+    /// Name of the symbol, e.g. the name of a function or the selector of a CSS rule
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Range of the whole declaration of the symbol
+    pub range: TextRange,
+    /// Range of the name of the symbol
+    pub selection_range: TextRange,
+    /// The symbols declared inside this symbol, e.g. the methods of a class
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SymbolKind {
+    Function,
+    Class,
+    Method,
+    Property,
+    Constructor,
+    Interface,
+    TypeAlias,
+    Enum,
+    EnumMember,
+    Namespace,
+    Variable,
+    Constant,
+    /// A CSS rule, e.g. `a:hover { }`
+    Rule,
+    /// A CSS at-rule, e.g. `@media print { }`
+    AtRule,
+    /// A JSON member whose value is an object
+    Object,
+    /// A JSON member whose value is an array
+    Array,
+    /// A JSON member whose value is a string
+    String,
+    /// A JSON member whose value is a number
+    Number,
+    /// A JSON member whose value is a boolean
+    Boolean,
+    /// A JSON member whose value is `null`
+    Null,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRangesParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRangesResult {
+    /// The ranges of the code that can be folded, sorted by their start
+    pub ranges: Vec<FoldingRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRange {
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FoldingRangeKind {
+    /// The code from an opening delimiter to its closing delimiter, e.g. a block, an object or a
+    /// JSX element. The line of the closing delimiter stays visible when the code is folded.
+    Block,
+    /// A comment that spans multiple lines, or single-line comments on consecutive lines
+    Comment,
+    /// Consecutive import statements
+    Imports,
+    /// The code from a `#region` comment to its `#endregion` comment
+    Region,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SelectionRangesParams {
+    pub path: RomePath,
+    pub offsets: Vec<TextSize>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SelectionRangesResult {
+    /// For each offset, the range of the token at the offset and the ranges of the nodes that
+    /// contain it, from the innermost node to the root
+    pub ranges: Vec<Vec<TextRange>>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticTokensParams {
+    pub path: RomePath,
+    /// The range of the tokens to return, or `None` to return the tokens of the whole file
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticTokensResult {
+    /// The identifiers classified by the binding that they declare or refer to, sorted by their
+    /// start
+    pub tokens: Vec<SemanticToken>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticToken {
+    pub range: TextRange,
+    pub kind: SemanticTokenKind,
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SemanticTokenKind {
+    Variable,
+    Parameter,
+    Function,
+    Class,
+    Interface,
+    /// A type alias, or a global type like `Promise`
+    Type,
+    TypeParameter,
+    Enum,
+    EnumMember,
+    Namespace,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SemanticTokenModifier {
+    /// The identifier declares the binding
+    Declaration,
+    /// The binding can't be assigned, e.g. a `const`, an import or an enum member
+    Readonly,
+    /// The binding is imported from another module
+    Import,
+    /// The identifier refers to a global that isn't declared in the file, e.g. `window`
+    Global,
+    /// The binding is never referenced and isn't exported
+    Unused,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SuppressionsParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SuppressionsResult {
+    /// The suppression comments of the file, sorted by their start
+    pub suppressions: Vec<Suppression>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Suppression {
+    /// The range of the comment
+    pub range: TextRange,
+    /// The comment doesn't suppress any diagnostic of the rules enabled for the file
+    pub unused: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CognitiveComplexityParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CognitiveComplexityResult {
+    /// The complexity of the functions of the file, sorted by their start
+    pub functions: Vec<FunctionComplexity>,
+    /// The maximum score allowed by `noExcessiveCognitiveComplexity`, or `None` when the rule
+    /// isn't enabled for the file
+    pub max_allowed_complexity: Option<u8>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FunctionComplexity {
+    /// The range of the name of the function, or of its `function` keyword or its arrow when
+    /// it's anonymous
+    pub range: TextRange,
+    /// The cognitive complexity score of the function. `255` means that the function is too
+    /// complex or too deeply nested to calculate an accurate score.
+    pub score: u8,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CompletionsParams {
+    pub path: RomePath,
+    /// The position of the cursor
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CompletionsResult {
+    /// The range of the text that the completions replace
+    pub range: TextRange,
+    /// The completions available at the cursor, sorted by their label
+    pub items: Vec<CompletionItem>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CompletionItem {
+    /// The name of the key or the value, as shown to the user
+    pub label: String,
+    pub kind: CompletionItemKind,
+    /// The text that replaces the range of the result
+    pub text: String,
+    /// The description of the key or the value, in markdown
+    pub documentation: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CompletionItemKind {
+    /// The key of a member of an object
+    Property,
+    /// A value, e.g. a member of an enum or a boolean
+    Value,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LinkedEditingRangesParams {
+    pub path: RomePath,
+    /// The position of the cursor
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LinkedEditingRangesResult {
+    /// The ranges that have the same text and change together, e.g. the names of the opening
+    /// and the closing tags of a JSX element. It's empty when the cursor isn't in such a range.
+    pub ranges: Vec<TextRange>,
+}
+
 impl RageEntry {
     pub fn section(name: &str) -> Self {
         Self::Section(name.to_string())
@@ -734,6 +1218,10 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Updates the imports and the re-exports of a file after the rename of an export of another
+    /// file. The local names are kept: `import { a }` becomes `import { b as a }`
+    fn rename_imports(&self, params: RenameImportsParams) -> Result<RenameResult, WorkspaceError>;
+
     /// Returns debug information about this workspace.
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError>;
 
@@ -745,6 +1233,80 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError>;
+
+    /// Searches the code that has the same syntax shape as a pattern
+    fn search_pattern(
+        &self,
+        params: SearchPatternParams,
+    ) -> Result<SearchPatternResult, WorkspaceError>;
+
+    /// Returns the information about the code at the given offset, e.g. the binding of an
+    /// identifier
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError>;
+
+    /// Returns where the binding or the module under the cursor is declared
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError>;
+
+    /// Returns the declaration and the references of the binding under the cursor
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError>;
+
+    /// Returns the symbols declared in a file, e.g. the functions and the classes of a
+    /// JavaScript file, nested in the symbols that contain them
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError>;
+
+    /// Returns the ranges of the code that can be folded, e.g. the blocks and the comments
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError>;
+
+    /// Returns, for each offset, the ranges that the selection goes through when it's expanded
+    /// from the offset: the range of the token, then the ranges of the nodes that contain it
+    fn selection_ranges(
+        &self,
+        params: SelectionRangesParams,
+    ) -> Result<SelectionRangesResult, WorkspaceError>;
+
+    /// Returns the identifiers of the file classified with the semantic model, e.g. the
+    /// parameters, the imports and the globals, so the editors can highlight them
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError>;
+
+    /// Returns the suppression comments of the file, and whether they still suppress a
+    /// diagnostic
+    fn suppressions(
+        &self,
+        params: SuppressionsParams,
+    ) -> Result<SuppressionsResult, WorkspaceError>;
+
+    /// Returns the cognitive complexity score of the functions of the file, as computed by
+    /// `noExcessiveCognitiveComplexity`
+    fn cognitive_complexity(
+        &self,
+        params: CognitiveComplexityParams,
+    ) -> Result<CognitiveComplexityResult, WorkspaceError>;
+
+    /// Returns the completions at the cursor: the keys and the values accepted by the
+    /// configuration file at that position
+    fn completions(&self, params: CompletionsParams) -> Result<CompletionsResult, WorkspaceError>;
+
+    /// Returns the ranges that must be edited together with the range under the cursor, e.g. the
+    /// names of the opening and the closing tags of a JSX element
+    fn linked_editing_ranges(
+        &self,
+        params: LinkedEditingRangesParams,
+    ) -> Result<LinkedEditingRangesResult, WorkspaceError>;
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
@@ -812,11 +1374,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         categories: RuleCategories,
         max_diagnostics: u64,
+        only: Vec<RuleSelector>,
+        skip: Vec<RuleSelector>,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
             categories,
             max_diagnostics,
+            only,
+            skip,
         })
     }
 
@@ -851,11 +1417,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         fix_file_mode: FixFileMode,
         should_format: bool,
+        only: Vec<RuleSelector>,
+        skip: Vec<RuleSelector>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            only,
+            skip,
         })
     }
 
@@ -864,6 +1434,103 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
         })
     }
+
+    pub fn search_pattern(&self, pattern: &str) -> Result<SearchPatternResult, WorkspaceError> {
+        self.workspace.search_pattern(SearchPatternParams {
+            path: self.path.clone(),
+            pattern: pattern.to_string(),
+        })
+    }
+
+    pub fn hover(&self, offset: TextSize) -> Result<HoverResult, WorkspaceError> {
+        self.workspace.hover(HoverParams {
+            path: self.path.clone(),
+            offset,
+        })
+    }
+
+    pub fn goto_definition(
+        &self,
+        offset: TextSize,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        self.workspace.goto_definition(GotoDefinitionParams {
+            path: self.path.clone(),
+            offset,
+        })
+    }
+
+    pub fn find_references(
+        &self,
+        offset: TextSize,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        self.workspace.find_references(FindReferencesParams {
+            path: self.path.clone(),
+            offset,
+        })
+    }
+
+    pub fn document_symbols(&self) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        self.workspace.document_symbols(DocumentSymbolsParams {
+            path: self.path.clone(),
+        })
+    }
+
+    pub fn folding_ranges(&self) -> Result<FoldingRangesResult, WorkspaceError> {
+        self.workspace.folding_ranges(FoldingRangesParams {
+            path: self.path.clone(),
+        })
+    }
+
+    pub fn selection_ranges(
+        &self,
+        offsets: Vec<TextSize>,
+    ) -> Result<SelectionRangesResult, WorkspaceError> {
+        self.workspace.selection_ranges(SelectionRangesParams {
+            path: self.path.clone(),
+            offsets,
+        })
+    }
+
+    pub fn semantic_tokens(
+        &self,
+        range: Option<TextRange>,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        self.workspace.semantic_tokens(SemanticTokensParams {
+            path: self.path.clone(),
+            range,
+        })
+    }
+
+    pub fn suppressions(&self) -> Result<SuppressionsResult, WorkspaceError> {
+        self.workspace.suppressions(SuppressionsParams {
+            path: self.path.clone(),
+        })
+    }
+
+    pub fn cognitive_complexity(&self) -> Result<CognitiveComplexityResult, WorkspaceError> {
+        self.workspace
+            .cognitive_complexity(CognitiveComplexityParams {
+                path: self.path.clone(),
+            })
+    }
+
+    pub fn completions(&self, offset: TextSize) -> Result<CompletionsResult, WorkspaceError> {
+        self.workspace.completions(CompletionsParams {
+            path: self.path.clone(),
+            offset,
+        })
+    }
+
+    pub fn linked_editing_ranges(
+        &self,
+        offset: TextSize,
+    ) -> Result<LinkedEditingRangesResult, WorkspaceError> {
+        self.workspace
+            .linked_editing_ranges(LinkedEditingRangesParams {
+                path: self.path.clone(),
+                offset,
+            })
+    }
 }
 
 impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
@@ -1,7 +1,12 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
+    CognitiveComplexityParams, CognitiveComplexityResult, CompletionsParams, CompletionsResult,
+    DocumentSymbolsParams, DocumentSymbolsResult, FileFeaturesResult, FindReferencesParams,
+    FindReferencesResult, FoldingRangesParams, FoldingRangesResult, GetFileContentParams,
+    GotoDefinitionParams, GotoDefinitionResult, HoverParams, HoverResult, IsPathIgnoredParams,
+    LinkedEditingRangesParams, LinkedEditingRangesResult, OrganizeImportsParams,
     OrganizeImportsResult, ProjectFeaturesParams, ProjectFeaturesResult, RageParams, RageResult,
-    ServerInfo,
+    SearchPatternParams, SearchPatternResult, SelectionRangesParams, SelectionRangesResult,
+    SemanticTokensParams, SemanticTokensResult, ServerInfo, SuppressionsParams, SuppressionsResult,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -16,7 +21,7 @@ use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, RenameImportsParams, RenameParams, RenameResult,
     SupportsFeatureParams, UpdateSettingsParams,
 };
 
@@ -188,6 +193,10 @@ where
         self.request("biome/rename", params)
     }
 
+    fn rename_imports(&self, params: RenameImportsParams) -> Result<RenameResult, WorkspaceError> {
+        self.request("biome/rename_imports", params)
+    }
+
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
         self.request("biome/rage", params)
     }
@@ -202,4 +211,82 @@ where
     ) -> Result<OrganizeImportsResult, WorkspaceError> {
         self.request("biome/organize_imports", params)
     }
+
+    fn search_pattern(
+        &self,
+        params: SearchPatternParams,
+    ) -> Result<SearchPatternResult, WorkspaceError> {
+        self.request("biome/search_pattern", params)
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError> {
+        self.request("biome/hover", params)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        self.request("biome/goto_definition", params)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        self.request("biome/find_references", params)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/document_symbols", params)
+    }
+
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError> {
+        self.request("biome/folding_ranges", params)
+    }
+
+    fn selection_ranges(
+        &self,
+        params: SelectionRangesParams,
+    ) -> Result<SelectionRangesResult, WorkspaceError> {
+        self.request("biome/selection_ranges", params)
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        self.request("biome/semantic_tokens", params)
+    }
+
+    fn suppressions(
+        &self,
+        params: SuppressionsParams,
+    ) -> Result<SuppressionsResult, WorkspaceError> {
+        self.request("biome/suppressions", params)
+    }
+
+    fn cognitive_complexity(
+        &self,
+        params: CognitiveComplexityParams,
+    ) -> Result<CognitiveComplexityResult, WorkspaceError> {
+        self.request("biome/cognitive_complexity", params)
+    }
+
+    fn completions(&self, params: CompletionsParams) -> Result<CompletionsResult, WorkspaceError> {
+        self.request("biome/completions", params)
+    }
+
+    fn linked_editing_ranges(
+        &self,
+        params: LinkedEditingRangesParams,
+    ) -> Result<LinkedEditingRangesResult, WorkspaceError> {
+        self.request("biome/linked_editing_ranges", params)
+    }
 }
@@ -2,23 +2,29 @@ use super::{
     ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SupportsFeatureParams,
-    UpdateSettingsParams,
+    PullDiagnosticsParams, PullDiagnosticsResult, RenameImportsParams, RenameResult,
+    SupportsFeatureParams, UpdateSettingsParams,
 };
 use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
 use crate::project_handlers::{ProjectCapabilities, ProjectHandlers};
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    CognitiveComplexityParams, CognitiveComplexityResult, CompletionsParams, CompletionsResult,
+    DocumentSymbolsParams, DocumentSymbolsResult, FileFeaturesResult, FindReferencesParams,
+    FindReferencesResult, FoldingRangesParams, FoldingRangesResult, GetFileContentParams,
+    GotoDefinitionParams, GotoDefinitionResult, HoverParams, HoverResult, IsPathIgnoredParams,
+    LinkedEditingRangesParams, LinkedEditingRangesResult, OrganizeImportsParams,
+    OrganizeImportsResult, RageEntry, RageParams, RageResult, RuleSelector, SearchPatternParams,
+    SearchPatternResult, SelectionRangesParams, SelectionRangesResult, SemanticTokensParams,
+    SemanticTokensResult, ServerInfo, SuppressionsParams, SuppressionsResult,
 };
 use crate::{
     file_handlers::Features,
     settings::{SettingsHandle, WorkspaceSettings},
-    Workspace, WorkspaceError,
+    Rules, Workspace, WorkspaceError,
 };
-use biome_analyze::{AnalysisFilter, RuleFilter};
+use biome_analyze::{profiling, AnalysisFilter, RuleCategories, RuleFilter, PLUGIN_GROUP};
 use biome_diagnostics::{
-    serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
+    category, serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
 use biome_fs::{RomePath, BIOME_JSON};
@@ -170,13 +176,15 @@ impl WorkspaceServer {
                 }
 
                 let settings = self.settings();
-                let parsed = parse(
-                    rome_path,
-                    document.language_hint,
-                    document.content.as_str(),
-                    settings,
-                    &mut document.node_cache,
-                );
+                let parsed = profiling::time_phase("parse", || {
+                    parse(
+                        rome_path,
+                        document.language_hint,
+                        document.content.as_str(),
+                        settings,
+                        &mut document.node_cache,
+                    )
+                });
 
                 Ok(entry.insert(parsed).clone())
             }
@@ -436,16 +444,16 @@ impl Workspace for WorkspaceServer {
         {
             // Compite final rules (taking `overrides` into account)
             let rules = settings.as_rules(params.path.as_path());
-            let mut rule_filter_list = rules
-                .as_ref()
-                .map(|rules| rules.as_enabled_rules())
-                .unwrap_or_default()
-                .into_iter()
-                .collect::<Vec<_>>();
-            if settings.organize_imports.enabled && !params.categories.is_syntax() {
-                rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
+            let mut rule_filter_list = rules_to_run(rules.as_deref(), &params.only);
+            if params.only.is_empty() && !params.categories.is_syntax() {
+                if settings.organize_imports.enabled {
+                    rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
+                }
+                rule_filter_list.push(RuleFilter::Group(PLUGIN_GROUP));
             }
+            let skipped_rules = to_rule_filters(&params.skip);
             let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+            filter.disabled_rules = Some(skipped_rules.as_slice());
             filter.categories = params.categories;
 
             info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
@@ -528,7 +536,7 @@ impl Workspace for WorkspaceServer {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
-        format(&params.path, parse, settings)
+        profiling::time_phase("format", || format(&params.path, parse, settings))
     }
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
@@ -544,7 +552,9 @@ impl Workspace for WorkspaceServer {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
-        format_range(&params.path, parse, settings, params.range)
+        profiling::time_phase("format", || {
+            format_range(&params.path, parse, settings, params.range)
+        })
     }
 
     fn format_on_type(&self, params: FormatOnTypeParams) -> Result<Printed, WorkspaceError> {
@@ -560,7 +570,9 @@ impl Workspace for WorkspaceServer {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
-        format_on_type(&params.path, parse, settings, params.offset)
+        profiling::time_phase("format", || {
+            format_on_type(&params.path, parse, settings, params.offset)
+        })
     }
 
     fn fix_file(&self, params: super::FixFileParams) -> Result<FixFileResult, WorkspaceError> {
@@ -573,13 +585,13 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(params.path.clone())?;
         // Compite final rules (taking `overrides` into account)
         let rules = settings.as_rules(params.path.as_path());
-        let rule_filter_list = rules
-            .as_ref()
-            .map(|rules| rules.as_enabled_rules())
-            .unwrap_or_default()
-            .into_iter()
-            .collect::<Vec<_>>();
-        let filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+        let mut rule_filter_list = rules_to_run(rules.as_deref(), &params.only);
+        if params.only.is_empty() {
+            rule_filter_list.push(RuleFilter::Group(PLUGIN_GROUP));
+        }
+        let skipped_rules = to_rule_filters(&params.skip);
+        let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+        filter.disabled_rules = Some(skipped_rules.as_slice());
         fix_all(FixAllParams {
             parse,
             rules: rules.as_ref().map(|x| x.borrow()),
@@ -604,6 +616,25 @@ impl Workspace for WorkspaceServer {
         Ok(result)
     }
 
+    fn rename_imports(&self, params: RenameImportsParams) -> Result<RenameResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let rename_imports = capabilities
+            .analyzer
+            .rename_imports
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = rename_imports(
+            &params.path,
+            parse,
+            &params.module_path,
+            &params.exported_name,
+            &params.new_name,
+        )?;
+
+        Ok(result)
+    }
+
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
         let entries = vec![
             RageEntry::section("Workspace"),
@@ -632,4 +663,226 @@ impl Workspace for WorkspaceServer {
 
         Ok(result)
     }
+
+    fn search_pattern(
+        &self,
+        params: SearchPatternParams,
+    ) -> Result<SearchPatternResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let search = capabilities
+            .analyzer
+            .search
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = search(&params.path, parse, &params.pattern)?;
+
+        Ok(result)
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let hover = capabilities
+            .analyzer
+            .hover
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = hover(&params.path, parse, params.offset)?;
+
+        Ok(result)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let goto_definition = capabilities
+            .analyzer
+            .goto_definition
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = goto_definition(&params.path, parse, params.offset)?;
+
+        Ok(result)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let find_references = capabilities
+            .analyzer
+            .find_references
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = find_references(&params.path, parse, params.offset)?;
+
+        Ok(result)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let document_symbols = capabilities
+            .analyzer
+            .document_symbols
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = document_symbols(parse)?;
+
+        Ok(result)
+    }
+
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let folding_ranges = capabilities
+            .analyzer
+            .folding_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = folding_ranges(parse)?;
+
+        Ok(result)
+    }
+
+    fn selection_ranges(
+        &self,
+        params: SelectionRangesParams,
+    ) -> Result<SelectionRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let selection_ranges = capabilities
+            .analyzer
+            .selection_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = selection_ranges(parse, &params.offsets)?;
+
+        Ok(result)
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let semantic_tokens = capabilities
+            .analyzer
+            .semantic_tokens
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = semantic_tokens(parse, params.range)?;
+
+        Ok(result)
+    }
+
+    fn suppressions(
+        &self,
+        params: SuppressionsParams,
+    ) -> Result<SuppressionsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let suppressions = capabilities
+            .analyzer
+            .suppressions
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let mut result = suppressions(parse)?;
+
+        // The linter reports the comments that didn't suppress any diagnostic
+        let diagnostics = self.pull_diagnostics(PullDiagnosticsParams {
+            path: params.path,
+            categories: RuleCategories::LINT,
+            max_diagnostics: u64::MAX,
+            only: Vec::new(),
+            skip: Vec::new(),
+        })?;
+        let unused_ranges: Vec<_> = diagnostics
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.category() == Some(category!("suppressions/unused")))
+            .filter_map(|diagnostic| diagnostic.location().span)
+            .collect();
+        for suppression in &mut result.suppressions {
+            suppression.unused = unused_ranges.contains(&suppression.range);
+        }
+
+        Ok(result)
+    }
+
+    fn cognitive_complexity(
+        &self,
+        params: CognitiveComplexityParams,
+    ) -> Result<CognitiveComplexityResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let cognitive_complexity = capabilities
+            .analyzer
+            .cognitive_complexity
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = cognitive_complexity(&params.path, parse, self.settings())?;
+
+        Ok(result)
+    }
+
+    fn completions(&self, params: CompletionsParams) -> Result<CompletionsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let completions = capabilities
+            .analyzer
+            .completions
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        let result = completions(&params.path, parse, params.offset)?;
+
+        Ok(result)
+    }
+
+    fn linked_editing_ranges(
+        &self,
+        params: LinkedEditingRangesParams,
+    ) -> Result<LinkedEditingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let linked_editing_ranges = capabilities
+            .analyzer
+            .linked_editing_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        let result = linked_editing_ranges(parse, params.offset)?;
+
+        Ok(result)
+    }
+}
+
+/// Returns the rules that the analyzer runs: the rules selected with `only`, even when they are
+/// turned off in the configuration, or the rules enabled by the configuration when `only` is empty
+fn rules_to_run<'a>(rules: Option<&'a Rules>, only: &[RuleSelector]) -> Vec<RuleFilter<'a>> {
+    if only.is_empty() {
+        rules
+            .map(|rules| rules.as_enabled_rules())
+            .unwrap_or_default()
+            .into_iter()
+            .collect()
+    } else {
+        to_rule_filters(only)
+    }
+}
+
+fn to_rule_filters(selectors: &[RuleSelector]) -> Vec<RuleFilter<'static>> {
+    selectors.iter().copied().map(RuleFilter::from).collect()
 }
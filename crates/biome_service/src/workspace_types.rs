@@ -453,7 +453,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 18] {
+pub fn methods() -> [WorkspaceMethod; 31] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
@@ -473,5 +473,18 @@ pub fn methods() -> [WorkspaceMethod; 18] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(rename_imports),
+        workspace_method!(search_pattern),
+        workspace_method!(hover),
+        workspace_method!(goto_definition),
+        workspace_method!(find_references),
+        workspace_method!(document_symbols),
+        workspace_method!(folding_ranges),
+        workspace_method!(selection_ranges),
+        workspace_method!(semantic_tokens),
+        workspace_method!(suppressions),
+        workspace_method!(cognitive_complexity),
+        workspace_method!(completions),
+        workspace_method!(linked_editing_ranges),
     ]
 }
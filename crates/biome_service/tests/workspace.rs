@@ -1,6 +1,13 @@
+use biome_analyze::RuleCategories;
+use biome_diagnostics::Diagnostic;
 use biome_fs::RomePath;
-use biome_js_syntax::TextSize;
-use biome_service::workspace::{server, FileGuard, Language, OpenFileParams};
+use biome_js_analyze::utils::rename::RenameError;
+use biome_js_syntax::{TextRange, TextSize};
+use biome_service::workspace::{
+    server, BindingKind, DocumentSymbol, FileGuard, HoverResult, Language, OpenFileParams,
+    ReferenceKind, RenameImportsParams, RenameParams, RenameResult,
+};
+use biome_service::WorkspaceError;
 
 #[test]
 fn debug_control_flow() {
@@ -27,3 +34,1051 @@ fn debug_control_flow() {
 
     assert_eq!(cfg, GRAPH);
 }
+
+fn search(path: &str, language_hint: Language, source: &str, pattern: &str) -> Vec<String> {
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new(path),
+            content: source.into(),
+            version: 0,
+            language_hint,
+        },
+    )
+    .unwrap();
+
+    let result = file.search_pattern(pattern).unwrap();
+    result
+        .matches
+        .into_iter()
+        .map(|range| source[range].to_string())
+        .collect()
+}
+
+#[test]
+fn search_pattern_with_metavariables() {
+    const SOURCE: &str = "console.log(a);
+console . error ( /* comment */ 'message' , b , );
+console.log();
+logger.log(a);";
+
+    assert_eq!(
+        search(
+            "file.js",
+            Language::JavaScript,
+            SOURCE,
+            "console.$method($$$args)"
+        ),
+        vec![
+            "console.log(a)",
+            "console . error ( /* comment */ 'message' , b , )",
+            "console.log()"
+        ]
+    );
+    assert_eq!(
+        search(
+            "file.js",
+            Language::JavaScript,
+            SOURCE,
+            "console.$method($arg)"
+        ),
+        vec!["console.log(a)"]
+    );
+    assert_eq!(
+        search("file.js", Language::JavaScript, SOURCE, "$object.log(a)"),
+        vec!["console.log(a)", "logger.log(a)"]
+    );
+}
+
+#[test]
+fn search_pattern_with_repeated_metavariables() {
+    const SOURCE: &str = "useEffect(() => {}, []);
+useEffect(callback, [dependency]);
+a === a;
+a === b;";
+
+    assert_eq!(
+        search(
+            "file.jsx",
+            Language::JavaScriptReact,
+            SOURCE,
+            "useEffect($fn, [])"
+        ),
+        vec!["useEffect(() => {}, [])"]
+    );
+    assert_eq!(
+        search("file.jsx", Language::JavaScriptReact, SOURCE, "$x === $x"),
+        vec!["a === a"]
+    );
+}
+
+#[test]
+fn search_pattern_in_typescript() {
+    const SOURCE: &str = "const a: Array<string> = [];
+let b: Array<number>;";
+
+    assert_eq!(
+        search(
+            "file.ts",
+            Language::TypeScript,
+            SOURCE,
+            "let $name: Array<$type>;"
+        ),
+        vec!["let b: Array<number>;"]
+    );
+}
+
+#[test]
+fn search_pattern_in_json() {
+    const SOURCE: &str = r#"{
+    "dependencies": { "react": "18.0.0" },
+    "devDependencies": { "typescript": "5.0.0", "react": "18.0.0" }
+}"#;
+
+    assert_eq!(
+        search(
+            "file.json",
+            Language::Json,
+            SOURCE,
+            r#"{ "$name": "18.0.0" }"#
+        ),
+        vec![r#"{ "react": "18.0.0" }"#]
+    );
+    assert_eq!(
+        search(
+            "file.json",
+            Language::Json,
+            SOURCE,
+            r#"{ "typescript": "$version", "react": "$version" }"#
+        )
+        .len(),
+        0
+    );
+    assert_eq!(
+        search(
+            "file.json",
+            Language::Json,
+            SOURCE,
+            r#"{ "typescript": "$typescript", "react": "$react" }"#
+        ),
+        vec![r#"{ "typescript": "5.0.0", "react": "18.0.0" }"#]
+    );
+}
+
+#[test]
+fn search_invalid_pattern() {
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: "a;".into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    let result = file.search_pattern("console.log(").unwrap();
+    assert!(!result.valid_pattern);
+    assert!(result.matches.is_empty());
+}
+
+fn hover(path: &str, language_hint: Language, source: &str, cursor: &str) -> HoverResult {
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new(path),
+            content: source.into(),
+            version: 0,
+            language_hint,
+        },
+    )
+    .unwrap();
+
+    let offset = source.find(cursor).expect("the cursor is in the source");
+    file.hover(TextSize::from(offset as u32)).unwrap()
+}
+
+#[test]
+fn hover_shows_the_declaration_of_a_reference() {
+    const SOURCE: &str = "import { join } from 'node:path';
+const separator = '/';
+function concat(a, b) {
+    return join(a, separator, b);
+}";
+
+    let result = hover("file.js", Language::JavaScript, SOURCE, "separator, b");
+    let binding = result.binding.unwrap();
+    assert_eq!(binding.name, "separator");
+    assert_eq!(binding.kind, BindingKind::Const);
+    assert_eq!(&SOURCE[binding.declaration_range], "separator");
+    assert_eq!(binding.declaration_range.start(), TextSize::from(40));
+
+    let result = hover("file.js", Language::JavaScript, SOURCE, "join(a");
+    assert_eq!(result.binding.unwrap().kind, BindingKind::Import);
+
+    let result = hover("file.js", Language::JavaScript, SOURCE, "b);");
+    assert_eq!(result.binding.unwrap().kind, BindingKind::Parameter);
+
+    let result = hover("file.js", Language::JavaScript, SOURCE, "concat");
+    assert_eq!(result.binding.unwrap().kind, BindingKind::Function);
+
+    let result = hover("file.js", Language::JavaScript, SOURCE, "return");
+    assert!(result.binding.is_none());
+}
+
+#[test]
+fn hover_shows_the_rules_of_the_configuration_file() {
+    const SOURCE: &str = r#"{
+    "linter": {
+        "rules": {
+            "suspicious": { "noDebugger": "off" }
+        }
+    },
+    "overrides": [
+        { "linter": { "rules": { "style": { "useConst": "error" } } } }
+    ]
+}"#;
+
+    let result = hover("biome.json", Language::Json, SOURCE, "noDebugger");
+    assert_eq!(
+        result.rule_name,
+        Some(("suspicious".to_string(), "noDebugger".to_string()))
+    );
+
+    let result = hover("biome.json", Language::Json, SOURCE, "useConst");
+    assert_eq!(
+        result.rule_name,
+        Some(("style".to_string(), "useConst".to_string()))
+    );
+
+    let result = hover("biome.json", Language::Json, SOURCE, "suspicious");
+    assert_eq!(result.rule_name, None);
+
+    let result = hover("file.json", Language::Json, SOURCE, "noDebugger");
+    assert_eq!(result.rule_name, None);
+}
+
+#[test]
+fn goto_definition_returns_the_declaration_or_the_imported_module() {
+    const SOURCE: &str = "import { join as joinPath } from './path';
+export { separator } from '../constants';
+let count = 0;
+count = joinPath(count);";
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+    let offset = |cursor: &str| TextSize::from(SOURCE.rfind(cursor).unwrap() as u32);
+
+    let result = file.goto_definition(offset("count);")).unwrap();
+    assert_eq!(&SOURCE[result.declaration_range.unwrap()], "count");
+    assert_eq!(
+        result.declaration_range.unwrap().start(),
+        TextSize::from(SOURCE.find("count").unwrap() as u32)
+    );
+    assert_eq!(result.module_specifier, None);
+
+    // A reference to an import goes to the import specifier
+    let result = file.goto_definition(offset("joinPath(")).unwrap();
+    assert_eq!(
+        &SOURCE[result.declaration_range.unwrap()],
+        "join as joinPath"
+    );
+    assert_eq!(result.module_specifier, None);
+
+    // The binding declared by the import goes to the imported module
+    let result = file.goto_definition(offset("joinPath }")).unwrap();
+    assert_eq!(result.module_specifier.as_deref(), Some("./path"));
+
+    let result = file.goto_definition(offset("../constants")).unwrap();
+    assert_eq!(result.declaration_range, None);
+    assert_eq!(result.module_specifier.as_deref(), Some("../constants"));
+
+    let result = file.goto_definition(offset("let")).unwrap();
+    assert_eq!(result.declaration_range, None);
+    assert_eq!(result.module_specifier, None);
+}
+
+#[test]
+fn find_references_returns_the_declaration_and_the_references() {
+    const SOURCE: &str = "callback();
+function callback() {}
+let count = 0;
+count += 1;
+console.log(count);";
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+    let offset = |cursor: &str| TextSize::from(SOURCE.find(cursor).unwrap() as u32);
+
+    let result = file.find_references(offset("count);")).unwrap();
+    let references: Vec<_> = result
+        .references
+        .iter()
+        .map(|reference| (reference.range.start(), reference.kind))
+        .collect();
+    assert_eq!(
+        references,
+        [
+            (offset("count = 0"), ReferenceKind::Declaration),
+            (offset("count += 1"), ReferenceKind::Write),
+            (offset("count);"), ReferenceKind::Read),
+        ]
+    );
+
+    // The references are sorted even when the binding is hoisted
+    let result = file.find_references(offset("callback() {}")).unwrap();
+    let kinds: Vec<_> = result
+        .references
+        .iter()
+        .map(|reference| reference.kind)
+        .collect();
+    assert_eq!(kinds, [ReferenceKind::Read, ReferenceKind::Declaration]);
+
+    let result = file.find_references(offset("console")).unwrap();
+    assert!(result.references.is_empty());
+}
+
+fn rename(source: &str, cursor: &str, new_name: &str) -> Result<RenameResult, WorkspaceError> {
+    let workspace = server();
+
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: source.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    let offset = source.find(cursor).expect("the cursor is in the source");
+    workspace.rename(RenameParams {
+        path: RomePath::new("file.js"),
+        symbol_at: TextSize::from(offset as u32),
+        new_name: new_name.to_string(),
+    })
+}
+
+#[test]
+fn rename_returns_the_renamed_export() {
+    const SOURCE: &str = "export function add() {}
+const sub = () => {};
+export { sub };
+const mul = () => {};
+export { mul as times };
+export default function div() {}";
+
+    let result = rename(SOURCE, "add", "sum").unwrap();
+    assert_eq!(result.exported_name.as_deref(), Some("add"));
+
+    let result = rename(SOURCE, "sub =", "subtract").unwrap();
+    assert_eq!(result.exported_name.as_deref(), Some("sub"));
+
+    let result = rename(SOURCE, "mul =", "multiply").unwrap();
+    assert_eq!(result.exported_name, None);
+
+    let result = rename(SOURCE, "div", "divide").unwrap();
+    assert_eq!(result.exported_name, None);
+}
+
+#[test]
+fn rename_rejects_the_reserved_words_and_the_conflicts() {
+    const SOURCE: &str = "let a = 1; let b = 2;";
+
+    for new_name in ["class", "null", "yield", "let", "1a", "a-b"] {
+        assert!(
+            matches!(
+                rename(SOURCE, "a =", new_name),
+                Err(WorkspaceError::RenameError(RenameError::InvalidName(_)))
+            ),
+            "{new_name} is a valid name"
+        );
+    }
+    assert!(matches!(
+        rename(SOURCE, "a =", "b"),
+        Err(WorkspaceError::RenameError(
+            RenameError::CannotBeRenamed { .. }
+        ))
+    ));
+    assert!(rename(SOURCE, "a =", "async").is_ok());
+}
+
+#[test]
+fn rename_imports_keeps_the_local_names() {
+    const SOURCE: &str = "import { add, sub } from './utils';
+import { add as plus } from '../src/utils.js';
+import { add as other } from './other';
+export { add } from './utils';
+export { add as sum } from './utils';
+add(1, 2);";
+
+    let workspace = server();
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("/project/src/app.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    let result = workspace
+        .rename_imports(RenameImportsParams {
+            path: RomePath::new("/project/src/app.js"),
+            module_path: RomePath::new("/project/src/utils.ts"),
+            exported_name: "add".to_string(),
+            new_name: "addition".to_string(),
+        })
+        .unwrap();
+
+    assert_eq!(
+        result.indels.new_string(SOURCE),
+        "import { addition as add, sub } from './utils';
+import { addition as plus } from '../src/utils.js';
+import { add as other } from './other';
+export { addition as add } from './utils';
+export { addition as sum } from './utils';
+add(1, 2);"
+    );
+}
+
+/// Returns the outline of a file: one line per symbol, with its kind and its name, indented
+/// under the symbol that contains it
+fn document_symbols(path: &str, language_hint: Language, source: &str) -> String {
+    fn print_symbols(symbols: &[DocumentSymbol], depth: usize, outline: &mut String) {
+        for symbol in symbols {
+            outline.push_str(&format!(
+                "{}{:?} {}\n",
+                "  ".repeat(depth),
+                symbol.kind,
+                symbol.name
+            ));
+            print_symbols(&symbol.children, depth + 1, outline);
+        }
+    }
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new(path),
+            content: source.into(),
+            version: 0,
+            language_hint,
+        },
+    )
+    .unwrap();
+
+    let mut outline = String::new();
+    print_symbols(&file.document_symbols().unwrap().symbols, 0, &mut outline);
+    outline
+}
+
+#[test]
+fn document_symbols_of_a_typescript_file() {
+    const SOURCE: &str = "import { join } from 'node:path';
+const internal = 1;
+export const VERSION = '1.0', handler = () => {
+    function nested() {}
+};
+export default function () {}
+export class User {
+    #id = 0;
+    constructor() {}
+    get name() { return ''; }
+    greet() {}
+}
+interface Shape {
+    area(): number;
+    sides: number;
+}
+type Id = string;
+enum Color { Red, Green }
+namespace Geometry {
+    export function distance() {}
+}";
+
+    assert_eq!(
+        document_symbols("file.ts", Language::TypeScript, SOURCE),
+        "Constant VERSION
+Constant handler
+  Function nested
+Function default
+Class User
+  Property #id
+  Constructor constructor
+  Property name
+  Method greet
+Interface Shape
+  Method area
+  Property sides
+TypeAlias Id
+Enum Color
+  EnumMember Red
+  EnumMember Green
+Namespace Geometry
+  Function distance
+"
+    );
+}
+
+#[test]
+fn document_symbols_of_css_and_json_files() {
+    const CSS: &str = "a:hover,
+a:focus { color: red; }
+@media (min-width: 600px) {
+    .card { display: flex; }
+}
+@import url(\"theme.css\");";
+
+    assert_eq!(
+        document_symbols("file.css", Language::Css, CSS),
+        "Rule a:hover, a:focus
+AtRule @media (min-width: 600px)
+  Rule .card
+AtRule @import url(\"theme.css\")
+"
+    );
+
+    const JSON: &str = r#"{
+    "name": "app",
+    "private": true,
+    "files": ["index.js"],
+    "overrides": [{ "include": ["test"] }],
+    "scripts": { "test": "node test.js" }
+}"#;
+
+    assert_eq!(
+        document_symbols("package.json", Language::Json, JSON),
+        "String name
+Boolean private
+Array files
+Array overrides
+  Object 0
+    Array include
+Object scripts
+  String test
+"
+    );
+}
+
+/// Returns the kinds of the folding ranges of the file, with the lines where they start and end
+fn folding_ranges(path: &str, language_hint: Language, source: &str) -> Vec<String> {
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new(path),
+            content: source.into(),
+            version: 0,
+            language_hint,
+        },
+    )
+    .unwrap();
+
+    let line = |offset: TextSize| source[..usize::from(offset)].matches('\n').count() + 1;
+    file.folding_ranges()
+        .unwrap()
+        .ranges
+        .into_iter()
+        .map(|range| {
+            format!(
+                "{:?} {}-{}",
+                range.kind,
+                line(range.range.start()),
+                line(range.range.end())
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn folding_ranges_of_a_jsx_file() {
+    const SOURCE: &str = r#"// #region Imports
+import a from "a";
+import { b } from "b";
+// #endregion
+
+/**
+ * Renders the items
+ */
+function List(props) {
+    // The items are
+    // never empty
+    return <ul>
+        {props.items.map((item) => <li>{item}</li>)}
+    </ul>;
+}"#;
+
+    assert_eq!(
+        folding_ranges("file.jsx", Language::JavaScriptReact, SOURCE),
+        [
+            "Region 1-4",
+            "Imports 2-3",
+            "Block 3-3",
+            "Comment 6-8",
+            "Block 9-9",
+            "Block 9-15",
+            "Comment 10-11",
+            "Block 12-14",
+            "Block 13-13",
+            "Block 13-13",
+            "Block 13-13",
+            "Block 13-13",
+            "Block 13-13",
+        ]
+    );
+}
+
+#[test]
+fn folding_ranges_of_css_and_json_files() {
+    const CSS: &str = "/* The
+   cards */
+.card {
+    color: red;
+}";
+
+    assert_eq!(
+        folding_ranges("file.css", Language::Css, CSS),
+        ["Comment 1-2", "Block 3-5"]
+    );
+
+    const JSON: &str = r#"{
+    "files": [
+        "index.js"
+    ]
+}"#;
+
+    assert_eq!(
+        folding_ranges("file.json", Language::Json, JSON),
+        ["Block 1-5", "Block 2-4"]
+    );
+}
+
+#[test]
+fn selection_ranges_from_the_token_to_the_root() {
+    const SOURCE: &str = "function sum(a, b) { return a + b; }";
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    // In `a + b`, and between `sum` and `(`
+    let offsets = vec![TextSize::from(28), TextSize::from(12)];
+    let result = file.selection_ranges(offsets).unwrap();
+    let texts = |ranges: &[TextRange]| {
+        ranges
+            .iter()
+            .map(|range| &SOURCE[*range])
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        texts(&result.ranges[0]),
+        [
+            "a",
+            "a + b",
+            "return a + b;",
+            "{ return a + b; }",
+            "function sum(a, b) { return a + b; }"
+        ]
+    );
+    assert_eq!(
+        texts(&result.ranges[1]),
+        ["sum", "function sum(a, b) { return a + b; }"]
+    );
+}
+
+#[test]
+fn semantic_tokens_of_a_typescript_file() {
+    const SOURCE: &str = r#"import { used, unused } from "mod";
+const limit = 10;
+let count = 0;
+enum Color { Red }
+function paint(color: Color, _ignored: number): Promise<void> {
+    count = used(limit, Color.Red, window);
+}
+export { paint };"#;
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.ts"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::TypeScript,
+        },
+    )
+    .unwrap();
+
+    let tokens = |range: Option<TextRange>| {
+        file.semantic_tokens(range)
+            .unwrap()
+            .tokens
+            .into_iter()
+            .map(|token| {
+                format!(
+                    "{} {:?} {:?}",
+                    &SOURCE[token.range], token.kind, token.modifiers
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        tokens(None),
+        [
+            "used Variable [Import, Readonly, Declaration]",
+            "unused Variable [Import, Readonly, Declaration, Unused]",
+            "limit Variable [Readonly, Declaration]",
+            "count Variable [Declaration]",
+            "Color Enum [Declaration]",
+            "Red EnumMember [Declaration, Readonly]",
+            "paint Function [Declaration]",
+            "color Parameter [Declaration, Unused]",
+            "Color Enum []",
+            "_ignored Parameter [Declaration]",
+            "Promise Type [Global]",
+            "count Variable []",
+            "used Variable [Import, Readonly]",
+            "limit Variable [Readonly]",
+            "Color Enum []",
+            "Red EnumMember [Readonly]",
+            "window Variable [Global]",
+            "paint Function []",
+        ]
+    );
+
+    let line_start = SOURCE.find("    count").unwrap() as u32;
+    let line_end = SOURCE.find("window").unwrap() as u32;
+    assert_eq!(
+        tokens(Some(TextRange::new(line_start.into(), line_end.into()))),
+        [
+            "count Variable []",
+            "used Variable [Import, Readonly]",
+            "limit Variable [Readonly]",
+            "Color Enum []",
+            "Red EnumMember [Readonly]",
+        ]
+    );
+}
+
+#[test]
+fn suppressions_used_and_unused() {
+    const SOURCE: &str = r#"// biome-ignore lint/suspicious/noDebugger: debugging
+debugger;
+// biome-ignore lint/suspicious/noDebugger: the debugger was removed
+console.log("done");
+let count = 0; // not a suppression
+"#;
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    let suppressions = file
+        .suppressions()
+        .unwrap()
+        .suppressions
+        .into_iter()
+        .map(|suppression| (&SOURCE[suppression.range], suppression.unused))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        suppressions,
+        [
+            (
+                "// biome-ignore lint/suspicious/noDebugger: debugging",
+                false
+            ),
+            (
+                "// biome-ignore lint/suspicious/noDebugger: the debugger was removed",
+                true
+            ),
+        ]
+    );
+}
+
+#[test]
+fn cognitive_complexity_of_the_functions() {
+    const SOURCE: &str = r#"function simple() {
+    return 1;
+}
+const nested = (a, b) => {
+    if (a) {
+        for (const item of b) {
+            if (item) {
+                return a && b || item;
+            }
+        }
+    }
+};
+[].map(function () {});
+"#;
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    let result = file.cognitive_complexity().unwrap();
+    let functions = result
+        .functions
+        .into_iter()
+        .map(|function| (&SOURCE[function.range], function.score))
+        .collect::<Vec<_>>();
+
+    assert_eq!(functions, [("simple", 0), ("=>", 8), ("function", 0)]);
+    // The rule isn't recommended, so it isn't enabled by default
+    assert_eq!(result.max_allowed_complexity, None);
+}
+
+/// Returns the range replaced by the completions at the `|` of `source`, and the labels and the
+/// texts of the completions
+#[cfg(feature = "schema")]
+fn configuration_completions(source: &str) -> (String, Vec<(String, String)>) {
+    let offset = source.find('|').expect("the cursor is in the source");
+    let content = source.replacen('|', "", 1);
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("biome.json"),
+            content: content.clone(),
+            version: 0,
+            language_hint: Language::Json,
+        },
+    )
+    .unwrap();
+
+    let result = file.completions(TextSize::from(offset as u32)).unwrap();
+    let items = result
+        .items
+        .into_iter()
+        .map(|item| (item.label, item.text))
+        .collect();
+    (content[result.range].to_string(), items)
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn completions_of_the_configuration_keys() {
+    let (range, items) = configuration_completions(r#"{ "formatter": { "ind| } }"#);
+    assert_eq!(range, "\"ind");
+    assert!(items.contains(&("indentStyle".into(), "\"indentStyle\": ".into())));
+
+    // The keys of the other members aren't completed again
+    let (range, items) = configuration_completions(r#"{ "formatter": { "enabled": true, | } }"#);
+    assert_eq!(range, "");
+    assert!(items.iter().all(|(label, _)| label != "enabled"));
+    assert!(items.contains(&("lineWidth".into(), "\"lineWidth\": ".into())));
+
+    let (_, items) = configuration_completions(
+        "{\n  \"linter\": {\n    \"rules\": {\n      \"style\": {},\n      |\n    }\n  }\n}",
+    );
+    let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+    assert!(labels.contains(&"recommended"));
+    assert!(labels.contains(&"suspicious"));
+    assert!(!labels.contains(&"style"));
+
+    let (range, items) = configuration_completions(
+        r#"{ "linter": { "rules": { "suspicious": { "noDeb|": "off" } } } }"#,
+    );
+    assert_eq!(range, "\"noDeb\"");
+    assert!(items.contains(&("noDebugger".into(), "\"noDebugger\"".into())));
+
+    let (_, items) = configuration_completions(
+        r#"{ "linter": { "rules": { "complexity": { "noExcessiveCognitiveComplexity": { "level": "error", "options": { | } } } } } }"#,
+    );
+    assert_eq!(
+        items,
+        [(
+            "maxAllowedComplexity".into(),
+            "\"maxAllowedComplexity\": ".into()
+        )]
+    );
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn completions_of_the_configuration_values() {
+    let (range, items) = configuration_completions(r#"{ "formatter": { "indentStyle": | } }"#);
+    assert_eq!(range, "");
+    assert_eq!(
+        items,
+        [
+            ("space".into(), "\"space\"".into()),
+            ("tab".into(), "\"tab\"".into())
+        ]
+    );
+
+    let (range, items) = configuration_completions(r#"{ "formatter": { "indentStyle": "t|" } }"#);
+    assert_eq!(range, "\"t\"");
+    assert_eq!(items.len(), 2);
+
+    let (_, items) = configuration_completions(r#"{ "formatter": { "enabled": | } }"#);
+    assert_eq!(
+        items,
+        [
+            ("false".into(), "false".into()),
+            ("true".into(), "true".into())
+        ]
+    );
+
+    let (_, items) = configuration_completions(
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": | } } } }"#,
+    );
+    let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+    assert_eq!(labels, ["error", "off", "warn"]);
+
+    let (_, items) = configuration_completions(
+        r#"{ "overrides": [{ "javascript": { "formatter": { "quoteStyle": | } } }] }"#,
+    );
+    let labels: Vec<_> = items.iter().map(|(label, _)| label.as_str()).collect();
+    assert_eq!(labels, ["double", "single"]);
+}
+
+#[test]
+fn configuration_errors_have_precise_ranges() {
+    const SOURCE: &str = r#"{
+    "formater": {},
+    "linter": {
+        "rules": {
+            "style": { "noVarr": "error" },
+            "complexity": {
+                "noExcessiveCognitiveComplexity": {
+                    "level": "error",
+                    "options": { "maxAllowedComplexity": "high" }
+                }
+            }
+        }
+    }
+}"#;
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("biome.json"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::Json,
+        },
+    )
+    .unwrap();
+
+    let result = file
+        .pull_diagnostics(RuleCategories::all(), u64::MAX, Vec::new(), Vec::new())
+        .unwrap();
+    let ranges: Vec<_> = result
+        .diagnostics
+        .iter()
+        .filter_map(|diagnostic| diagnostic.location().span)
+        .map(|span| &SOURCE[span])
+        .collect();
+    assert_eq!(ranges, ["\"formater\"", "\"noVarr\"", "\"high\""]);
+}
+
+/// Returns the text of the ranges linked to the `|` of `source`
+fn linked_editing_ranges(source: &str) -> Vec<String> {
+    let offset = source.find('|').expect("the cursor is in the source");
+    let content = source.replacen('|', "", 1);
+
+    let workspace = server();
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.jsx"),
+            content: content.clone(),
+            version: 0,
+            language_hint: Language::JavaScriptReact,
+        },
+    )
+    .unwrap();
+
+    file.linked_editing_ranges(TextSize::from(offset as u32))
+        .unwrap()
+        .ranges
+        .into_iter()
+        .map(|range| content[range].to_string())
+        .collect()
+}
+
+#[test]
+fn linked_editing_ranges_of_jsx_tags() {
+    assert_eq!(
+        linked_editing_ranges("<div>\n  <Fo|o>text</Foo>\n</div>;"),
+        ["Foo", "Foo"]
+    );
+    // The cursor can be at the end of the name of the closing tag
+    assert_eq!(
+        linked_editing_ranges("<Foo.Bar a={1}>\n  <span />\n</Foo.Bar|>;"),
+        ["Foo.Bar", "Foo.Bar"]
+    );
+    assert_eq!(
+        linked_editing_ranges("<svg:|rect></svg:rect>;"),
+        ["svg:rect", "svg:rect"]
+    );
+    assert_eq!(
+        linked_editing_ranges("<div>\n  <Fo|o>text</Foo>\n</div>;"),
+        linked_editing_ranges("<div>\n  <Foo>text</Fo|o>\n</div>;")
+    );
+
+    // The attributes, the self-closing tags and the names that don't match aren't linked
+    assert!(linked_editing_ranges("<Foo cl|assName=\"a\"></Foo>;").is_empty());
+    assert!(linked_editing_ranges("<Fo|o />;").is_empty());
+    assert!(linked_editing_ranges("<Fo|o></Bar>;").is_empty());
+}
@@ -2,10 +2,13 @@ use js_sys::Error;
 use wasm_bindgen::prelude::*;
 
 use biome_service::workspace::{
-    self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
-    FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, UpdateSettingsParams,
+    self, ChangeFileParams, CloseFileParams, CognitiveComplexityParams, CompletionsParams,
+    DocumentSymbolsParams, FindReferencesParams, FixFileParams, FoldingRangesParams,
+    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
+    GetFileContentParams, GetFormatterIRParams, GetSyntaxTreeParams, GotoDefinitionParams,
+    HoverParams, LinkedEditingRangesParams, OrganizeImportsParams, PullActionsParams,
+    PullDiagnosticsParams, RenameImportsParams, RenameParams, SearchPatternParams,
+    SelectionRangesParams, SemanticTokensParams, SuppressionsParams, UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -194,6 +197,166 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = renameImports)]
+    pub fn rename_imports(&self, params: IRenameImportsParams) -> Result<IRenameResult, Error> {
+        let params: RenameImportsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.rename_imports(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IRenameResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = searchPattern)]
+    pub fn search_pattern(
+        &self,
+        params: ISearchPatternParams,
+    ) -> Result<ISearchPatternResult, Error> {
+        let params: SearchPatternParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.search_pattern(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISearchPatternResult::from)
+            .map_err(into_error)
+    }
+
+    pub fn hover(&self, params: IHoverParams) -> Result<IHoverResult, Error> {
+        let params: HoverParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.hover(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IHoverResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = gotoDefinition)]
+    pub fn goto_definition(
+        &self,
+        params: IGotoDefinitionParams,
+    ) -> Result<IGotoDefinitionResult, Error> {
+        let params: GotoDefinitionParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.goto_definition(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGotoDefinitionResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = findReferences)]
+    pub fn find_references(
+        &self,
+        params: IFindReferencesParams,
+    ) -> Result<IFindReferencesResult, Error> {
+        let params: FindReferencesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.find_references(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IFindReferencesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = documentSymbols)]
+    pub fn document_symbols(
+        &self,
+        params: IDocumentSymbolsParams,
+    ) -> Result<IDocumentSymbolsResult, Error> {
+        let params: DocumentSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.document_symbols(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IDocumentSymbolsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = foldingRanges)]
+    pub fn folding_ranges(
+        &self,
+        params: IFoldingRangesParams,
+    ) -> Result<IFoldingRangesResult, Error> {
+        let params: FoldingRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.folding_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IFoldingRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = selectionRanges)]
+    pub fn selection_ranges(
+        &self,
+        params: ISelectionRangesParams,
+    ) -> Result<ISelectionRangesResult, Error> {
+        let params: SelectionRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.selection_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISelectionRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = semanticTokens)]
+    pub fn semantic_tokens(
+        &self,
+        params: ISemanticTokensParams,
+    ) -> Result<ISemanticTokensResult, Error> {
+        let params: SemanticTokensParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.semantic_tokens(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISemanticTokensResult::from)
+            .map_err(into_error)
+    }
+
+    pub fn suppressions(&self, params: ISuppressionsParams) -> Result<ISuppressionsResult, Error> {
+        let params: SuppressionsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.suppressions(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISuppressionsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = cognitiveComplexity)]
+    pub fn cognitive_complexity(
+        &self,
+        params: ICognitiveComplexityParams,
+    ) -> Result<ICognitiveComplexityResult, Error> {
+        let params: CognitiveComplexityParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .cognitive_complexity(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(ICognitiveComplexityResult::from)
+            .map_err(into_error)
+    }
+
+    pub fn completions(&self, params: ICompletionsParams) -> Result<ICompletionsResult, Error> {
+        let params: CompletionsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.completions(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ICompletionsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = linkedEditingRanges)]
+    pub fn linked_editing_ranges(
+        &self,
+        params: ILinkedEditingRangesParams,
+    ) -> Result<ILinkedEditingRangesResult, Error> {
+        let params: LinkedEditingRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .linked_editing_ranges(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(ILinkedEditingRangesResult::from)
+            .map_err(into_error)
+    }
 }
 
 fn to_value<T: serde::ser::Serialize + ?Sized>(
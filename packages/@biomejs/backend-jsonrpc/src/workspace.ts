@@ -67,6 +67,10 @@ export interface PartialConfiguration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * A list of paths to the files of plugins, that define custom lint rules with patterns.
+	 */
+	plugins?: Plugins;
 	/**
 	 * The configuration of the VCS integration
 	 */
@@ -211,6 +215,7 @@ export interface PartialOrganizeImports {
 	include?: StringSet;
 }
 export type Overrides = OverridePattern[];
+export type Plugins = Plugin[];
 /**
  * Set of properties to integrate Biome with a VCS software.
  */
@@ -446,6 +451,7 @@ export interface OverridePattern {
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
+export type Plugin = string;
 export type VcsClientKind = "git";
 export type QuoteStyle = "double" | "single";
 export type ArrowParentheses = "always" | "asNeeded";
@@ -1568,9 +1574,18 @@ export interface GetFormatterIRParams {
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
 	max_diagnostics: number;
+	/**
+	 * When not empty, only these rules run, even when they are turned off in the configuration
+	 */
+	only?: RuleSelector[];
 	path: RomePath;
+	/**
+	 * These rules don't run
+	 */
+	skip?: RuleSelector[];
 }
 export type RuleCategories = RuleCategory[];
+export type RuleSelector = string;
 export type RuleCategory = "Syntax" | "Lint" | "Action" | "Transformation";
 export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
@@ -1805,6 +1820,7 @@ export type Category =
 	| "configuration"
 	| "organizeImports"
 	| "migrate"
+	| "search"
 	| "deserialize"
 	| "project"
 	| "internalError/io"
@@ -1820,6 +1836,7 @@ export type Category =
 	| "lint/correctness"
 	| "lint/nursery"
 	| "lint/performance"
+	| "lint/plugin"
 	| "lint/security"
 	| "lint/style"
 	| "lint/suspicious"
@@ -2006,8 +2023,16 @@ export interface FormatOnTypeParams {
 }
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
+	/**
+	 * When not empty, only the fixes of these rules are applied, even when they are turned off in the configuration
+	 */
+	only?: RuleSelector[];
 	path: RomePath;
 	should_format: boolean;
+	/**
+	 * The fixes of these rules aren't applied
+	 */
+	skip?: RuleSelector[];
 }
 /**
  * Which fixes should be applied during the analyzing phase
@@ -2047,6 +2072,10 @@ export interface RenameParams {
 	symbol_at: TextSize;
 }
 export interface RenameResult {
+	/**
+	 * Name of the export of the renamed binding, when the rename changes it. The files that import this name from the renamed file must be updated with [Workspace::rename_imports]
+	 */
+	exported_name?: string;
 	/**
 	 * List of text edit operations to apply on the source code
 	 */
@@ -2056,6 +2085,313 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface RenameImportsParams {
+	/**
+	 * Name of the export before the rename
+	 */
+	exported_name: string;
+	/**
+	 * The file that exports the renamed binding
+	 */
+	module_path: RomePath;
+	new_name: string;
+	/**
+	 * The file that imports the renamed export
+	 */
+	path: RomePath;
+}
+export interface SearchPatternParams {
+	path: RomePath;
+	/**
+	 * The code to search, where `$name` matches any node and `$$$name` matches any number of elements of a list
+	 */
+	pattern: string;
+}
+export interface SearchPatternResult {
+	/**
+	 * Ranges of the code that match the pattern
+	 */
+	matches: TextRange[];
+	/**
+	 * Whether the pattern could be parsed with the parser of the language of the file. A pattern that can't be parsed doesn't match anything.
+	 */
+	valid_pattern: boolean;
+}
+export interface HoverParams {
+	offset: TextSize;
+	path: RomePath;
+}
+export interface HoverResult {
+	/**
+	 * The binding that the identifier under the cursor declares or refers to
+	 */
+	binding?: HoverBinding;
+	/**
+	 * Range of the node under the cursor
+	 */
+	range?: TextRange;
+	/**
+	 * Name of the rule group and rule under the cursor, e.g. a rule of the configuration file
+	 */
+	rule_name?: [string, string];
+}
+export interface HoverBinding {
+	/**
+	 * Range of the identifier that declares the binding
+	 */
+	declaration_range: TextRange;
+	/**
+	 * How the binding is declared
+	 */
+	kind: BindingKind;
+	/**
+	 * Name of the binding
+	 */
+	name: string;
+}
+export type BindingKind =
+	| "Const"
+	| "Let"
+	| "Var"
+	| "Using"
+	| "Function"
+	| "Class"
+	| "Parameter"
+	| "TypeParameter"
+	| "Interface"
+	| "TypeAlias"
+	| "Enum"
+	| "Namespace"
+	| "Import"
+	| "CatchParameter";
+export interface GotoDefinitionParams {
+	offset: TextSize;
+	path: RomePath;
+}
+export interface GotoDefinitionResult {
+	/**
+	 * Range of the declaration of the binding under the cursor. For an imported binding, it's the range of the import specifier
+	 */
+	declaration_range?: TextRange;
+	/**
+	 * Specifier of the module under the cursor, e.g. `./utils`, when the cursor is on the source of an import or an export, or on the binding declared by an import
+	 */
+	module_specifier?: string;
+}
+export interface FindReferencesParams {
+	offset: TextSize;
+	path: RomePath;
+}
+export interface FindReferencesResult {
+	/**
+	 * The declaration and the references of the binding under the cursor, in the order they appear in the file
+	 */
+	references: BindingReference[];
+}
+export interface BindingReference {
+	kind: ReferenceKind;
+	/**
+	 * Range of the identifier
+	 */
+	range: TextRange;
+}
+export type ReferenceKind = "Declaration" | "Read" | "Write";
+export interface DocumentSymbolsParams {
+	path: RomePath;
+}
+export interface DocumentSymbolsResult {
+	/**
+	 * The symbols declared at the top level of the file, in the order they appear in the file
+	 */
+	symbols: DocumentSymbol[];
+}
+export interface DocumentSymbol {
+	/**
+	 * The symbols declared inside this symbol, e.g. the methods of a class
+	 */
+	children: DocumentSymbol[];
+	kind: SymbolKind;
+	/**
+	 * Name of the symbol, e.g. the name of a function or the selector of a CSS rule
+	 */
+	name: string;
+	/**
+	 * Range of the whole declaration of the symbol
+	 */
+	range: TextRange;
+	/**
+	 * Range of the name of the symbol
+	 */
+	selection_range: TextRange;
+}
+export type SymbolKind =
+	| "Function"
+	| "Class"
+	| "Method"
+	| "Property"
+	| "Constructor"
+	| "Interface"
+	| "TypeAlias"
+	| "Enum"
+	| "EnumMember"
+	| "Namespace"
+	| "Variable"
+	| "Constant"
+	| "Rule"
+	| "AtRule"
+	| "Object"
+	| "Array"
+	| "String"
+	| "Number"
+	| "Boolean"
+	| "Null";
+export interface FoldingRangesParams {
+	path: RomePath;
+}
+export interface FoldingRangesResult {
+	/**
+	 * The ranges of the code that can be folded, sorted by their start
+	 */
+	ranges: FoldingRange[];
+}
+export interface FoldingRange {
+	kind: FoldingRangeKind;
+	range: TextRange;
+}
+export type FoldingRangeKind = "Block" | "Comment" | "Imports" | "Region";
+export interface SelectionRangesParams {
+	offsets: TextSize[];
+	path: RomePath;
+}
+export interface SelectionRangesResult {
+	/**
+	 * For each offset, the range of the token at the offset and the ranges of the nodes that contain it, from the innermost node to the root
+	 */
+	ranges: TextRange[][];
+}
+export interface SemanticTokensParams {
+	path: RomePath;
+	/**
+	 * The range of the tokens to return, or `None` to return the tokens of the whole file
+	 */
+	range?: TextRange;
+}
+export interface SemanticTokensResult {
+	/**
+	 * The identifiers classified by the binding that they declare or refer to, sorted by their start
+	 */
+	tokens: SemanticToken[];
+}
+export interface SemanticToken {
+	kind: SemanticTokenKind;
+	modifiers: SemanticTokenModifier[];
+	range: TextRange;
+}
+export type SemanticTokenKind =
+	| "Variable"
+	| "Parameter"
+	| "Function"
+	| "Class"
+	| "Interface"
+	| "TypeParameter"
+	| "Enum"
+	| "EnumMember"
+	| "Namespace"
+	| "Type";
+export type SemanticTokenModifier =
+	| "Declaration"
+	| "Readonly"
+	| "Import"
+	| "Global"
+	| "Unused";
+export interface SuppressionsParams {
+	path: RomePath;
+}
+export interface SuppressionsResult {
+	/**
+	 * The suppression comments of the file, sorted by their start
+	 */
+	suppressions: Suppression[];
+}
+export interface Suppression {
+	/**
+	 * The range of the comment
+	 */
+	range: TextRange;
+	/**
+	 * The comment doesn't suppress any diagnostic of the rules enabled for the file
+	 */
+	unused: boolean;
+}
+export interface CognitiveComplexityParams {
+	path: RomePath;
+}
+export interface CognitiveComplexityResult {
+	/**
+	 * The complexity of the functions of the file, sorted by their start
+	 */
+	functions: FunctionComplexity[];
+	/**
+	 * The maximum score allowed by `noExcessiveCognitiveComplexity`, or `None` when the rule isn't enabled for the file
+	 */
+	max_allowed_complexity?: number;
+}
+export interface FunctionComplexity {
+	/**
+	 * The range of the name of the function, or of its `function` keyword or its arrow when it's anonymous
+	 */
+	range: TextRange;
+	/**
+	 * The cognitive complexity score of the function. `255` means that the function is too complex or too deeply nested to calculate an accurate score.
+	 */
+	score: number;
+}
+export interface CompletionsParams {
+	/**
+	 * The position of the cursor
+	 */
+	offset: TextSize;
+	path: RomePath;
+}
+export interface CompletionsResult {
+	/**
+	 * The completions available at the cursor, sorted by their label
+	 */
+	items: CompletionItem[];
+	/**
+	 * The range of the text that the completions replace
+	 */
+	range: TextRange;
+}
+export interface CompletionItem {
+	/**
+	 * The description of the key or the value, in markdown
+	 */
+	documentation?: string;
+	kind: CompletionItemKind;
+	/**
+	 * The name of the key or the value, as shown to the user
+	 */
+	label: string;
+	/**
+	 * The text that replaces the range of the result
+	 */
+	text: string;
+}
+export type CompletionItemKind = "Property" | "Value";
+export interface LinkedEditingRangesParams {
+	/**
+	 * The position of the cursor
+	 */
+	offset: TextSize;
+	path: RomePath;
+}
+export interface LinkedEditingRangesResult {
+	/**
+	 * The ranges that have the same text and change together, e.g. the names of the opening and the closing tags of a JSX element. It's empty when the cursor isn't in such a range.
+	 */
+	ranges: TextRange[];
+}
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
@@ -2082,6 +2418,27 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	renameImports(params: RenameImportsParams): Promise<RenameResult>;
+	searchPattern(params: SearchPatternParams): Promise<SearchPatternResult>;
+	hover(params: HoverParams): Promise<HoverResult>;
+	gotoDefinition(params: GotoDefinitionParams): Promise<GotoDefinitionResult>;
+	findReferences(params: FindReferencesParams): Promise<FindReferencesResult>;
+	documentSymbols(
+		params: DocumentSymbolsParams,
+	): Promise<DocumentSymbolsResult>;
+	foldingRanges(params: FoldingRangesParams): Promise<FoldingRangesResult>;
+	selectionRanges(
+		params: SelectionRangesParams,
+	): Promise<SelectionRangesResult>;
+	semanticTokens(params: SemanticTokensParams): Promise<SemanticTokensResult>;
+	suppressions(params: SuppressionsParams): Promise<SuppressionsResult>;
+	cognitiveComplexity(
+		params: CognitiveComplexityParams,
+	): Promise<CognitiveComplexityResult>;
+	completions(params: CompletionsParams): Promise<CompletionsResult>;
+	linkedEditingRanges(
+		params: LinkedEditingRangesParams,
+	): Promise<LinkedEditingRangesResult>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -2140,6 +2497,45 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		renameImports(params) {
+			return transport.request("biome/rename_imports", params);
+		},
+		searchPattern(params) {
+			return transport.request("biome/search_pattern", params);
+		},
+		hover(params) {
+			return transport.request("biome/hover", params);
+		},
+		gotoDefinition(params) {
+			return transport.request("biome/goto_definition", params);
+		},
+		findReferences(params) {
+			return transport.request("biome/find_references", params);
+		},
+		documentSymbols(params) {
+			return transport.request("biome/document_symbols", params);
+		},
+		foldingRanges(params) {
+			return transport.request("biome/folding_ranges", params);
+		},
+		selectionRanges(params) {
+			return transport.request("biome/selection_ranges", params);
+		},
+		semanticTokens(params) {
+			return transport.request("biome/semantic_tokens", params);
+		},
+		suppressions(params) {
+			return transport.request("biome/suppressions", params);
+		},
+		cognitiveComplexity(params) {
+			return transport.request("biome/cognitive_complexity", params);
+		},
+		completions(params) {
+			return transport.request("biome/completions", params);
+		},
+		linkedEditingRanges(params) {
+			return transport.request("biome/linked_editing_ranges", params);
+		},
 		destroy() {
 			transport.destroy();
 		},